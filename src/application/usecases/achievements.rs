@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::domain::{
+    entities::adventurer_achievements::AddAdventurerAchievementEntity,
+    repositories::achievements::AchievementsRepository,
+    value_objects::achievement_model::{AchievementModel, earned_badge_codes},
+};
+
+pub struct AchievementsUsecase<T>
+where
+    T: AchievementsRepository + Send + Sync,
+{
+    pub achievements_repository: Arc<T>,
+}
+
+impl<T> AchievementsUsecase<T>
+where
+    T: AchievementsRepository + Send + Sync,
+{
+    pub fn new(achievements_repository: Arc<T>) -> Self {
+        Self {
+            achievements_repository,
+        }
+    }
+
+    pub async fn achievements(&self, adventurer_id: i32) -> Result<Vec<AchievementModel>> {
+        let results = self
+            .achievements_repository
+            .by_adventurer_id(adventurer_id)
+            .await?;
+
+        Ok(results
+            .iter()
+            .map(|achievement| achievement.to_model())
+            .collect())
+    }
+
+    // ประเมิน badge ใหม่ให้ทุกคนใน crew หลัง event ของ quest เรียกซ้ำได้ไม่ได้ badge ซ้ำ
+    pub async fn evaluate_crew(&self, quest_id: i32) -> Result<Vec<(i32, String)>> {
+        let adventurer_ids = self
+            .achievements_repository
+            .crew_adventurer_ids(quest_id)
+            .await?;
+
+        let mut awarded = Vec::<(i32, String)>::new();
+
+        for adventurer_id in adventurer_ids {
+            for badge_code in self.evaluate(adventurer_id, Some(quest_id)).await? {
+                awarded.push((adventurer_id, badge_code));
+            }
+        }
+
+        Ok(awarded)
+    }
+
+    pub async fn evaluate(&self, adventurer_id: i32, quest_id: Option<i32>) -> Result<Vec<String>> {
+        let awarded_badge_codes = self
+            .achievements_repository
+            .by_adventurer_id(adventurer_id)
+            .await?
+            .into_iter()
+            .map(|achievement| achievement.badge_code)
+            .collect::<Vec<String>>();
+
+        let stats = self
+            .achievements_repository
+            .achievement_stats(adventurer_id)
+            .await?;
+
+        let awarded_at = chrono::Utc::now().naive_utc();
+
+        let add_adventurer_achievement_entities = earned_badge_codes(&stats, &awarded_badge_codes)
+            .into_iter()
+            .map(|badge_code| AddAdventurerAchievementEntity {
+                adventurer_id,
+                badge_code,
+                quest_id,
+                awarded_at,
+            })
+            .collect::<Vec<AddAdventurerAchievementEntity>>();
+
+        let results = self
+            .achievements_repository
+            .award(add_adventurer_achievement_entities)
+            .await?;
+
+        Ok(results)
+    }
+}
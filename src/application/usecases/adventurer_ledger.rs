@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::usecases::achievements::AchievementsUsecase,
+    config::config_loader::get_adventurer_levels_env,
+    domain::{
+        repositories::{
+            achievements::AchievementsRepository, adventurer_ledger::AdventurerLedgerRepository,
+            quest_ratings::QuestRatingsRepository,
+        },
+        value_objects::{
+            adventurer_ledger_model::{AdventurerBalanceModel, AdventurerLedgerModel},
+            adventurer_level_model::{AdventurerProfileModel, level_progress},
+            quest_rating_model::ReputationModel,
+        },
+    },
+};
+
+pub struct AdventurerLedgerUsecase<T1, T2, T3>
+where
+    T1: AdventurerLedgerRepository + Send + Sync,
+    T2: QuestRatingsRepository + Send + Sync,
+    T3: AchievementsRepository + Send + Sync,
+{
+    pub adventurer_ledger_repository: Arc<T1>,
+    pub quest_ratings_repository: Arc<T2>,
+    pub achievements_usecase: AchievementsUsecase<T3>,
+}
+
+impl<T1, T2, T3> AdventurerLedgerUsecase<T1, T2, T3>
+where
+    T1: AdventurerLedgerRepository + Send + Sync,
+    T2: QuestRatingsRepository + Send + Sync,
+    T3: AchievementsRepository + Send + Sync,
+{
+    pub fn new(
+        adventurer_ledger_repository: Arc<T1>,
+        quest_ratings_repository: Arc<T2>,
+        achievements_repository: Arc<T3>,
+    ) -> Self {
+        Self {
+            adventurer_ledger_repository,
+            quest_ratings_repository,
+            achievements_usecase: AchievementsUsecase::new(achievements_repository),
+        }
+    }
+
+    pub async fn balance(&self, adventurer_id: i32) -> Result<AdventurerBalanceModel> {
+        let gold = self
+            .adventurer_ledger_repository
+            .gold_balance(adventurer_id)
+            .await?;
+
+        Ok(AdventurerBalanceModel {
+            adventurer_id,
+            gold,
+        })
+    }
+
+    pub async fn entries_by_adventurer_id(
+        &self,
+        adventurer_id: i32,
+    ) -> Result<Vec<AdventurerLedgerModel>> {
+        let results = self
+            .adventurer_ledger_repository
+            .entries_by_adventurer_id(adventurer_id)
+            .await?;
+
+        Ok(results.iter().map(|entry| entry.to_model()).collect())
+    }
+
+    pub async fn profile(&self, adventurer_id: i32) -> Result<AdventurerProfileModel> {
+        let adventurer_levels = get_adventurer_levels_env()?;
+
+        let experience = self
+            .adventurer_ledger_repository
+            .experience_total(adventurer_id)
+            .await?;
+
+        let (score_total, ratings_count) = self
+            .quest_ratings_repository
+            .adventurer_score_totals(adventurer_id)
+            .await?;
+
+        let achievements = self
+            .achievements_usecase
+            .achievements(adventurer_id)
+            .await?;
+
+        Ok(AdventurerProfileModel {
+            adventurer_id,
+            level: level_progress(
+                experience,
+                adventurer_levels.base_experience,
+                adventurer_levels.growth_factor,
+            ),
+            reputation: ReputationModel::from_totals(score_total, ratings_count),
+            achievements,
+        })
+    }
+}
@@ -15,7 +15,6 @@ use crate::{
             authentication_model::LoginModel,
             jwt_model::{Claims, Passport, Roles},
         },
-        postgres::schema::quests::guild_commander_id,
     },
 };
 
@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Duration;
+
+use crate::{
+    application::usecases::crew_swithboard::CrewSwithboardUsecase,
+    config::config_loader::get_quest_invitations_env,
+    domain::{
+        entities::quest_invitations::AddQuestInvitationEntity,
+        repositories::{
+            achievements::AchievementsRepository, adventurer_ledger::AdventurerLedgerRepository,
+            crew_invitations::CrewInvitationsRepository, crew_swithboard::CrewSwithboardRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_invitation_model::{InviteAdventurersModel, QuestInvitationModel},
+            quest_participants::QuestParticipants,
+            quest_statuses::QuestStatuses,
+        },
+    },
+};
+
+pub struct CrewInvitationsUsecase<T1, T2, T3, T4, T5>
+where
+    T1: CrewInvitationsRepository + Send + Sync,
+    T2: CrewSwithboardRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: AdventurerLedgerRepository + Send + Sync,
+    T5: AchievementsRepository + Send + Sync,
+{
+    pub crew_invitations_repository: Arc<T1>,
+    pub quest_viewing_repository: Arc<T3>,
+    pub crew_swithboard_usecase: CrewSwithboardUsecase<T2, T3, T4, T5>,
+}
+
+impl<T1, T2, T3, T4, T5> CrewInvitationsUsecase<T1, T2, T3, T4, T5>
+where
+    T1: CrewInvitationsRepository + Send + Sync,
+    T2: CrewSwithboardRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: AdventurerLedgerRepository + Send + Sync,
+    T5: AchievementsRepository + Send + Sync,
+{
+    pub fn new(
+        crew_invitations_repository: Arc<T1>,
+        crew_swithboard_repository: Arc<T2>,
+        quest_viewing_repository: Arc<T3>,
+        adventurer_ledger_repository: Arc<T4>,
+        achievements_repository: Arc<T5>,
+    ) -> Self {
+        Self {
+            crew_invitations_repository,
+            quest_viewing_repository: Arc::clone(&quest_viewing_repository),
+            crew_swithboard_usecase: CrewSwithboardUsecase::new(
+                crew_swithboard_repository,
+                quest_viewing_repository,
+                adventurer_ledger_repository,
+                achievements_repository,
+            ),
+        }
+    }
+
+    pub async fn invite(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        invite_adventurers_model: InviteAdventurersModel,
+    ) -> Result<Vec<i32>> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(
+                quest_id,
+                Some(QuestParticipants::GuildCommander(guild_commander_id)),
+            )
+            .await?;
+
+        if quest.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest is not owned by this guild commander"
+            ));
+        }
+
+        let quest_status_condition = quest.status.to_string() == QuestStatuses::Open.to_string()
+            || quest.status.to_string() == QuestStatuses::Failed.to_string();
+
+        if !quest_status_condition {
+            return Err(anyhow::anyhow!("Quest is not invitable"));
+        }
+
+        let mut adventurer_ids = invite_adventurers_model.adventurer_ids;
+        adventurer_ids.sort_unstable();
+        adventurer_ids.dedup();
+
+        if adventurer_ids.is_empty() {
+            return Err(anyhow::anyhow!("No adventurers to invite"));
+        }
+
+        let quest_invitations = get_quest_invitations_env()?;
+        let now = chrono::Utc::now().naive_utc();
+        let expires_at = now + Duration::hours(quest_invitations.expiry_hours);
+
+        let invitation_entities = adventurer_ids
+            .into_iter()
+            .map(|adventurer_id| AddQuestInvitationEntity {
+                quest_id,
+                adventurer_id,
+                guild_commander_id,
+                expires_at,
+                created_at: now,
+            })
+            .collect();
+
+        let result = self
+            .crew_invitations_repository
+            .invite(invitation_entities)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn pending_by_quest_id(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestInvitationModel>> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(
+                quest_id,
+                Some(QuestParticipants::GuildCommander(guild_commander_id)),
+            )
+            .await?;
+
+        if quest.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest is not owned by this guild commander"
+            ));
+        }
+
+        let results = self
+            .crew_invitations_repository
+            .pending_by_quest_id(quest_id, chrono::Utc::now().naive_utc())
+            .await?;
+
+        Ok(results
+            .iter()
+            .map(|invitation| invitation.to_model())
+            .collect())
+    }
+
+    pub async fn pending_by_adventurer_id(
+        &self,
+        adventurer_id: i32,
+    ) -> Result<Vec<QuestInvitationModel>> {
+        let results = self
+            .crew_invitations_repository
+            .pending_by_adventurer_id(adventurer_id, chrono::Utc::now().naive_utc())
+            .await?;
+
+        Ok(results
+            .iter()
+            .map(|invitation| invitation.to_model())
+            .collect())
+    }
+
+    pub async fn accept(&self, invitation_id: i32, adventurer_id: i32) -> Result<()> {
+        let invitation = self
+            .crew_invitations_repository
+            .find_pending(invitation_id, adventurer_id, chrono::Utc::now().naive_utc())
+            .await?;
+
+        self.crew_swithboard_usecase
+            .join_by_invitation(invitation.quest_id, adventurer_id)
+            .await?;
+
+        self.crew_invitations_repository
+            .remove(invitation.id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn decline(&self, invitation_id: i32, adventurer_id: i32) -> Result<()> {
+        let invitation = self
+            .crew_invitations_repository
+            .find_pending(invitation_id, adventurer_id, chrono::Utc::now().naive_utc())
+            .await?;
+
+        self.crew_invitations_repository
+            .remove(invitation.id)
+            .await?;
+
+        Ok(())
+    }
+}
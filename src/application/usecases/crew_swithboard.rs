@@ -1,58 +1,142 @@
 use std::sync::Arc;
 
-use crate::domain::{
-    repositories::{
-        crew_swithboard::CrewSwithboardRepository, quest_viewing::QuestViewingRepository,
-    },
-    value_objects::{
-        quest_adventurer_juntion::QuestAdventurerJunction, quest_statuses::QuestStatuses,
+use crate::{
+    application::usecases::achievements::AchievementsUsecase,
+    config::config_loader::get_adventurer_levels_env,
+    domain::{
+        entities::{
+            quest_crew_bans::QuestCrewBanEntity,
+            quest_join_applications::QuestJoinApplicationEntity,
+            quest_waitlist::AddQuestWaitlistEntity, quests::QuestEntity,
+        },
+        repositories::{
+            achievements::AchievementsRepository, adventurer_ledger::AdventurerLedgerRepository,
+            crew_swithboard::CrewSwithboardRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            adventurer_level_model::level_progress,
+            join_policies::JoinPolicies,
+            quest_adventurer_juntion::QuestAdventurerJunction,
+            quest_crew_ban_model::{KickAdventurerModel, QuestCrewBanModel},
+            quest_join_application_model::QuestJoinApplicationModel,
+            quest_participants::QuestParticipants,
+            quest_revision_model::CrewQuestRevisionsModel,
+            quest_statuses::QuestStatuses,
+            quest_waitlist_model::{JoinOutcomes, QuestWaitlistModel},
+        },
     },
 };
 use anyhow::Result;
+use tracing::error;
 
-pub struct CrewSwithboardUsecase<T1, T2>
+pub struct CrewSwithboardUsecase<T1, T2, T3, T4>
 where
     T1: CrewSwithboardRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
 {
     pub crew_swithboard_repository: Arc<T1>,
     pub quest_viewing_repository: Arc<T2>,
+    pub adventurer_ledger_repository: Arc<T3>,
+    pub achievements_usecase: AchievementsUsecase<T4>,
 }
 
-impl<T1, T2> CrewSwithboardUsecase<T1, T2>
+impl<T1, T2, T3, T4> CrewSwithboardUsecase<T1, T2, T3, T4>
 where
     T1: CrewSwithboardRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
 {
-    pub fn new(crew_swithboard_repository: Arc<T1>, quest_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        crew_swithboard_repository: Arc<T1>,
+        quest_viewing_repository: Arc<T2>,
+        adventurer_ledger_repository: Arc<T3>,
+        achievements_repository: Arc<T4>,
+    ) -> Self {
         Self {
             crew_swithboard_repository,
             quest_viewing_repository,
+            adventurer_ledger_repository,
+            achievements_usecase: AchievementsUsecase::new(achievements_repository),
         }
     }
 
-    pub async fn join(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
-        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+    pub async fn join(&self, quest_id: i32, adventurer_id: i32) -> Result<JoinOutcomes> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(quest_id, Some(QuestParticipants::Adventurer(adventurer_id)))
+            .await?;
 
-        let quest_status_condition = quest.status.to_string() == QuestStatuses::Open.to_string()
-            || quest.status.to_string() == QuestStatuses::Failed.to_string();
+        match quest.join_policy {
+            JoinPolicies::Open => {}
+            JoinPolicies::ApprovalRequired => {
+                return Err(anyhow::anyhow!("Quest requires approval, apply to join"));
+            }
+            JoinPolicies::InviteOnly => {
+                return Err(anyhow::anyhow!("Quest is invite only"));
+            }
+        }
 
-        if !quest_status_condition {
-            return Err(anyhow::anyhow!("Quest is not join "));
+        self.ensure_joinable(&quest, adventurer_id).await?;
+
+        let junction_body = QuestAdventurerJunction {
+            adventurer_id,
+            quest_id,
+        };
+
+        let waitlist_entity = AddQuestWaitlistEntity {
+            quest_id,
+            adventurer_id,
+            created_at: chrono::Utc::now().naive_utc(),
+        };
+
+        let joined = self
+            .crew_swithboard_repository
+            .join(junction_body, Some(waitlist_entity))
+            .await?;
+
+        if !joined {
+            let position = self
+                .crew_swithboard_repository
+                .waitlist_position(quest_id, adventurer_id)
+                .await?;
+
+            return Ok(JoinOutcomes::Waitlisted { position });
         }
 
+        self.evaluate_achievements(quest_id).await;
+
+        Ok(JoinOutcomes::Joined)
+    }
+
+    pub async fn join_by_invitation(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(quest_id, Some(QuestParticipants::Adventurer(adventurer_id)))
+            .await?;
+
+        self.ensure_joinable(&quest, adventurer_id).await?;
+
         let junction_body = QuestAdventurerJunction {
             adventurer_id,
             quest_id,
         };
 
-        self.crew_swithboard_repository.join(junction_body).await?;
+        self.crew_swithboard_repository
+            .join(junction_body, None)
+            .await?;
+        self.evaluate_achievements(quest_id).await;
 
         Ok(())
     }
 
     pub async fn leave(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
-        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+        let quest = self
+            .quest_viewing_repository
+            .view_details(quest_id, Some(QuestParticipants::Adventurer(adventurer_id)))
+            .await?;
 
         let quest_status_condition = quest.status.to_string() == QuestStatuses::Open.to_string()
             || quest.status.to_string() == QuestStatuses::Failed.to_string();
@@ -67,7 +151,384 @@ where
         };
 
         self.crew_swithboard_repository.leave(junction_body).await?;
+        self.evaluate_achievements(quest_id).await;
 
         Ok(())
     }
+
+    pub async fn kick(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+        kick_adventurer_model: KickAdventurerModel,
+    ) -> Result<()> {
+        let quest = self
+            .owned_quest_details(quest_id, guild_commander_id)
+            .await?;
+
+        let quest_status_condition = quest.status.to_string() == QuestStatuses::Open.to_string()
+            || quest.status.to_string() == QuestStatuses::Failed.to_string();
+
+        if !quest_status_condition {
+            return Err(anyhow::anyhow!("Quest is not kickable"));
+        }
+
+        let junction_body = QuestAdventurerJunction {
+            adventurer_id,
+            quest_id,
+        };
+
+        let ban_entity = if kick_adventurer_model.ban.unwrap_or(false) {
+            Some(QuestCrewBanEntity {
+                quest_id,
+                adventurer_id,
+                guild_commander_id,
+                created_at: chrono::Utc::now().naive_utc(),
+            })
+        } else {
+            None
+        };
+
+        self.crew_swithboard_repository
+            .kick(junction_body, ban_entity)
+            .await?;
+        self.evaluate_achievements(quest_id).await;
+
+        Ok(())
+    }
+
+    pub async fn unban(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+    ) -> Result<()> {
+        self.owned_quest_details(quest_id, guild_commander_id)
+            .await?;
+
+        self.crew_swithboard_repository
+            .unban(quest_id, adventurer_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn bans_by_quest_id(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestCrewBanModel>> {
+        self.owned_quest_details(quest_id, guild_commander_id)
+            .await?;
+
+        let results = self
+            .crew_swithboard_repository
+            .bans_by_quest_id(quest_id)
+            .await?;
+
+        Ok(results.iter().map(|ban| ban.to_model()).collect())
+    }
+
+    pub async fn apply(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(quest_id, Some(QuestParticipants::Adventurer(adventurer_id)))
+            .await?;
+
+        if quest.join_policy != JoinPolicies::ApprovalRequired {
+            return Err(anyhow::anyhow!("Quest does not accept join applications"));
+        }
+
+        self.ensure_joinable(&quest, adventurer_id).await?;
+        self.ensure_crew_capacity(&quest).await?;
+
+        let application_entity = QuestJoinApplicationEntity {
+            quest_id,
+            adventurer_id,
+            created_at: chrono::Utc::now().naive_utc(),
+        };
+
+        self.crew_swithboard_repository
+            .apply(application_entity)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn withdraw(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        self.crew_swithboard_repository
+            .remove_application(quest_id, adventurer_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn applications_by_quest_id(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestJoinApplicationModel>> {
+        self.owned_quest_details(quest_id, guild_commander_id)
+            .await?;
+
+        let results = self
+            .crew_swithboard_repository
+            .applications_by_quest_id(quest_id)
+            .await?;
+
+        Ok(results
+            .iter()
+            .map(|application| application.to_model())
+            .collect())
+    }
+
+    pub async fn approve(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+    ) -> Result<()> {
+        let quest = self
+            .owned_quest_details(quest_id, guild_commander_id)
+            .await?;
+
+        self.ensure_joinable(&quest, adventurer_id).await?;
+
+        let junction_body = QuestAdventurerJunction {
+            adventurer_id,
+            quest_id,
+        };
+
+        self.crew_swithboard_repository
+            .approve(junction_body)
+            .await?;
+        self.evaluate_achievements(quest_id).await;
+
+        Ok(())
+    }
+
+    pub async fn reject(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+    ) -> Result<()> {
+        self.owned_quest_details(quest_id, guild_commander_id)
+            .await?;
+
+        self.crew_swithboard_repository
+            .remove_application(quest_id, adventurer_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn leave_waitlist(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        self.crew_swithboard_repository
+            .leave_waitlist(quest_id, adventurer_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn waitlist_position(
+        &self,
+        quest_id: i32,
+        adventurer_id: i32,
+    ) -> Result<QuestWaitlistModel> {
+        let waitlist = self
+            .crew_swithboard_repository
+            .waitlist_by_adventurer_id(adventurer_id)
+            .await?;
+
+        let waitlist_entry = waitlist
+            .iter()
+            .find(|waitlist_entry| waitlist_entry.quest_id == quest_id)
+            .ok_or(anyhow::anyhow!("Adventurer is not on the quest waitlist"))?;
+
+        let position = self
+            .crew_swithboard_repository
+            .waitlist_position(quest_id, adventurer_id)
+            .await?;
+
+        Ok(waitlist_entry.to_model(position))
+    }
+
+    pub async fn waitlist_by_adventurer_id(
+        &self,
+        adventurer_id: i32,
+    ) -> Result<Vec<QuestWaitlistModel>> {
+        let waitlist = self
+            .crew_swithboard_repository
+            .waitlist_by_adventurer_id(adventurer_id)
+            .await?;
+
+        let mut waitlist_model = Vec::<QuestWaitlistModel>::new();
+
+        for waitlist_entry in waitlist {
+            let position = self
+                .crew_swithboard_repository
+                .waitlist_position(waitlist_entry.quest_id, adventurer_id)
+                .await?;
+            waitlist_model.push(waitlist_entry.to_model(position));
+        }
+
+        Ok(waitlist_model)
+    }
+
+    pub async fn revisions_since_joined(
+        &self,
+        quest_id: i32,
+        adventurer_id: i32,
+    ) -> Result<CrewQuestRevisionsModel> {
+        let crew_member = self
+            .crew_swithboard_repository
+            .crew_member(quest_id, adventurer_id)
+            .await?;
+
+        let revisions = self
+            .quest_viewing_repository
+            .revisions_by_quest_id(quest_id)
+            .await?;
+
+        Ok(CrewQuestRevisionsModel {
+            quest_id,
+            joined_at: crew_member.joined_at,
+            reconfirmation_required: crew_member.reconfirmation_required,
+            revisions: revisions
+                .iter()
+                .filter(|revision| revision.created_at >= crew_member.joined_at)
+                .map(|revision| revision.to_model())
+                .collect(),
+        })
+    }
+
+    pub async fn reconfirm(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        let crew_member = self
+            .crew_swithboard_repository
+            .crew_member(quest_id, adventurer_id)
+            .await?;
+
+        if !crew_member.reconfirmation_required {
+            return Err(anyhow::anyhow!("Quest does not need reconfirmation"));
+        }
+
+        self.crew_swithboard_repository
+            .reconfirm(quest_id, adventurer_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn reconfirmations_by_adventurer_id(&self, adventurer_id: i32) -> Result<Vec<i32>> {
+        let results = self
+            .crew_swithboard_repository
+            .reconfirmations_by_adventurer_id(adventurer_id)
+            .await?;
+
+        Ok(results)
+    }
+
+    async fn ensure_joinable(&self, quest: &QuestEntity, adventurer_id: i32) -> Result<()> {
+        let quest_status_condition = quest.status.to_string() == QuestStatuses::Open.to_string()
+            || quest.status.to_string() == QuestStatuses::Failed.to_string();
+
+        if !quest_status_condition {
+            return Err(anyhow::anyhow!("Quest is not join "));
+        }
+
+        if let Some(join_deadline) = quest.join_deadline
+            && chrono::Utc::now().naive_utc() > join_deadline
+        {
+            return Err(anyhow::anyhow!("Quest join deadline has passed"));
+        }
+
+        if quest.min_level > 1 {
+            let adventurer_levels = get_adventurer_levels_env()?;
+
+            let experience = self
+                .adventurer_ledger_repository
+                .experience_total(adventurer_id)
+                .await?;
+
+            let adventurer_level = level_progress(
+                experience,
+                adventurer_levels.base_experience,
+                adventurer_levels.growth_factor,
+            );
+
+            if adventurer_level.level < quest.min_level {
+                return Err(anyhow::anyhow!(
+                    "Adventurer level {} is below the required level {}",
+                    adventurer_level.level,
+                    quest.min_level
+                ));
+            }
+        }
+
+        if self
+            .crew_swithboard_repository
+            .is_banned(quest.id, adventurer_id)
+            .await?
+        {
+            return Err(anyhow::anyhow!("Adventurer is banned from this quest"));
+        }
+
+        if self
+            .crew_swithboard_repository
+            .is_crew_member(quest.id, adventurer_id)
+            .await?
+        {
+            return Err(anyhow::anyhow!("Adventurer is already in the quest crew"));
+        }
+
+        Ok(())
+    }
+
+    async fn ensure_crew_capacity(&self, quest: &QuestEntity) -> Result<()> {
+        if self.is_crew_full(quest).await? {
+            return Err(anyhow::anyhow!("Quest crew is full"));
+        }
+
+        Ok(())
+    }
+
+    async fn is_crew_full(&self, quest: &QuestEntity) -> Result<bool> {
+        let adventurers_count = self
+            .quest_viewing_repository
+            .adventurers_counting_by_quest_id(quest.id)
+            .await?;
+
+        Ok(adventurers_count >= quest.max_crew as i64)
+    }
+
+    // leave กับ kick อาจดึงคนจาก waitlist เข้า crew จึงประเมินทั้ง crew
+    async fn evaluate_achievements(&self, quest_id: i32) {
+        if let Err(e) = self.achievements_usecase.evaluate_crew(quest_id).await {
+            error!("Failed to evaluate achievements :{}", e);
+        }
+    }
+
+    async fn owned_quest_details(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<QuestEntity> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(
+                quest_id,
+                Some(QuestParticipants::GuildCommander(guild_commander_id)),
+            )
+            .await?;
+
+        if quest.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest is not owned by this guild commander"
+            ));
+        }
+
+        Ok(quest)
+    }
 }
@@ -0,0 +1,257 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::domain::{
+    entities::guilds::{AddGuildMemberEntity, GuildMemberEntity},
+    repositories::guilds::GuildsRepository,
+    value_objects::{
+        guild_model::{
+            AddGuildMemberModel, AddGuildModel, ChangeGuildRoleModel, GuildMemberModel,
+            GuildMembershipModel, GuildModel, validate_guild,
+        },
+        guild_roles::GuildRoles,
+        pagination::{PaginatedModel, PaginationQuery},
+        quest_participants::QuestParticipants,
+    },
+};
+
+pub struct GuildsUsecase<T>
+where
+    T: GuildsRepository + Send + Sync,
+{
+    pub guilds_repository: Arc<T>,
+}
+
+impl<T> GuildsUsecase<T>
+where
+    T: GuildsRepository + Send + Sync,
+{
+    pub fn new(guilds_repository: Arc<T>) -> Self {
+        Self { guilds_repository }
+    }
+
+    pub async fn create(
+        &self,
+        guild_commander_id: i32,
+        add_guild_model: AddGuildModel,
+    ) -> Result<i32> {
+        validate_guild(&add_guild_model.name, &add_guild_model.description)?;
+
+        let result = self
+            .guilds_repository
+            .create(add_guild_model.to_entity(), guild_commander_id)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn guilds(&self, pagination: PaginationQuery) -> Result<PaginatedModel<GuildModel>> {
+        let results = self
+            .guilds_repository
+            .guilds(pagination.page_size(), pagination.offset())
+            .await?;
+
+        let total = self.guilds_repository.guilds_counting().await?;
+
+        let mut items = Vec::<GuildModel>::new();
+
+        for result in results {
+            let members_count = self
+                .guilds_repository
+                .members_counting_by_guild_id(result.id)
+                .await?;
+            items.push(result.to_model(members_count));
+        }
+
+        Ok(PaginatedModel {
+            items,
+            page: pagination.page(),
+            page_size: pagination.page_size(),
+            total,
+        })
+    }
+
+    pub async fn view_details(&self, guild_id: i32) -> Result<GuildModel> {
+        let result = self.guilds_repository.view_details(guild_id).await?;
+
+        let members_count = self
+            .guilds_repository
+            .members_counting_by_guild_id(guild_id)
+            .await?;
+
+        Ok(result.to_model(members_count))
+    }
+
+    pub async fn members(&self, guild_id: i32) -> Result<Vec<GuildMemberModel>> {
+        self.guilds_repository.view_details(guild_id).await?;
+
+        let results = self.guilds_repository.members_by_guild_id(guild_id).await?;
+
+        Ok(results.iter().map(|member| member.to_model()).collect())
+    }
+
+    pub async fn memberships(
+        &self,
+        participant: QuestParticipants,
+    ) -> Result<Vec<GuildMembershipModel>> {
+        let results = self.guilds_repository.memberships(participant).await?;
+
+        let mut memberships = Vec::<GuildMembershipModel>::new();
+
+        for (member, guild) in results {
+            let members_count = self
+                .guilds_repository
+                .members_counting_by_guild_id(guild.id)
+                .await?;
+            memberships.push(GuildMembershipModel {
+                guild: guild.to_model(members_count),
+                member_id: member.id,
+                role: member.role,
+                joined_at: member.joined_at,
+            });
+        }
+
+        Ok(memberships)
+    }
+
+    pub async fn add_member(
+        &self,
+        guild_id: i32,
+        participant: QuestParticipants,
+        add_guild_member_model: AddGuildMemberModel,
+    ) -> Result<i32> {
+        let manager = self.ensure_manager(guild_id, participant).await?;
+
+        let new_member = add_guild_member_model.participant()?;
+        let role = add_guild_member_model.role.unwrap_or_default();
+
+        match role {
+            GuildRoles::Owner => {
+                return Err(anyhow::anyhow!("Guild can only have one owner"));
+            }
+            GuildRoles::Officer if manager.role != GuildRoles::Owner => {
+                return Err(anyhow::anyhow!("Only the guild owner can appoint officers"));
+            }
+            _ => {}
+        }
+
+        if self
+            .guilds_repository
+            .member(guild_id, new_member)
+            .await?
+            .is_some()
+        {
+            return Err(anyhow::anyhow!("Already a member of this guild"));
+        }
+
+        let result = self
+            .guilds_repository
+            .add_member(AddGuildMemberEntity {
+                guild_id,
+                guild_commander_id: new_member.guild_commander_id(),
+                adventurer_id: new_member.adventurer_id(),
+                role: role.to_string(),
+                joined_at: chrono::Utc::now().naive_utc(),
+            })
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn change_role(
+        &self,
+        guild_id: i32,
+        participant: QuestParticipants,
+        member_id: i32,
+        change_guild_role_model: ChangeGuildRoleModel,
+    ) -> Result<i32> {
+        let manager = self.ensure_manager(guild_id, participant).await?;
+
+        if manager.role != GuildRoles::Owner {
+            return Err(anyhow::anyhow!("Only the guild owner can change roles"));
+        }
+
+        let member = self.guild_member(guild_id, member_id).await?;
+
+        if member.role == GuildRoles::Owner || change_guild_role_model.role == GuildRoles::Owner {
+            return Err(anyhow::anyhow!("Guild owner role cannot be changed"));
+        }
+
+        let result = self
+            .guilds_repository
+            .change_role(member_id, change_guild_role_model.role.to_string())
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove_member(
+        &self,
+        guild_id: i32,
+        participant: QuestParticipants,
+        member_id: i32,
+    ) -> Result<()> {
+        let manager = self.ensure_manager(guild_id, participant).await?;
+
+        let member = self.guild_member(guild_id, member_id).await?;
+
+        match member.role {
+            GuildRoles::Owner => {
+                return Err(anyhow::anyhow!("Guild owner cannot be removed"));
+            }
+            GuildRoles::Officer if manager.role != GuildRoles::Owner => {
+                return Err(anyhow::anyhow!("Only the guild owner can remove officers"));
+            }
+            _ => {}
+        }
+
+        self.guilds_repository.remove_member(member_id).await?;
+
+        Ok(())
+    }
+
+    pub async fn leave(&self, guild_id: i32, participant: QuestParticipants) -> Result<()> {
+        let member = self
+            .guilds_repository
+            .member(guild_id, participant)
+            .await?
+            .ok_or(anyhow::anyhow!("Not a member of this guild"))?;
+
+        if member.role == GuildRoles::Owner {
+            return Err(anyhow::anyhow!("Guild owner cannot leave the guild"));
+        }
+
+        self.guilds_repository.remove_member(member.id).await?;
+
+        Ok(())
+    }
+
+    async fn ensure_manager(
+        &self,
+        guild_id: i32,
+        participant: QuestParticipants,
+    ) -> Result<GuildMemberEntity> {
+        let member = self
+            .guilds_repository
+            .member(guild_id, participant)
+            .await?
+            .ok_or(anyhow::anyhow!("Not a member of this guild"))?;
+
+        if !member.role.can_manage_members() {
+            return Err(anyhow::anyhow!("Not allowed to manage guild members"));
+        }
+
+        Ok(member)
+    }
+
+    async fn guild_member(&self, guild_id: i32, member_id: i32) -> Result<GuildMemberEntity> {
+        let member = self.guilds_repository.member_details(member_id).await?;
+
+        if member.guild_id != guild_id {
+            return Err(anyhow::anyhow!("Member does not belong to this guild"));
+        }
+
+        Ok(member)
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    config::config_loader::get_idempotency_keys_env,
+    domain::{
+        entities::idempotency_keys::{AddIdempotencyKeyEntity, CompleteIdempotencyKeyEntity},
+        repositories::idempotency_keys::IdempotencyKeysRepository,
+        value_objects::idempotency_model::IdempotencyOutcomes,
+    },
+};
+
+pub struct IdempotencyKeysUsecase<T>
+where
+    T: IdempotencyKeysRepository + Send + Sync,
+{
+    pub idempotency_keys_repository: Arc<T>,
+}
+
+impl<T> IdempotencyKeysUsecase<T>
+where
+    T: IdempotencyKeysRepository + Send + Sync,
+{
+    pub fn new(idempotency_keys_repository: Arc<T>) -> Self {
+        Self {
+            idempotency_keys_repository,
+        }
+    }
+
+    pub async fn begin(
+        &self,
+        user_scope: &str,
+        idempotency_key: &str,
+        request_method: &str,
+        request_path: &str,
+        request_fingerprint: &str,
+    ) -> Result<IdempotencyOutcomes> {
+        let idempotency_keys = get_idempotency_keys_env()?;
+        let now = chrono::Utc::now().naive_utc();
+
+        let add_idempotency_key_entity = AddIdempotencyKeyEntity {
+            user_scope: user_scope.to_string(),
+            idempotency_key: idempotency_key.to_string(),
+            request_method: request_method.to_string(),
+            request_path: request_path.to_string(),
+            request_fingerprint: request_fingerprint.to_string(),
+            created_at: now,
+            expires_at: now + chrono::Duration::hours(idempotency_keys.ttl_hours),
+        };
+
+        if self
+            .idempotency_keys_repository
+            .reserve(
+                add_idempotency_key_entity,
+                now - chrono::Duration::seconds(idempotency_keys.in_progress_timeout_secs),
+            )
+            .await?
+        {
+            return Ok(IdempotencyOutcomes::Proceed);
+        }
+
+        let stored = self
+            .idempotency_keys_repository
+            .find(user_scope, idempotency_key)
+            .await?;
+
+        // key เดียวกันต้องใช้กับ request เดิมเท่านั้น
+        if stored.request_method != request_method
+            || stored.request_path != request_path
+            || stored.request_fingerprint != request_fingerprint
+        {
+            return Ok(IdempotencyOutcomes::Mismatch);
+        }
+
+        match (stored.status_code, stored.response_body) {
+            (Some(status_code), Some(response_body)) => Ok(IdempotencyOutcomes::Replay {
+                status_code: status_code as u16,
+                content_type: stored.content_type,
+                etag: stored.etag,
+                location: stored.location,
+                response_body,
+            }),
+            _ => Ok(IdempotencyOutcomes::InProgress),
+        }
+    }
+
+    pub async fn complete(
+        &self,
+        user_scope: &str,
+        idempotency_key: &str,
+        complete_idempotency_key_entity: CompleteIdempotencyKeyEntity,
+    ) -> Result<()> {
+        self.idempotency_keys_repository
+            .complete(user_scope, idempotency_key, complete_idempotency_key_entity)
+            .await
+    }
+
+    pub async fn release(&self, user_scope: &str, idempotency_key: &str) -> Result<()> {
+        self.idempotency_keys_repository
+            .release(user_scope, idempotency_key)
+            .await
+    }
+
+    pub async fn purge_expired(&self) -> Result<usize> {
+        self.idempotency_keys_repository
+            .purge_expired(chrono::Utc::now().naive_utc())
+            .await
+    }
+}
@@ -1,39 +1,66 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use tracing::error;
 
-use crate::domain::{
-    repositories::{
-        journey_ledger::JourneyLedgerRepository, quest_viewing::QuestViewingRepository,
-    },
-    value_objects::{
-        quest_adventurer_juntion::MAX_ADVENTURERS_PER_QUEST, quest_statuses::QuestStatuses,
+use crate::{
+    application::usecases::achievements::AchievementsUsecase,
+    config::config_loader::get_adventurer_levels_env,
+    domain::{
+        repositories::{
+            achievements::AchievementsRepository, journey_ledger::JourneyLedgerRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_participants::QuestParticipants,
+            quest_report_model::{AddQuestReportModel, validate_report_body},
+            quest_statuses::QuestStatuses,
+        },
     },
 };
 
-pub struct JourneyLedgerUsecase<T1, T2>
+pub struct JourneyLedgerUsecase<T1, T2, T3>
 where
     T1: JourneyLedgerRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: AchievementsRepository + Send + Sync,
 {
     pub journey_ledger_repository: Arc<T1>,
     pub quest_viewing_repository: Arc<T2>,
+    pub achievements_usecase: AchievementsUsecase<T3>,
 }
 
-impl<T1, T2> JourneyLedgerUsecase<T1, T2>
+impl<T1, T2, T3> JourneyLedgerUsecase<T1, T2, T3>
 where
     T1: JourneyLedgerRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: AchievementsRepository + Send + Sync,
 {
-    pub fn new(journey_ledger_repository: Arc<T1>, quest_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        journey_ledger_repository: Arc<T1>,
+        quest_viewing_repository: Arc<T2>,
+        achievements_repository: Arc<T3>,
+    ) -> Self {
         Self {
             journey_ledger_repository,
             quest_viewing_repository,
+            achievements_usecase: AchievementsUsecase::new(achievements_repository),
         }
     }
 
-    pub async fn in_journey(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
-        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+    pub async fn in_journey(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        expected_version: Option<i32>,
+    ) -> Result<i32> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(
+                quest_id,
+                Some(QuestParticipants::GuildCommander(guild_commander_id)),
+            )
+            .await?;
 
         let adventurers_number = self
             .quest_viewing_repository
@@ -42,41 +69,101 @@ where
 
         let conditions_to_update = (quest.status.to_string() == QuestStatuses::Open.to_string()
             || quest.status.to_string() == QuestStatuses::Failed.to_string())
-            && adventurers_number > 0
-            && adventurers_number <= MAX_ADVENTURERS_PER_QUEST;
+            && adventurers_number >= quest.min_crew as i64
+            && adventurers_number <= quest.max_crew as i64;
 
         if !conditions_to_update {
             return Err(anyhow::anyhow!("Invalid condition to change status"));
         }
 
+        if let Some(starts_at) = quest.starts_at
+            && chrono::Utc::now().naive_utc() < starts_at
+        {
+            return Err(anyhow::anyhow!("Quest cannot start before starts_at"));
+        }
+
+        let pending_reconfirmations = self
+            .quest_viewing_repository
+            .pending_reconfirmations_count(quest_id)
+            .await?;
+
+        if pending_reconfirmations > 0 {
+            return Err(anyhow::anyhow!(
+                "{} crew members have not reconfirmed the quest changes",
+                pending_reconfirmations
+            ));
+        }
+
         let result = self
             .journey_ledger_repository
-            .in_journey(quest_id, guild_commander_id)
+            .in_journey(quest_id, guild_commander_id, expected_version)
             .await?;
 
         Ok(result)
     }
 
-    pub async fn to_completed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
-        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+    pub async fn to_completed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        expected_version: Option<i32>,
+        add_quest_report_model: AddQuestReportModel,
+    ) -> Result<i32> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(
+                quest_id,
+                Some(QuestParticipants::GuildCommander(guild_commander_id)),
+            )
+            .await?;
 
-        let conditions_to_update =
-            quest.status.to_string() == QuestStatuses::InJourney.to_string();
+        let conditions_to_update = quest.status.to_string() == QuestStatuses::InJourney.to_string();
 
         if !conditions_to_update {
             return Err(anyhow::anyhow!("Invalid condition to change status"));
         }
 
+        let report_body = add_quest_report_model.body();
+        validate_report_body(&report_body)?;
+
+        if quest.require_checkpoints {
+            let pending_checkpoints = self
+                .quest_viewing_repository
+                .pending_mandatory_checkpoints_count(quest_id)
+                .await?;
+
+            if pending_checkpoints > 0 {
+                return Err(anyhow::anyhow!(
+                    "{} mandatory checkpoints are not done yet",
+                    pending_checkpoints
+                ));
+            }
+        }
+
         let result = self
             .journey_ledger_repository
-            .to_completed(quest_id, guild_commander_id)
+            .to_completed(quest_id, guild_commander_id, expected_version, report_body)
             .await?;
 
+        self.evaluate_achievements(result).await;
+
         Ok(result)
     }
 
-    pub async fn to_failed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
-        let quest = self.quest_viewing_repository.view_details(quest_id).await?;
+    pub async fn to_failed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        expected_version: Option<i32>,
+        add_quest_report_model: AddQuestReportModel,
+    ) -> Result<i32> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(
+                quest_id,
+                Some(QuestParticipants::GuildCommander(guild_commander_id)),
+            )
+            .await?;
 
         let conditions_to_update = quest.status.to_string() == QuestStatuses::InJourney.to_string();
 
@@ -84,11 +171,151 @@ where
             return Err(anyhow::anyhow!("Invalid condition to change status"));
         }
 
+        let report_body = add_quest_report_model.body();
+        validate_report_body(&report_body)?;
+
+        let adventurer_levels = get_adventurer_levels_env()?;
+
         let result = self
             .journey_ledger_repository
-            .to_failed(quest_id, guild_commander_id)
+            .to_failed(
+                quest_id,
+                guild_commander_id,
+                expected_version,
+                adventurer_levels.failure_experience_penalty,
+                report_body,
+            )
             .await?;
 
+        self.evaluate_achievements(result).await;
+
         Ok(result)
     }
+
+    pub async fn expire_overdue(&self) -> Result<Vec<i32>> {
+        let overdue_quests = self
+            .quest_viewing_repository
+            .overdue_in_journey(chrono::Utc::now().naive_utc())
+            .await?;
+
+        let mut expired_quest_ids = Vec::<i32>::new();
+
+        // quest หนึ่ง fail ไม่ควรทำให้ quest ที่เหลือในรอบนี้ค้าง
+        for quest in overdue_quests {
+            match self
+                .to_failed(
+                    quest.id,
+                    quest.guild_commander_id,
+                    None,
+                    AddQuestReportModel {
+                        body: Some("Automatically failed after the due date passed".to_string()),
+                    },
+                )
+                .await
+            {
+                Ok(quest_id) => expired_quest_ids.push(quest_id),
+                Err(e) => error!("Failed to expire overdue quest {} :{}", quest.id, e),
+            }
+        }
+
+        Ok(expired_quest_ids)
+    }
+
+    // quest เปลี่ยนสถานะสำเร็จแล้ว badge ประเมินไม่ผ่านไม่ควรทำให้ request fail
+    async fn evaluate_achievements(&self, quest_id: i32) {
+        if let Err(e) = self.achievements_usecase.evaluate_crew(quest_id).await {
+            error!("Failed to evaluate achievements :{}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::quests::QuestEntity,
+        repositories::{
+            achievements::MockAchievementsRepository, journey_ledger::MockJourneyLedgerRepository,
+            quest_viewing::MockQuestViewingRepository,
+        },
+    };
+
+    fn quest(status: QuestStatuses) -> QuestEntity {
+        let now = chrono::Utc::now().naive_utc();
+        QuestEntity {
+            id: 1,
+            name: "Quest".to_string(),
+            description: None,
+            status,
+            guild_commander_id: 1,
+            created_at: now,
+            updated_at: now,
+            min_crew: 1,
+            max_crew: 4,
+            join_policy: Default::default(),
+            starts_at: None,
+            join_deadline: None,
+            due_at: None,
+            reward_gold: 0,
+            reward_experience: 0,
+            min_level: 1,
+            version: 1,
+            require_checkpoints: false,
+            guild_id: None,
+            visibility: Default::default(),
+        }
+    }
+
+    fn usecase(
+        status: QuestStatuses,
+        journey_ledger_repository: MockJourneyLedgerRepository,
+    ) -> JourneyLedgerUsecase<
+        MockJourneyLedgerRepository,
+        MockQuestViewingRepository,
+        MockAchievementsRepository,
+    > {
+        let mut quest_viewing_repository = MockQuestViewingRepository::new();
+        quest_viewing_repository
+            .expect_view_details()
+            .returning(move |_, _| {
+                let quest = quest(status.clone());
+                Box::pin(async move { Ok(quest) })
+            });
+        quest_viewing_repository
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(2) }));
+        quest_viewing_repository
+            .expect_pending_reconfirmations_count()
+            .returning(|_| Box::pin(async { Ok(0) }));
+
+        JourneyLedgerUsecase::new(
+            Arc::new(journey_ledger_repository),
+            Arc::new(quest_viewing_repository),
+            Arc::new(MockAchievementsRepository::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn in_journey_rejects_finished_quest() {
+        let result = usecase(QuestStatuses::Completed, MockJourneyLedgerRepository::new())
+            .in_journey(1, 1, None)
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn in_journey_restarts_failed_quest() {
+        let mut journey_ledger_repository = MockJourneyLedgerRepository::new();
+        journey_ledger_repository
+            .expect_in_journey()
+            .times(1)
+            .returning(|quest_id, _, _| Box::pin(async move { Ok(quest_id) }));
+
+        let result = usecase(QuestStatuses::Failed, journey_ledger_repository)
+            .in_journey(1, 1, None)
+            .await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
 }
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::domain::{
+    repositories::leaderboards::LeaderboardsRepository,
+    value_objects::{
+        leaderboard_model::{
+            AdventurerLeaderboardEntryModel, AdventurerLeaderboardQuery,
+            GuildCommanderLeaderboardEntryModel, GuildCommanderLeaderboardQuery,
+        },
+        pagination::{PaginatedModel, PaginationQuery},
+    },
+};
+
+pub struct LeaderboardsUsecase<T>
+where
+    T: LeaderboardsRepository + Send + Sync,
+{
+    pub leaderboards_repository: Arc<T>,
+}
+
+impl<T> LeaderboardsUsecase<T>
+where
+    T: LeaderboardsRepository + Send + Sync,
+{
+    pub fn new(leaderboards_repository: Arc<T>) -> Self {
+        Self {
+            leaderboards_repository,
+        }
+    }
+
+    pub async fn adventurers(
+        &self,
+        leaderboard_query: AdventurerLeaderboardQuery,
+        pagination: PaginationQuery,
+    ) -> Result<PaginatedModel<AdventurerLeaderboardEntryModel>> {
+        let since = leaderboard_query
+            .window
+            .unwrap_or_default()
+            .since(chrono::Utc::now().date_naive());
+
+        let results = self
+            .leaderboards_repository
+            .adventurers(
+                since,
+                leaderboard_query.sort.unwrap_or_default(),
+                pagination.page_size(),
+                pagination.offset(),
+            )
+            .await?;
+
+        let total = self
+            .leaderboards_repository
+            .adventurers_counting(since)
+            .await?;
+
+        Ok(PaginatedModel {
+            items: results
+                .iter()
+                .enumerate()
+                .map(|(index, entity)| {
+                    AdventurerLeaderboardEntryModel::from_entity(
+                        pagination.offset() + index as i64 + 1,
+                        entity,
+                    )
+                })
+                .collect(),
+            page: pagination.page(),
+            page_size: pagination.page_size(),
+            total,
+        })
+    }
+
+    pub async fn guild_commanders(
+        &self,
+        leaderboard_query: GuildCommanderLeaderboardQuery,
+        pagination: PaginationQuery,
+    ) -> Result<PaginatedModel<GuildCommanderLeaderboardEntryModel>> {
+        let since = leaderboard_query
+            .window
+            .unwrap_or_default()
+            .since(chrono::Utc::now().date_naive());
+
+        let results = self
+            .leaderboards_repository
+            .guild_commanders(
+                since,
+                leaderboard_query.sort.unwrap_or_default(),
+                pagination.page_size(),
+                pagination.offset(),
+            )
+            .await?;
+
+        let total = self
+            .leaderboards_repository
+            .guild_commanders_counting(since)
+            .await?;
+
+        Ok(PaginatedModel {
+            items: results
+                .iter()
+                .enumerate()
+                .map(|(index, entity)| {
+                    GuildCommanderLeaderboardEntryModel::from_entity(
+                        pagination.offset() + index as i64 + 1,
+                        entity,
+                    )
+                })
+                .collect(),
+            page: pagination.page(),
+            page_size: pagination.page_size(),
+            total,
+        })
+    }
+}
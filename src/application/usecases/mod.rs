@@ -1,7 +1,21 @@
+pub mod achievements;
+pub mod adventurer_ledger;
 pub mod adventures;
-pub mod crew_swithboard;
 pub mod authentication;
+pub mod crew_invitations;
+pub mod crew_swithboard;
 pub mod guild_commanders;
+pub mod guilds;
+pub mod idempotency_keys;
 pub mod journey_ledger;
+pub mod leaderboards;
+pub mod quest_checkpoints;
+pub mod quest_comments;
 pub mod quest_ops;
-pub mod quest_viewing;
\ No newline at end of file
+pub mod quest_participants;
+pub mod quest_purge;
+pub mod quest_ratings;
+pub mod quest_recurrences;
+pub mod quest_reports;
+pub mod quest_templates;
+pub mod quest_viewing;
@@ -0,0 +1,265 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::usecases::quest_participants::QuestParticipantsUsecase,
+    domain::{
+        entities::{quest_checkpoints::QuestCheckpointEntity, quests::QuestEntity},
+        repositories::{
+            crew_swithboard::CrewSwithboardRepository,
+            quest_checkpoints::QuestCheckpointsRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_checkpoint_model::{
+                AddQuestCheckpointModel, CompleteQuestCheckpointModel, EditQuestCheckpointModel,
+                QuestCheckpointModel, validate_checkpoint_note, validate_checkpoint_title,
+            },
+            quest_participants::QuestParticipants,
+            quest_statuses::QuestStatuses,
+        },
+    },
+};
+
+pub struct QuestCheckpointsUsecase<T1, T2, T3>
+where
+    T1: QuestCheckpointsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    pub quest_checkpoints_repository: Arc<T1>,
+    pub quest_viewing_repository: Arc<T2>,
+    pub crew_swithboard_repository: Arc<T3>,
+    pub quest_participants_usecase: QuestParticipantsUsecase<T2, T3>,
+}
+
+impl<T1, T2, T3> QuestCheckpointsUsecase<T1, T2, T3>
+where
+    T1: QuestCheckpointsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    pub fn new(
+        quest_checkpoints_repository: Arc<T1>,
+        quest_viewing_repository: Arc<T2>,
+        crew_swithboard_repository: Arc<T3>,
+    ) -> Self {
+        Self {
+            quest_checkpoints_repository,
+            quest_participants_usecase: QuestParticipantsUsecase::new(
+                Arc::clone(&quest_viewing_repository),
+                Arc::clone(&crew_swithboard_repository),
+            ),
+            quest_viewing_repository,
+            crew_swithboard_repository,
+        }
+    }
+
+    pub async fn checkpoints(
+        &self,
+        quest_id: i32,
+        participant: QuestParticipants,
+    ) -> Result<Vec<QuestCheckpointModel>> {
+        self.quest_participants_usecase
+            .ensure_participant(quest_id, participant, "checkpoints")
+            .await?;
+
+        let results = self
+            .quest_checkpoints_repository
+            .by_quest_id(quest_id)
+            .await?;
+
+        Ok(results
+            .iter()
+            .map(|checkpoint| checkpoint.to_model())
+            .collect())
+    }
+
+    pub async fn add(
+        &self,
+        quest_id: i32,
+        participant: QuestParticipants,
+        add_quest_checkpoint_model: AddQuestCheckpointModel,
+    ) -> Result<i32> {
+        self.ensure_commander_can_manage(quest_id, participant)
+            .await?;
+
+        validate_checkpoint_title(&add_quest_checkpoint_model.title)?;
+
+        let result = self
+            .quest_checkpoints_repository
+            .add(add_quest_checkpoint_model.to_entity(quest_id))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn edit(
+        &self,
+        quest_id: i32,
+        checkpoint_id: i32,
+        participant: QuestParticipants,
+        edit_quest_checkpoint_model: EditQuestCheckpointModel,
+    ) -> Result<i32> {
+        self.ensure_commander_can_manage(quest_id, participant)
+            .await?;
+        self.checkpoint_on_quest(quest_id, checkpoint_id).await?;
+
+        if let Some(title) = &edit_quest_checkpoint_model.title {
+            validate_checkpoint_title(title)?;
+        }
+
+        let result = self
+            .quest_checkpoints_repository
+            .edit(checkpoint_id, edit_quest_checkpoint_model.to_entity())
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove(
+        &self,
+        quest_id: i32,
+        checkpoint_id: i32,
+        participant: QuestParticipants,
+    ) -> Result<()> {
+        self.ensure_commander_can_manage(quest_id, participant)
+            .await?;
+        self.checkpoint_on_quest(quest_id, checkpoint_id).await?;
+
+        self.quest_checkpoints_repository
+            .remove(checkpoint_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn complete(
+        &self,
+        quest_id: i32,
+        checkpoint_id: i32,
+        participant: QuestParticipants,
+        complete_quest_checkpoint_model: CompleteQuestCheckpointModel,
+    ) -> Result<i32> {
+        let adventurer_id = self.ensure_crew_in_journey(quest_id, participant).await?;
+
+        let checkpoint = self.checkpoint_on_quest(quest_id, checkpoint_id).await?;
+
+        if checkpoint.completed_at.is_some() {
+            return Err(anyhow::anyhow!("Checkpoint is already done"));
+        }
+
+        validate_checkpoint_note(&complete_quest_checkpoint_model.note)?;
+
+        let result = self
+            .quest_checkpoints_repository
+            .complete(
+                checkpoint_id,
+                adventurer_id,
+                complete_quest_checkpoint_model.note,
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn reopen(
+        &self,
+        quest_id: i32,
+        checkpoint_id: i32,
+        participant: QuestParticipants,
+    ) -> Result<i32> {
+        self.ensure_crew_in_journey(quest_id, participant).await?;
+
+        let checkpoint = self.checkpoint_on_quest(quest_id, checkpoint_id).await?;
+
+        if checkpoint.completed_at.is_none() {
+            return Err(anyhow::anyhow!("Checkpoint is not done yet"));
+        }
+
+        let result = self
+            .quest_checkpoints_repository
+            .reopen(checkpoint_id)
+            .await?;
+
+        Ok(result)
+    }
+
+    // commander เจ้าของ quest จัดการ checkpoint ได้จนกว่า quest จะจบ
+    async fn ensure_commander_can_manage(
+        &self,
+        quest_id: i32,
+        participant: QuestParticipants,
+    ) -> Result<QuestEntity> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(quest_id, Some(participant))
+            .await?;
+
+        if participant.guild_commander_id() != Some(quest.guild_commander_id) {
+            return Err(anyhow::anyhow!(
+                "Only the owning guild commander can manage checkpoints"
+            ));
+        }
+
+        let manageable = quest.status.to_string() == QuestStatuses::Open.to_string()
+            || quest.status.to_string() == QuestStatuses::InJourney.to_string();
+
+        if !manageable {
+            return Err(anyhow::anyhow!(
+                "Checkpoints can only be managed while the quest is Open or InJourney"
+            ));
+        }
+
+        Ok(quest)
+    }
+
+    async fn ensure_crew_in_journey(
+        &self,
+        quest_id: i32,
+        participant: QuestParticipants,
+    ) -> Result<i32> {
+        let adventurer_id = participant
+            .adventurer_id()
+            .ok_or_else(|| anyhow::anyhow!("Only crew members can update checkpoints"))?;
+
+        let quest = self
+            .quest_viewing_repository
+            .view_details(quest_id, Some(participant))
+            .await?;
+
+        let is_crew_member = self
+            .crew_swithboard_repository
+            .is_crew_member(quest_id, adventurer_id)
+            .await?;
+
+        if !is_crew_member {
+            return Err(anyhow::anyhow!("Only crew members can update checkpoints"));
+        }
+
+        if quest.status.to_string() != QuestStatuses::InJourney.to_string() {
+            return Err(anyhow::anyhow!(
+                "Checkpoints can only be updated while the quest is InJourney"
+            ));
+        }
+
+        Ok(adventurer_id)
+    }
+
+    async fn checkpoint_on_quest(
+        &self,
+        quest_id: i32,
+        checkpoint_id: i32,
+    ) -> Result<QuestCheckpointEntity> {
+        let checkpoint = self
+            .quest_checkpoints_repository
+            .view_details(checkpoint_id)
+            .await?;
+
+        if checkpoint.quest_id != quest_id {
+            return Err(anyhow::anyhow!("Checkpoint not found"));
+        }
+
+        Ok(checkpoint)
+    }
+}
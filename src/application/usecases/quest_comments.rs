@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::usecases::quest_participants::QuestParticipantsUsecase,
+    domain::{
+        repositories::{
+            crew_swithboard::CrewSwithboardRepository, quest_comments::QuestCommentsRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            pagination::{PaginatedModel, PaginationQuery},
+            quest_comment_model::{
+                AddQuestCommentModel, EditQuestCommentModel, QuestCommentModel,
+                validate_comment_body,
+            },
+            quest_participants::QuestParticipants,
+        },
+    },
+};
+
+pub struct QuestCommentsUsecase<T1, T2, T3>
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    pub quest_comments_repository: Arc<T1>,
+    pub quest_participants_usecase: QuestParticipantsUsecase<T2, T3>,
+}
+
+impl<T1, T2, T3> QuestCommentsUsecase<T1, T2, T3>
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    pub fn new(
+        quest_comments_repository: Arc<T1>,
+        quest_viewing_repository: Arc<T2>,
+        crew_swithboard_repository: Arc<T3>,
+    ) -> Self {
+        Self {
+            quest_comments_repository,
+            quest_participants_usecase: QuestParticipantsUsecase::new(
+                quest_viewing_repository,
+                crew_swithboard_repository,
+            ),
+        }
+    }
+
+    pub async fn comments(
+        &self,
+        quest_id: i32,
+        participant: QuestParticipants,
+        pagination: PaginationQuery,
+    ) -> Result<PaginatedModel<QuestCommentModel>> {
+        self.quest_participants_usecase
+            .ensure_participant(quest_id, participant, "comments")
+            .await?;
+
+        let threads = self
+            .quest_comments_repository
+            .threads_by_quest_id(quest_id, pagination.page_size(), pagination.offset())
+            .await?;
+
+        let total = self
+            .quest_comments_repository
+            .threads_counting_by_quest_id(quest_id)
+            .await?;
+
+        let replies = self
+            .quest_comments_repository
+            .replies_by_parent_ids(threads.iter().map(|thread| thread.id).collect())
+            .await?;
+
+        let items = threads
+            .iter()
+            .map(|thread| {
+                thread.to_model(
+                    replies
+                        .iter()
+                        .filter(|reply| reply.parent_comment_id == Some(thread.id))
+                        .map(|reply| reply.to_model(Vec::new()))
+                        .collect(),
+                )
+            })
+            .collect();
+
+        Ok(PaginatedModel {
+            items,
+            page: pagination.page(),
+            page_size: pagination.page_size(),
+            total,
+        })
+    }
+
+    pub async fn add(
+        &self,
+        quest_id: i32,
+        participant: QuestParticipants,
+        add_quest_comment_model: AddQuestCommentModel,
+    ) -> Result<i32> {
+        self.quest_participants_usecase
+            .ensure_participant(quest_id, participant, "comments")
+            .await?;
+
+        validate_comment_body(&add_quest_comment_model.body)?;
+
+        // ตอบ reply จะต่อท้าย thread เดียวกัน thread ลึกแค่ชั้นเดียว
+        let parent_comment_id = match add_quest_comment_model.parent_comment_id {
+            Some(parent_comment_id) => {
+                let parent_comment = self
+                    .quest_comments_repository
+                    .view_details(parent_comment_id)
+                    .await?;
+
+                if parent_comment.quest_id != quest_id {
+                    return Err(anyhow::anyhow!("Parent comment is not on this quest"));
+                }
+
+                Some(
+                    parent_comment
+                        .parent_comment_id
+                        .unwrap_or(parent_comment.id),
+                )
+            }
+            None => None,
+        };
+
+        let add_quest_comment_entity =
+            add_quest_comment_model.to_entity(quest_id, parent_comment_id, participant);
+
+        let result = self
+            .quest_comments_repository
+            .add(add_quest_comment_entity)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn edit(
+        &self,
+        quest_id: i32,
+        comment_id: i32,
+        participant: QuestParticipants,
+        edit_quest_comment_model: EditQuestCommentModel,
+    ) -> Result<i32> {
+        self.quest_participants_usecase
+            .ensure_participant(quest_id, participant, "comments")
+            .await?;
+
+        let comment = self
+            .quest_comments_repository
+            .view_details(comment_id)
+            .await?;
+
+        if comment.quest_id != quest_id || comment.deleted_at.is_some() {
+            return Err(anyhow::anyhow!("Comment not found"));
+        }
+
+        if !comment.is_authored_by(participant) {
+            return Err(anyhow::anyhow!("Only the author can edit this comment"));
+        }
+
+        validate_comment_body(&edit_quest_comment_model.body)?;
+
+        let result = self
+            .quest_comments_repository
+            .edit(comment_id, edit_quest_comment_model.body.trim().to_string())
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove(
+        &self,
+        quest_id: i32,
+        comment_id: i32,
+        participant: QuestParticipants,
+    ) -> Result<()> {
+        let quest = self
+            .quest_participants_usecase
+            .ensure_participant(quest_id, participant, "comments")
+            .await?;
+
+        let comment = self
+            .quest_comments_repository
+            .view_details(comment_id)
+            .await?;
+
+        if comment.quest_id != quest_id || comment.deleted_at.is_some() {
+            return Err(anyhow::anyhow!("Comment not found"));
+        }
+
+        // commander เจ้าของ quest ลบ comment ของคนอื่นได้ในฐานะ moderator
+        let moderated = if comment.is_authored_by(participant) {
+            false
+        } else if participant.guild_commander_id() == Some(quest.guild_commander_id) {
+            true
+        } else {
+            return Err(anyhow::anyhow!("Only the author can delete this comment"));
+        };
+
+        self.quest_comments_repository
+            .remove(comment_id, moderated)
+            .await?;
+
+        Ok(())
+    }
+}
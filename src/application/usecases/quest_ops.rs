@@ -2,9 +2,26 @@ use std::sync::Arc;
 
 use anyhow::Result;
 
-use crate::domain::{
-    repositories::{quest_ops::QuestOpsRepository, quest_viewing::QuestViewingRepository},
-    value_objects::quest_model::{AddQuestModel, EditQuestModel},
+use crate::{
+    config::config_loader::{get_crew_limits_env, get_quest_retention_env},
+    domain::{
+        entities::{quest_revisions::AddQuestRevisionEntity, quests::AddQuestEntity},
+        repositories::{quest_ops::QuestOpsRepository, quest_viewing::QuestViewingRepository},
+        value_objects::{
+            quest_adventurer_juntion::{validate_crew_limits, validate_crew_limits_for_crew},
+            quest_model::{
+                AddQuestModel, EditQuestModel, QuestModel, validate_quest_due_at,
+                validate_quest_min_level, validate_quest_reward, validate_quest_schedule,
+                validate_quest_visibility,
+            },
+            quest_participants::QuestParticipants,
+            quest_revision_model::{
+                QuestFieldsModel, QuestRevisionModel, has_material_changes, quest_edit_changes,
+            },
+            quest_statuses::QuestStatuses,
+            quest_tag_model::{SetQuestTagsModel, normalize_tags},
+        },
+    },
 };
 
 pub struct QuestOpsUsecase<T1, T2>
@@ -33,35 +50,181 @@ where
         guild_commander_id: i32,
         add_quest_model: AddQuestModel,
     ) -> Result<i32> {
-        let add_quest_entity = add_quest_model.to_entity(guild_commander_id);
-        let result = self.quest_ops_repository.add(add_quest_entity).await?;
+        let (add_quest_entity, tags) =
+            self.to_add_quest_entity(guild_commander_id, add_quest_model)?;
+
+        let result = self
+            .quest_ops_repository
+            .add(add_quest_entity, tags)
+            .await?;
+
         Ok(result)
     }
 
+    // validate แล้วคืน entity พร้อม tag ให้ผู้เรียกที่ต้องบันทึก quest ใน transaction ของตัวเอง
+    pub fn to_add_quest_entity(
+        &self,
+        guild_commander_id: i32,
+        add_quest_model: AddQuestModel,
+    ) -> Result<(AddQuestEntity, Vec<String>)> {
+        let crew_limits = get_crew_limits_env()?;
+
+        let add_quest_entity =
+            add_quest_model.to_entity(guild_commander_id, crew_limits.max_crew_ceiling);
+
+        validate_crew_limits(
+            add_quest_entity.min_crew,
+            add_quest_entity.max_crew,
+            crew_limits.max_crew_ceiling,
+        )?;
+
+        validate_quest_schedule(
+            add_quest_entity.starts_at,
+            add_quest_entity.join_deadline,
+            add_quest_entity.due_at,
+        )?;
+
+        validate_quest_due_at(add_quest_entity.due_at, chrono::Utc::now().naive_utc())?;
+
+        validate_quest_reward(
+            add_quest_entity.reward_gold,
+            add_quest_entity.reward_experience,
+        )?;
+
+        validate_quest_min_level(add_quest_entity.min_level)?;
+
+        validate_quest_visibility(add_quest_entity.guild_id, &add_quest_entity.visibility)?;
+
+        let tags = normalize_tags(&add_quest_model.tags.unwrap_or_default())?;
+
+        Ok((add_quest_entity, tags))
+    }
+
     pub async fn edit(
         &self,
         quest_id: i32,
         guild_commander_id: i32,
+        expected_version: Option<i32>,
         edit_quest_model: EditQuestModel,
     ) -> Result<i32> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(
+                quest_id,
+                Some(QuestParticipants::GuildCommander(guild_commander_id)),
+            )
+            .await?;
+
+        if quest.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest is not owned by this guild commander"
+            ));
+        }
+
+        if quest.status != QuestStatuses::Open {
+            return Err(anyhow::anyhow!("Only Open quests can be edited"));
+        }
+
         let adventurers_count = self
             .quest_viewing_repository
             .adventurers_counting_by_quest_id(quest_id)
             .await?;
 
-        if adventurers_count > 0 {
-            return Err(anyhow::anyhow!("Quest has adventurers, cannot delete"));
+        if edit_quest_model.min_crew.is_some() || edit_quest_model.max_crew.is_some() {
+            let crew_limits = get_crew_limits_env()?;
+
+            validate_crew_limits(
+                edit_quest_model.min_crew.unwrap_or(quest.min_crew),
+                edit_quest_model.max_crew.unwrap_or(quest.max_crew),
+                crew_limits.max_crew_ceiling,
+            )?;
+        }
+
+        validate_crew_limits_for_crew(
+            edit_quest_model.min_crew.unwrap_or(quest.min_crew),
+            edit_quest_model.max_crew.unwrap_or(quest.max_crew),
+            adventurers_count,
+            edit_quest_model
+                .join_deadline
+                .or(quest.join_deadline)
+                .is_some_and(|join_deadline| join_deadline <= chrono::Utc::now().naive_utc()),
+        )?;
+
+        if edit_quest_model.starts_at.is_some()
+            || edit_quest_model.join_deadline.is_some()
+            || edit_quest_model.due_at.is_some()
+        {
+            validate_quest_schedule(
+                edit_quest_model.starts_at.or(quest.starts_at),
+                edit_quest_model.join_deadline.or(quest.join_deadline),
+                edit_quest_model.due_at.or(quest.due_at),
+            )?;
+
+            validate_quest_due_at(edit_quest_model.due_at, chrono::Utc::now().naive_utc())?;
         }
 
+        validate_quest_reward(
+            edit_quest_model.reward_gold.unwrap_or(0),
+            edit_quest_model.reward_experience.unwrap_or(0),
+        )?;
+
+        if let Some(min_level) = edit_quest_model.min_level {
+            validate_quest_min_level(min_level)?;
+        }
+
+        if edit_quest_model.guild_id.is_some() || edit_quest_model.visibility.is_some() {
+            validate_quest_visibility(
+                edit_quest_model.guild_id.or(quest.guild_id),
+                &edit_quest_model
+                    .visibility
+                    .clone()
+                    .unwrap_or(quest.visibility.clone())
+                    .to_string(),
+            )?;
+        }
+
+        let changes = quest_edit_changes(&quest, &edit_quest_model);
+
+        if changes.is_empty() {
+            return Ok(quest_id);
+        }
+
+        // แก้ field สำคัญหลังมี crew แล้ว crew ต้องยืนยันใหม่
+        let add_quest_revision_entity = AddQuestRevisionEntity {
+            guild_commander_id,
+            changes: serde_json::to_value(&changes)?,
+            requires_reconfirmation: adventurers_count > 0 && has_material_changes(&changes),
+            created_at: chrono::Utc::now().naive_utc(),
+        };
+
         let edit_quest_entity = edit_quest_model.to_entity(guild_commander_id);
         let result = self
             .quest_ops_repository
-            .edit(quest_id, edit_quest_entity)
+            .edit(
+                quest_id,
+                expected_version,
+                edit_quest_entity,
+                add_quest_revision_entity,
+            )
             .await?;
         Ok(result)
     }
 
     pub async fn remove(&self, quest_id: i32, guild_commander_id: i32) -> Result<()> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(
+                quest_id,
+                Some(QuestParticipants::GuildCommander(guild_commander_id)),
+            )
+            .await?;
+
+        if quest.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest is not owned by this guild commander"
+            ));
+        }
+
         let adventurers_count = self
             .quest_viewing_repository
             .adventurers_counting_by_quest_id(quest_id)
@@ -71,10 +234,468 @@ where
             return Err(anyhow::anyhow!("Quest has adventurers, cannot delete"));
         }
 
+        self.quest_ops_repository
+            .remove(quest_id, guild_commander_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn set_tags(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        set_quest_tags_model: SetQuestTagsModel,
+    ) -> Result<()> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(
+                quest_id,
+                Some(QuestParticipants::GuildCommander(guild_commander_id)),
+            )
+            .await?;
+
+        if quest.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest is not owned by this guild commander"
+            ));
+        }
+
+        let tags = normalize_tags(&set_quest_tags_model.tags)?;
+
+        self.quest_ops_repository.set_tags(quest_id, tags).await?;
+
+        Ok(())
+    }
+
+    pub async fn clone_quest(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(
+                quest_id,
+                Some(QuestParticipants::GuildCommander(guild_commander_id)),
+            )
+            .await?;
+
+        if quest.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest is not owned by this guild commander"
+            ));
+        }
+
+        let tags = self
+            .quest_viewing_repository
+            .tags_by_quest_id(quest_id)
+            .await?;
+
+        // กำหนดเวลาไม่ copy เพราะของเดิมมักผ่านไปแล้ว
+        let add_quest_model = AddQuestModel {
+            name: quest.name,
+            description: quest.description,
+            min_crew: Some(quest.min_crew),
+            max_crew: Some(quest.max_crew),
+            join_policy: Some(quest.join_policy),
+            starts_at: None,
+            join_deadline: None,
+            due_at: None,
+            reward_gold: Some(quest.reward_gold),
+            reward_experience: Some(quest.reward_experience),
+            min_level: Some(quest.min_level),
+            require_checkpoints: Some(quest.require_checkpoints),
+            tags: Some(tags),
+            guild_id: quest.guild_id,
+            visibility: Some(quest.visibility),
+        };
+
+        self.add(guild_commander_id, add_quest_model).await
+    }
+
+    pub async fn deleted_quests(&self, guild_commander_id: i32) -> Result<Vec<QuestModel>> {
+        let quest_retention = get_quest_retention_env()?;
+        let deleted_after = chrono::Utc::now().naive_utc()
+            - chrono::Duration::hours(quest_retention.restore_grace_hours);
+
+        let results = self
+            .quest_ops_repository
+            .deleted_by_guild_commander_id(guild_commander_id, deleted_after)
+            .await?;
+
+        let mut quests_model = Vec::<QuestModel>::new();
+
+        for result in results {
+            let adventurers_count = self
+                .quest_viewing_repository
+                .adventurers_counting_by_quest_id(result.id)
+                .await?;
+            let tags = self
+                .quest_viewing_repository
+                .tags_by_quest_id(result.id)
+                .await?;
+            quests_model.push(result.to_model(adventurers_count, tags));
+        }
+
+        Ok(quests_model)
+    }
+
+    pub async fn restore(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+        let quest_retention = get_quest_retention_env()?;
+        let deleted_after = chrono::Utc::now().naive_utc()
+            - chrono::Duration::hours(quest_retention.restore_grace_hours);
+
+        let restorable_quests = self
+            .quest_ops_repository
+            .deleted_by_guild_commander_id(guild_commander_id, deleted_after)
+            .await?;
+
+        if !restorable_quests.iter().any(|quest| quest.id == quest_id) {
+            return Err(anyhow::anyhow!(
+                "Deleted quest not found or restore grace period has passed"
+            ));
+        }
+
         let result = self
             .quest_ops_repository
-            .remove(quest_id, guild_commander_id)
+            .restore(quest_id, guild_commander_id, deleted_after)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn revert(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        revision_number: i32,
+        expected_version: Option<i32>,
+    ) -> Result<i32> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(
+                quest_id,
+                Some(QuestParticipants::GuildCommander(guild_commander_id)),
+            )
+            .await?;
+
+        if quest.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest is not owned by this guild commander"
+            ));
+        }
+
+        if quest.status != QuestStatuses::Open {
+            return Err(anyhow::anyhow!("Only Open quests can be edited"));
+        }
+
+        let revisions = self
+            .quest_viewing_repository
+            .revisions_by_quest_id(quest_id)
+            .await?
+            .iter()
+            .map(|revision| revision.to_model())
+            .collect::<Vec<QuestRevisionModel>>();
+
+        let current_fields = QuestFieldsModel::from_entity(&quest);
+        let target_fields = current_fields.at_revision(&revisions, revision_number)?;
+
+        let changes = current_fields.changes_to(&target_fields)?;
+
+        if changes.is_empty() {
+            return Ok(quest_id);
+        }
+
+        let crew_limits = get_crew_limits_env()?;
+
+        validate_crew_limits(
+            target_fields.min_crew,
+            target_fields.max_crew,
+            crew_limits.max_crew_ceiling,
+        )?;
+
+        // revision เก่าอาจผ่าน validation ในตอนนั้นแต่ใช้ไม่ได้แล้วในตอนนี้
+        validate_quest_schedule(
+            target_fields.starts_at,
+            target_fields.join_deadline,
+            target_fields.due_at,
+        )?;
+
+        if target_fields.due_at != current_fields.due_at {
+            validate_quest_due_at(target_fields.due_at, chrono::Utc::now().naive_utc())?;
+        }
+
+        validate_quest_reward(target_fields.reward_gold, target_fields.reward_experience)?;
+
+        validate_quest_min_level(target_fields.min_level)?;
+
+        validate_quest_visibility(
+            target_fields.guild_id,
+            &target_fields.visibility.to_string(),
+        )?;
+
+        let adventurers_count = self
+            .quest_viewing_repository
+            .adventurers_counting_by_quest_id(quest_id)
             .await?;
+
+        validate_crew_limits_for_crew(
+            target_fields.min_crew,
+            target_fields.max_crew,
+            adventurers_count,
+            target_fields
+                .join_deadline
+                .is_some_and(|join_deadline| join_deadline <= chrono::Utc::now().naive_utc()),
+        )?;
+
+        let add_quest_revision_entity = AddQuestRevisionEntity {
+            guild_commander_id,
+            changes: serde_json::to_value(&changes)?,
+            requires_reconfirmation: adventurers_count > 0 && has_material_changes(&changes),
+            created_at: chrono::Utc::now().naive_utc(),
+        };
+
+        let result = self
+            .quest_ops_repository
+            .edit(
+                quest_id,
+                expected_version,
+                target_fields.to_edit_entity(guild_commander_id),
+                add_quest_revision_entity,
+            )
+            .await?;
+
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        entities::quests::QuestEntity,
+        repositories::{
+            quest_ops::MockQuestOpsRepository, quest_viewing::MockQuestViewingRepository,
+        },
+        value_objects::quest_visibilities::QuestVisibilities,
+    };
+
+    fn quest(guild_commander_id: i32, status: QuestStatuses) -> QuestEntity {
+        let now = chrono::Utc::now().naive_utc();
+        QuestEntity {
+            id: 1,
+            name: "Quest".to_string(),
+            description: None,
+            status,
+            guild_commander_id,
+            created_at: now,
+            updated_at: now,
+            min_crew: 1,
+            max_crew: 4,
+            join_policy: Default::default(),
+            starts_at: None,
+            join_deadline: None,
+            due_at: None,
+            reward_gold: 0,
+            reward_experience: 0,
+            min_level: 1,
+            version: 1,
+            require_checkpoints: false,
+            guild_id: None,
+            visibility: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn remove_rejects_quest_owned_by_another_commander() {
+        let quest_ops_repository = MockQuestOpsRepository::new();
+        let mut quest_viewing_repository = MockQuestViewingRepository::new();
+        quest_viewing_repository
+            .expect_view_details()
+            .returning(|_, _| Box::pin(async { Ok(quest(1, QuestStatuses::Open)) }));
+
+        let usecase = QuestOpsUsecase::new(
+            Arc::new(quest_ops_repository),
+            Arc::new(quest_viewing_repository),
+        );
+
+        let result = usecase.remove(1, 2).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn remove_deletes_empty_owned_quest() {
+        let mut quest_ops_repository = MockQuestOpsRepository::new();
+        quest_ops_repository
+            .expect_remove()
+            .withf(|quest_id, guild_commander_id| *quest_id == 1 && *guild_commander_id == 1)
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        let mut quest_viewing_repository = MockQuestViewingRepository::new();
+        quest_viewing_repository
+            .expect_view_details()
+            .returning(|_, _| Box::pin(async { Ok(quest(1, QuestStatuses::Open)) }));
+        quest_viewing_repository
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(0) }));
+
+        let usecase = QuestOpsUsecase::new(
+            Arc::new(quest_ops_repository),
+            Arc::new(quest_viewing_repository),
+        );
+
+        let result = usecase.remove(1, 1).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn edit_rejects_guild_only_quest_without_guild() {
+        let quest_ops_repository = MockQuestOpsRepository::new();
+        let mut quest_viewing_repository = MockQuestViewingRepository::new();
+        quest_viewing_repository
+            .expect_view_details()
+            .returning(|_, _| Box::pin(async { Ok(quest(1, QuestStatuses::Open)) }));
+        quest_viewing_repository
+            .expect_adventurers_counting_by_quest_id()
+            .returning(|_| Box::pin(async { Ok(0) }));
+
+        let usecase = QuestOpsUsecase::new(
+            Arc::new(quest_ops_repository),
+            Arc::new(quest_viewing_repository),
+        );
+
+        let edit_quest_model = EditQuestModel {
+            name: None,
+            description: None,
+            min_crew: None,
+            max_crew: None,
+            join_policy: None,
+            starts_at: None,
+            join_deadline: None,
+            due_at: None,
+            reward_gold: None,
+            reward_experience: None,
+            min_level: None,
+            require_checkpoints: None,
+            guild_id: None,
+            visibility: Some(QuestVisibilities::GuildOnly),
+        };
+
+        let result = usecase.edit(1, 1, None, edit_quest_model).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn set_tags_rejects_quest_owned_by_another_commander() {
+        let quest_ops_repository = MockQuestOpsRepository::new();
+        let mut quest_viewing_repository = MockQuestViewingRepository::new();
+        quest_viewing_repository
+            .expect_view_details()
+            .returning(|_, _| Box::pin(async { Ok(quest(1, QuestStatuses::Open)) }));
+
+        let usecase = QuestOpsUsecase::new(
+            Arc::new(quest_ops_repository),
+            Arc::new(quest_viewing_repository),
+        );
+
+        let result = usecase
+            .set_tags(
+                1,
+                2,
+                SetQuestTagsModel {
+                    tags: vec!["dragon".to_string()],
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn set_tags_stores_normalized_tags() {
+        let mut quest_ops_repository = MockQuestOpsRepository::new();
+        quest_ops_repository
+            .expect_set_tags()
+            .withf(|quest_id, tags| *quest_id == 1 && tags == &["dragon", "cave"])
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        let mut quest_viewing_repository = MockQuestViewingRepository::new();
+        quest_viewing_repository
+            .expect_view_details()
+            .returning(|_, _| Box::pin(async { Ok(quest(1, QuestStatuses::Open)) }));
+
+        let usecase = QuestOpsUsecase::new(
+            Arc::new(quest_ops_repository),
+            Arc::new(quest_viewing_repository),
+        );
+
+        let result = usecase
+            .set_tags(
+                1,
+                1,
+                SetQuestTagsModel {
+                    tags: vec![
+                        " Dragon".to_string(),
+                        "cave".to_string(),
+                        "DRAGON".to_string(),
+                    ],
+                },
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn clone_quest_rejects_quest_owned_by_another_commander() {
+        let quest_ops_repository = MockQuestOpsRepository::new();
+        let mut quest_viewing_repository = MockQuestViewingRepository::new();
+        quest_viewing_repository
+            .expect_view_details()
+            .returning(|_, _| Box::pin(async { Ok(quest(1, QuestStatuses::Completed)) }));
+
+        let usecase = QuestOpsUsecase::new(
+            Arc::new(quest_ops_repository),
+            Arc::new(quest_viewing_repository),
+        );
+
+        let result = usecase.clone_quest(1, 2).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn revert_rejects_quest_that_is_not_open() {
+        let quest_ops_repository = MockQuestOpsRepository::new();
+        let mut quest_viewing_repository = MockQuestViewingRepository::new();
+        quest_viewing_repository
+            .expect_view_details()
+            .returning(|_, _| Box::pin(async { Ok(quest(1, QuestStatuses::InJourney)) }));
+
+        let usecase = QuestOpsUsecase::new(
+            Arc::new(quest_ops_repository),
+            Arc::new(quest_viewing_repository),
+        );
+
+        let result = usecase.revert(1, 1, 0, None).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn revert_rejects_quest_owned_by_another_commander() {
+        let quest_ops_repository = MockQuestOpsRepository::new();
+        let mut quest_viewing_repository = MockQuestViewingRepository::new();
+        quest_viewing_repository
+            .expect_view_details()
+            .returning(|_, _| Box::pin(async { Ok(quest(1, QuestStatuses::Open)) }));
+
+        let usecase = QuestOpsUsecase::new(
+            Arc::new(quest_ops_repository),
+            Arc::new(quest_viewing_repository),
+        );
+
+        let result = usecase.revert(1, 2, 0, None).await;
+
+        assert!(result.is_err());
+    }
+}
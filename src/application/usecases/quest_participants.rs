@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::domain::{
+    entities::quests::QuestEntity,
+    repositories::{
+        crew_swithboard::CrewSwithboardRepository, quest_viewing::QuestViewingRepository,
+    },
+    value_objects::quest_participants::QuestParticipants,
+};
+
+pub struct QuestParticipantsUsecase<T1, T2>
+where
+    T1: QuestViewingRepository + Send + Sync,
+    T2: CrewSwithboardRepository + Send + Sync,
+{
+    pub quest_viewing_repository: Arc<T1>,
+    pub crew_swithboard_repository: Arc<T2>,
+}
+
+impl<T1, T2> QuestParticipantsUsecase<T1, T2>
+where
+    T1: QuestViewingRepository + Send + Sync,
+    T2: CrewSwithboardRepository + Send + Sync,
+{
+    pub fn new(quest_viewing_repository: Arc<T1>, crew_swithboard_repository: Arc<T2>) -> Self {
+        Self {
+            quest_viewing_repository,
+            crew_swithboard_repository,
+        }
+    }
+
+    // เฉพาะ guild commander เจ้าของ quest หรือ adventurer ใน crew เท่านั้น
+    pub async fn ensure_participant(
+        &self,
+        quest_id: i32,
+        participant: QuestParticipants,
+        resource: &str,
+    ) -> Result<QuestEntity> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(quest_id, Some(participant))
+            .await?;
+
+        let allowed = match participant {
+            QuestParticipants::GuildCommander(guild_commander_id) => {
+                quest.guild_commander_id == guild_commander_id
+            }
+            QuestParticipants::Adventurer(adventurer_id) => {
+                self.crew_swithboard_repository
+                    .is_crew_member(quest_id, adventurer_id)
+                    .await?
+            }
+        };
+
+        if !allowed {
+            return Err(anyhow::anyhow!(
+                "Only the quest crew and its guild commander can access {}",
+                resource
+            ));
+        }
+
+        Ok(quest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        repositories::{
+            crew_swithboard::MockCrewSwithboardRepository,
+            quest_viewing::MockQuestViewingRepository,
+        },
+        value_objects::quest_statuses::QuestStatuses,
+    };
+
+    fn quest(guild_commander_id: i32) -> QuestEntity {
+        let now = chrono::Utc::now().naive_utc();
+        QuestEntity {
+            id: 1,
+            name: "Quest".to_string(),
+            description: None,
+            status: QuestStatuses::Open,
+            guild_commander_id,
+            created_at: now,
+            updated_at: now,
+            min_crew: 1,
+            max_crew: 4,
+            join_policy: Default::default(),
+            starts_at: None,
+            join_deadline: None,
+            due_at: None,
+            reward_gold: 0,
+            reward_experience: 0,
+            min_level: 1,
+            version: 1,
+            require_checkpoints: false,
+            guild_id: None,
+            visibility: Default::default(),
+        }
+    }
+
+    fn usecase(
+        is_crew_member: bool,
+    ) -> QuestParticipantsUsecase<MockQuestViewingRepository, MockCrewSwithboardRepository> {
+        let mut quest_viewing_repository = MockQuestViewingRepository::new();
+        quest_viewing_repository
+            .expect_view_details()
+            .returning(|_, _| Box::pin(async { Ok(quest(1)) }));
+        let mut crew_swithboard_repository = MockCrewSwithboardRepository::new();
+        crew_swithboard_repository
+            .expect_is_crew_member()
+            .returning(move |_, _| Box::pin(async move { Ok(is_crew_member) }));
+
+        QuestParticipantsUsecase::new(
+            Arc::new(quest_viewing_repository),
+            Arc::new(crew_swithboard_repository),
+        )
+    }
+
+    #[tokio::test]
+    async fn ensure_participant_allows_owning_commander() {
+        let result = usecase(false)
+            .ensure_participant(1, QuestParticipants::GuildCommander(1), "comments")
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn ensure_participant_rejects_other_commander() {
+        let result = usecase(false)
+            .ensure_participant(1, QuestParticipants::GuildCommander(2), "comments")
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn ensure_participant_allows_crew_member() {
+        let result = usecase(true)
+            .ensure_participant(1, QuestParticipants::Adventurer(5), "comments")
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn ensure_participant_rejects_adventurer_outside_crew() {
+        let result = usecase(false)
+            .ensure_participant(1, QuestParticipants::Adventurer(5), "comments")
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use tracing::error;
+
+use crate::{
+    config::config_loader::get_quest_retention_env,
+    domain::repositories::{attachment_storage::AttachmentStorage, quest_ops::QuestOpsRepository},
+};
+
+pub struct QuestPurgeUsecase<T1, T2>
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: AttachmentStorage + Send + Sync,
+{
+    pub quest_ops_repository: Arc<T1>,
+    pub attachment_storage: Arc<T2>,
+}
+
+impl<T1, T2> QuestPurgeUsecase<T1, T2>
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: AttachmentStorage + Send + Sync,
+{
+    pub fn new(quest_ops_repository: Arc<T1>, attachment_storage: Arc<T2>) -> Self {
+        Self {
+            quest_ops_repository,
+            attachment_storage,
+        }
+    }
+
+    pub async fn purge_expired(&self) -> Result<Vec<i32>> {
+        let quest_retention = get_quest_retention_env()?;
+        let deleted_before = chrono::Utc::now().naive_utc()
+            - chrono::Duration::days(quest_retention.purge_retention_days);
+
+        let (results, storage_keys) = self
+            .quest_ops_repository
+            .purge_deleted_before(deleted_before)
+            .await?;
+
+        // แถวใน database ลบไปแล้ว ไฟล์ที่ลบไม่สำเร็จไม่ควรทำให้รอบนี้ fail
+        for storage_key in storage_keys {
+            if let Err(e) = self.attachment_storage.delete(&storage_key).await {
+                error!("Failed to delete attachment {} :{}", storage_key, e);
+            }
+        }
+
+        Ok(results)
+    }
+}
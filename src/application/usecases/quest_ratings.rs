@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::usecases::quest_participants::QuestParticipantsUsecase,
+    domain::{
+        entities::quest_ratings::AddQuestRatingEntity,
+        repositories::{
+            crew_swithboard::CrewSwithboardRepository, quest_ratings::QuestRatingsRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_participants::QuestParticipants,
+            quest_rating_model::{
+                AddQuestRatingModel, GuildCommanderProfileModel, QuestRatingModel, ReputationModel,
+                validate_rating,
+            },
+            quest_statuses::QuestStatuses,
+            rating_directions::RatingDirections,
+        },
+    },
+};
+
+pub struct QuestRatingsUsecase<T1, T2, T3>
+where
+    T1: QuestRatingsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    pub quest_ratings_repository: Arc<T1>,
+    pub quest_viewing_repository: Arc<T2>,
+    pub quest_participants_usecase: QuestParticipantsUsecase<T2, T3>,
+}
+
+impl<T1, T2, T3> QuestRatingsUsecase<T1, T2, T3>
+where
+    T1: QuestRatingsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    pub fn new(
+        quest_ratings_repository: Arc<T1>,
+        quest_viewing_repository: Arc<T2>,
+        crew_swithboard_repository: Arc<T3>,
+    ) -> Self {
+        Self {
+            quest_ratings_repository,
+            quest_participants_usecase: QuestParticipantsUsecase::new(
+                Arc::clone(&quest_viewing_repository),
+                crew_swithboard_repository,
+            ),
+            quest_viewing_repository,
+        }
+    }
+
+    pub async fn ratings(
+        &self,
+        quest_id: i32,
+        participant: QuestParticipants,
+    ) -> Result<Vec<QuestRatingModel>> {
+        self.quest_participants_usecase
+            .ensure_participant(quest_id, participant, "ratings")
+            .await?;
+
+        let results = self.quest_ratings_repository.by_quest_id(quest_id).await?;
+
+        Ok(results.iter().map(|rating| rating.to_model()).collect())
+    }
+
+    pub async fn rate(
+        &self,
+        quest_id: i32,
+        participant: QuestParticipants,
+        add_quest_rating_model: AddQuestRatingModel,
+    ) -> Result<i32> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(quest_id, Some(participant))
+            .await?;
+
+        let finished = quest.status.to_string() == QuestStatuses::Completed.to_string()
+            || quest.status.to_string() == QuestStatuses::Failed.to_string();
+
+        if !finished {
+            return Err(anyhow::anyhow!(
+                "Ratings are only allowed once the quest is Completed or Failed"
+            ));
+        }
+
+        let comment = add_quest_rating_model.comment();
+        validate_rating(add_quest_rating_model.score, &comment)?;
+
+        let (adventurer_id, direction) = match participant {
+            QuestParticipants::GuildCommander(guild_commander_id) => {
+                if quest.guild_commander_id != guild_commander_id {
+                    return Err(anyhow::anyhow!(
+                        "Only the owning guild commander can rate this crew"
+                    ));
+                }
+
+                let adventurer_id = add_quest_rating_model
+                    .adventurer_id
+                    .ok_or_else(|| anyhow::anyhow!("adventurer_id is required"))?;
+
+                (adventurer_id, RatingDirections::CommanderToAdventurer)
+            }
+            QuestParticipants::Adventurer(adventurer_id) => {
+                (adventurer_id, RatingDirections::AdventurerToCommander)
+            }
+        };
+
+        // ใช้ crew ตอนที่ quest จบ ไม่ใช่ crew ปัจจุบัน
+        let is_final_crew_member = self
+            .quest_ratings_repository
+            .is_final_crew_member(quest_id, adventurer_id)
+            .await?;
+
+        if !is_final_crew_member {
+            return Err(anyhow::anyhow!(
+                "Ratings are only allowed between the quest crew and its guild commander"
+            ));
+        }
+
+        let is_rated = self
+            .quest_ratings_repository
+            .is_rated(
+                quest_id,
+                quest.guild_commander_id,
+                adventurer_id,
+                direction.clone(),
+            )
+            .await?;
+
+        if is_rated {
+            return Err(anyhow::anyhow!("Already rated for this quest"));
+        }
+
+        let add_quest_rating_entity = AddQuestRatingEntity {
+            quest_id,
+            guild_commander_id: quest.guild_commander_id,
+            adventurer_id,
+            direction: direction.to_string(),
+            score: add_quest_rating_model.score,
+            comment,
+            created_at: chrono::Utc::now().naive_utc(),
+        };
+
+        let result = self
+            .quest_ratings_repository
+            .add(add_quest_rating_entity)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn guild_commander_profile(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<GuildCommanderProfileModel> {
+        let (score_total, ratings_count) = self
+            .quest_ratings_repository
+            .guild_commander_score_totals(guild_commander_id)
+            .await?;
+
+        Ok(GuildCommanderProfileModel {
+            guild_commander_id,
+            reputation: ReputationModel::from_totals(score_total, ratings_count),
+        })
+    }
+}
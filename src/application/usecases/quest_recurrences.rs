@@ -0,0 +1,257 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use tracing::error;
+
+use crate::{
+    application::usecases::quest_templates::QuestTemplatesUsecase,
+    domain::{
+        entities::quest_recurrences::{AddQuestRecurrenceEntity, QuestRecurrenceEntity},
+        repositories::{
+            quest_ops::QuestOpsRepository, quest_recurrences::QuestRecurrencesRepository,
+            quest_templates::QuestTemplatesRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_recurrence_model::{AddQuestRecurrenceModel, CronSchedule, QuestRecurrenceModel},
+            quest_template_model::CreateQuestFromTemplateModel,
+            recurrence_statuses::RecurrenceStatuses,
+        },
+    },
+};
+
+pub struct QuestRecurrencesUsecase<T1, T2, T3, T4>
+where
+    T1: QuestRecurrencesRepository + Send + Sync,
+    T2: QuestTemplatesRepository + Send + Sync,
+    T3: QuestOpsRepository + Send + Sync,
+    T4: QuestViewingRepository + Send + Sync,
+{
+    pub quest_recurrences_repository: Arc<T1>,
+    pub quest_templates_usecase: QuestTemplatesUsecase<T2, T3, T4>,
+}
+
+impl<T1, T2, T3, T4> QuestRecurrencesUsecase<T1, T2, T3, T4>
+where
+    T1: QuestRecurrencesRepository + Send + Sync,
+    T2: QuestTemplatesRepository + Send + Sync,
+    T3: QuestOpsRepository + Send + Sync,
+    T4: QuestViewingRepository + Send + Sync,
+{
+    pub fn new(
+        quest_recurrences_repository: Arc<T1>,
+        quest_templates_repository: Arc<T2>,
+        quest_ops_repository: Arc<T3>,
+        quest_viewing_repository: Arc<T4>,
+    ) -> Self {
+        Self {
+            quest_recurrences_repository,
+            quest_templates_usecase: QuestTemplatesUsecase::new(
+                quest_templates_repository,
+                quest_ops_repository,
+                quest_viewing_repository,
+            ),
+        }
+    }
+
+    pub async fn add(
+        &self,
+        guild_commander_id: i32,
+        add_quest_recurrence_model: AddQuestRecurrenceModel,
+    ) -> Result<i32> {
+        self.quest_templates_usecase
+            .view_details(
+                add_quest_recurrence_model.quest_template_id,
+                guild_commander_id,
+            )
+            .await?;
+
+        let max_open_instances = add_quest_recurrence_model.max_open_instances.unwrap_or(1);
+
+        if max_open_instances < 1 {
+            return Err(anyhow::anyhow!("max_open_instances must be at least 1"));
+        }
+
+        let schedule = add_quest_recurrence_model.rule.to_cron_expression()?;
+        let now = chrono::Utc::now().naive_utc();
+        let next_run_at = CronSchedule::parse(&schedule)?
+            .next_after(now)
+            .ok_or(anyhow::anyhow!("Recurrence schedule never runs"))?;
+
+        let add_quest_recurrence_entity = AddQuestRecurrenceEntity {
+            guild_commander_id,
+            quest_template_id: add_quest_recurrence_model.quest_template_id,
+            schedule,
+            max_open_instances,
+            status: RecurrenceStatuses::Active.to_string(),
+            next_run_at,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let result = self
+            .quest_recurrences_repository
+            .add(add_quest_recurrence_entity)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestRecurrenceModel>> {
+        let results = self
+            .quest_recurrences_repository
+            .by_guild_commander_id(guild_commander_id)
+            .await?;
+
+        Ok(results
+            .iter()
+            .map(|recurrence| recurrence.to_model())
+            .collect())
+    }
+
+    pub async fn pause(&self, quest_recurrence_id: i32, guild_commander_id: i32) -> Result<()> {
+        let quest_recurrence = self
+            .owned_quest_recurrence(quest_recurrence_id, guild_commander_id)
+            .await?;
+
+        if quest_recurrence.status != RecurrenceStatuses::Active {
+            return Err(anyhow::anyhow!("Only active recurrences can be paused"));
+        }
+
+        self.quest_recurrences_repository
+            .update_status(
+                quest_recurrence_id,
+                RecurrenceStatuses::Paused,
+                quest_recurrence.next_run_at,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn resume(&self, quest_recurrence_id: i32, guild_commander_id: i32) -> Result<()> {
+        let quest_recurrence = self
+            .owned_quest_recurrence(quest_recurrence_id, guild_commander_id)
+            .await?;
+
+        if quest_recurrence.status != RecurrenceStatuses::Paused {
+            return Err(anyhow::anyhow!("Only paused recurrences can be resumed"));
+        }
+
+        // ไม่ย้อนสร้างรอบที่พลาดไประหว่างหยุด
+        let next_run_at = CronSchedule::parse(&quest_recurrence.schedule)?
+            .next_after(chrono::Utc::now().naive_utc())
+            .ok_or(anyhow::anyhow!("Recurrence schedule never runs"))?;
+
+        self.quest_recurrences_repository
+            .update_status(quest_recurrence_id, RecurrenceStatuses::Active, next_run_at)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn stop(&self, quest_recurrence_id: i32, guild_commander_id: i32) -> Result<()> {
+        let quest_recurrence = self
+            .owned_quest_recurrence(quest_recurrence_id, guild_commander_id)
+            .await?;
+
+        if quest_recurrence.status == RecurrenceStatuses::Stopped {
+            return Err(anyhow::anyhow!("Recurrence is already stopped"));
+        }
+
+        self.quest_recurrences_repository
+            .update_status(
+                quest_recurrence_id,
+                RecurrenceStatuses::Stopped,
+                quest_recurrence.next_run_at,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn spawn_due(&self) -> Result<Vec<i32>> {
+        let now = chrono::Utc::now().naive_utc();
+
+        let due_recurrences = self.quest_recurrences_repository.due(now).await?;
+
+        let mut spawned_quest_ids = Vec::<i32>::new();
+
+        // recurrence หนึ่งพังไม่ควรทำให้ recurrence อื่นในรอบนี้ค้าง
+        for quest_recurrence in due_recurrences {
+            match self.spawn(&quest_recurrence, now).await {
+                Ok(Some(quest_id)) => spawned_quest_ids.push(quest_id),
+                Ok(None) => {}
+                Err(e) => error!(
+                    "Failed to spawn quest recurrence {} :{}",
+                    quest_recurrence.id, e
+                ),
+            }
+        }
+
+        Ok(spawned_quest_ids)
+    }
+
+    async fn spawn(
+        &self,
+        quest_recurrence: &QuestRecurrenceEntity,
+        now: chrono::NaiveDateTime,
+    ) -> Result<Option<i32>> {
+        let Some(next_run_at) = CronSchedule::parse(&quest_recurrence.schedule)?.next_after(now)
+        else {
+            self.quest_recurrences_repository
+                .update_status(
+                    quest_recurrence.id,
+                    RecurrenceStatuses::Stopped,
+                    quest_recurrence.next_run_at,
+                )
+                .await?;
+            return Ok(None);
+        };
+
+        let open_instances_count = self
+            .quest_recurrences_repository
+            .open_instances_count(quest_recurrence.id)
+            .await?;
+
+        // ถึงเพดานแล้วข้ามรอบนี้ไป
+        let spawned_quest = if open_instances_count < quest_recurrence.max_open_instances as i64 {
+            Some(
+                self.quest_templates_usecase
+                    .to_add_quest_entity(
+                        quest_recurrence.quest_template_id,
+                        quest_recurrence.guild_commander_id,
+                        CreateQuestFromTemplateModel::default(),
+                    )
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        self.quest_recurrences_repository
+            .record_run(quest_recurrence.id, spawned_quest, now, next_run_at)
+            .await
+    }
+
+    async fn owned_quest_recurrence(
+        &self,
+        quest_recurrence_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<QuestRecurrenceEntity> {
+        let quest_recurrence = self
+            .quest_recurrences_repository
+            .view_details(quest_recurrence_id)
+            .await?;
+
+        if quest_recurrence.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest recurrence is not owned by this guild commander"
+            ));
+        }
+
+        Ok(quest_recurrence)
+    }
+}
@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::usecases::quest_participants::QuestParticipantsUsecase,
+    config::config_loader::get_quest_attachments_env,
+    domain::{
+        entities::quest_reports::AddQuestAttachmentEntity,
+        repositories::{
+            attachment_storage::AttachmentStorage, crew_swithboard::CrewSwithboardRepository,
+            quest_reports::QuestReportsRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_participants::QuestParticipants,
+            quest_report_model::{
+                QuestAttachmentContentModel, QuestEvidenceModel, UploadQuestAttachmentModel,
+                validate_attachment,
+            },
+            quest_statuses::QuestStatuses,
+        },
+    },
+};
+
+pub struct QuestReportsUsecase<T1, T2, T3, T4>
+where
+    T1: QuestReportsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+    T4: AttachmentStorage + Send + Sync,
+{
+    pub quest_reports_repository: Arc<T1>,
+    pub quest_participants_usecase: QuestParticipantsUsecase<T2, T3>,
+    pub attachment_storage: Arc<T4>,
+}
+
+impl<T1, T2, T3, T4> QuestReportsUsecase<T1, T2, T3, T4>
+where
+    T1: QuestReportsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+    T4: AttachmentStorage + Send + Sync,
+{
+    pub fn new(
+        quest_reports_repository: Arc<T1>,
+        quest_viewing_repository: Arc<T2>,
+        crew_swithboard_repository: Arc<T3>,
+        attachment_storage: Arc<T4>,
+    ) -> Self {
+        Self {
+            quest_reports_repository,
+            quest_participants_usecase: QuestParticipantsUsecase::new(
+                quest_viewing_repository,
+                crew_swithboard_repository,
+            ),
+            attachment_storage,
+        }
+    }
+
+    pub async fn evidence(
+        &self,
+        quest_id: i32,
+        participant: QuestParticipants,
+    ) -> Result<QuestEvidenceModel> {
+        self.quest_participants_usecase
+            .ensure_participant(quest_id, participant, "reports")
+            .await?;
+
+        let reports = self
+            .quest_reports_repository
+            .reports_by_quest_id(quest_id)
+            .await?;
+
+        let attachments = self
+            .quest_reports_repository
+            .attachments_by_quest_id(quest_id)
+            .await?;
+
+        Ok(QuestEvidenceModel {
+            reports: reports.iter().map(|report| report.to_model()).collect(),
+            attachments: attachments
+                .iter()
+                .map(|attachment| attachment.to_model())
+                .collect(),
+        })
+    }
+
+    pub async fn upload(
+        &self,
+        quest_id: i32,
+        participant: QuestParticipants,
+        upload_quest_attachment_model: UploadQuestAttachmentModel,
+    ) -> Result<i32> {
+        let quest = self
+            .quest_participants_usecase
+            .ensure_participant(quest_id, participant, "reports")
+            .await?;
+
+        let adventurer_id = participant
+            .adventurer_id()
+            .ok_or_else(|| anyhow::anyhow!("Only crew members can upload attachments"))?;
+
+        let uploadable = quest.status.to_string() == QuestStatuses::InJourney.to_string()
+            || quest.status.to_string() == QuestStatuses::Completed.to_string()
+            || quest.status.to_string() == QuestStatuses::Failed.to_string();
+
+        if !uploadable {
+            return Err(anyhow::anyhow!(
+                "Attachments can only be uploaded once the quest is InJourney"
+            ));
+        }
+
+        let quest_attachments = get_quest_attachments_env()?;
+
+        validate_attachment(
+            &upload_quest_attachment_model,
+            quest_attachments.max_file_size_bytes,
+            &quest_attachments.allowed_content_types,
+        )?;
+
+        let storage_key = format!("quests/{}/{:032x}", quest_id, rand::random::<u128>());
+        let size_bytes = upload_quest_attachment_model.content.len() as i64;
+
+        self.attachment_storage
+            .put(&storage_key, upload_quest_attachment_model.content)
+            .await?;
+
+        let add_quest_attachment_entity = AddQuestAttachmentEntity {
+            quest_id,
+            adventurer_id,
+            file_name: upload_quest_attachment_model.file_name.trim().to_string(),
+            content_type: upload_quest_attachment_model.content_type.to_lowercase(),
+            size_bytes,
+            storage_key: storage_key.clone(),
+            created_at: chrono::Utc::now().naive_utc(),
+        };
+
+        // บันทึกลง db ไม่ผ่าน ลบไฟล์ทิ้งไม่ให้ค้างใน storage
+        match self
+            .quest_reports_repository
+            .add_attachment(add_quest_attachment_entity)
+            .await
+        {
+            Ok(attachment_id) => Ok(attachment_id),
+            Err(e) => {
+                self.attachment_storage.delete(&storage_key).await?;
+                Err(e)
+            }
+        }
+    }
+
+    pub async fn download(
+        &self,
+        quest_id: i32,
+        attachment_id: i32,
+        participant: QuestParticipants,
+    ) -> Result<QuestAttachmentContentModel> {
+        self.quest_participants_usecase
+            .ensure_participant(quest_id, participant, "reports")
+            .await?;
+
+        let attachment = self
+            .quest_reports_repository
+            .attachment(attachment_id)
+            .await?;
+
+        if attachment.quest_id != quest_id {
+            return Err(anyhow::anyhow!("Attachment not found"));
+        }
+
+        let content = self.attachment_storage.get(&attachment.storage_key).await?;
+
+        Ok(QuestAttachmentContentModel {
+            file_name: attachment.file_name,
+            content_type: attachment.content_type,
+            content,
+        })
+    }
+
+    pub async fn remove_attachment(
+        &self,
+        quest_id: i32,
+        attachment_id: i32,
+        participant: QuestParticipants,
+    ) -> Result<()> {
+        self.quest_participants_usecase
+            .ensure_participant(quest_id, participant, "reports")
+            .await?;
+
+        let attachment = self
+            .quest_reports_repository
+            .attachment(attachment_id)
+            .await?;
+
+        if attachment.quest_id != quest_id {
+            return Err(anyhow::anyhow!("Attachment not found"));
+        }
+
+        if participant.adventurer_id() != Some(attachment.adventurer_id) {
+            return Err(anyhow::anyhow!(
+                "Only the uploader can delete this attachment"
+            ));
+        }
+
+        self.quest_reports_repository
+            .remove_attachment(attachment_id)
+            .await?;
+
+        self.attachment_storage
+            .delete(&attachment.storage_key)
+            .await?;
+
+        Ok(())
+    }
+}
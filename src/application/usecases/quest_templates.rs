@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::usecases::quest_ops::QuestOpsUsecase,
+    config::config_loader::get_crew_limits_env,
+    domain::{
+        entities::{quest_templates::QuestTemplateEntity, quests::AddQuestEntity},
+        repositories::{
+            quest_ops::QuestOpsRepository, quest_templates::QuestTemplatesRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_adventurer_juntion::validate_crew_limits,
+            quest_model::{
+                validate_quest_min_level, validate_quest_reward, validate_quest_visibility,
+            },
+            quest_tag_model::normalize_tags,
+            quest_template_model::{
+                AddQuestTemplateModel, CreateQuestFromTemplateModel, QuestTemplateModel,
+            },
+        },
+    },
+};
+
+pub struct QuestTemplatesUsecase<T1, T2, T3>
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    pub quest_templates_repository: Arc<T1>,
+    pub quest_ops_usecase: QuestOpsUsecase<T2, T3>,
+}
+
+impl<T1, T2, T3> QuestTemplatesUsecase<T1, T2, T3>
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    pub fn new(
+        quest_templates_repository: Arc<T1>,
+        quest_ops_repository: Arc<T2>,
+        quest_viewing_repository: Arc<T3>,
+    ) -> Self {
+        Self {
+            quest_templates_repository,
+            quest_ops_usecase: QuestOpsUsecase::new(quest_ops_repository, quest_viewing_repository),
+        }
+    }
+
+    pub async fn add(
+        &self,
+        guild_commander_id: i32,
+        add_quest_template_model: AddQuestTemplateModel,
+    ) -> Result<i32> {
+        let crew_limits = get_crew_limits_env()?;
+
+        let tags = normalize_tags(&add_quest_template_model.tags.clone().unwrap_or_default())?;
+        let add_quest_template_entity = add_quest_template_model.to_entity(
+            guild_commander_id,
+            tags,
+            crew_limits.max_crew_ceiling,
+        );
+
+        validate_crew_limits(
+            add_quest_template_entity.min_crew,
+            add_quest_template_entity.max_crew,
+            crew_limits.max_crew_ceiling,
+        )?;
+
+        validate_quest_reward(
+            add_quest_template_entity.reward_gold,
+            add_quest_template_entity.reward_experience,
+        )?;
+
+        validate_quest_min_level(add_quest_template_entity.min_level)?;
+
+        validate_quest_visibility(
+            add_quest_template_entity.guild_id,
+            &add_quest_template_entity.visibility,
+        )?;
+
+        let result = self
+            .quest_templates_repository
+            .add(add_quest_template_entity)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestTemplateModel>> {
+        let results = self
+            .quest_templates_repository
+            .by_guild_commander_id(guild_commander_id)
+            .await?;
+
+        Ok(results.iter().map(|template| template.to_model()).collect())
+    }
+
+    pub async fn view_details(
+        &self,
+        quest_template_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<QuestTemplateModel> {
+        let quest_template = self
+            .owned_quest_template(quest_template_id, guild_commander_id)
+            .await?;
+
+        Ok(quest_template.to_model())
+    }
+
+    pub async fn remove(&self, quest_template_id: i32, guild_commander_id: i32) -> Result<()> {
+        self.quest_templates_repository
+            .remove(quest_template_id, guild_commander_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn create_quest(
+        &self,
+        quest_template_id: i32,
+        guild_commander_id: i32,
+        create_quest_from_template_model: CreateQuestFromTemplateModel,
+    ) -> Result<i32> {
+        let quest_template = self
+            .owned_quest_template(quest_template_id, guild_commander_id)
+            .await?;
+
+        let add_quest_model = quest_template
+            .to_model()
+            .to_add_quest_model(create_quest_from_template_model);
+
+        self.quest_ops_usecase
+            .add(guild_commander_id, add_quest_model)
+            .await
+    }
+
+    pub async fn to_add_quest_entity(
+        &self,
+        quest_template_id: i32,
+        guild_commander_id: i32,
+        create_quest_from_template_model: CreateQuestFromTemplateModel,
+    ) -> Result<(AddQuestEntity, Vec<String>)> {
+        let quest_template = self
+            .owned_quest_template(quest_template_id, guild_commander_id)
+            .await?;
+
+        let add_quest_model = quest_template
+            .to_model()
+            .to_add_quest_model(create_quest_from_template_model);
+
+        self.quest_ops_usecase
+            .to_add_quest_entity(guild_commander_id, add_quest_model)
+    }
+
+    async fn owned_quest_template(
+        &self,
+        quest_template_id: i32,
+        guild_commander_id: i32,
+    ) -> Result<QuestTemplateEntity> {
+        let quest_template = self
+            .quest_templates_repository
+            .view_details(quest_template_id)
+            .await?;
+
+        if quest_template.guild_commander_id != guild_commander_id {
+            return Err(anyhow::anyhow!(
+                "Quest template is not owned by this guild commander"
+            ));
+        }
+
+        Ok(quest_template)
+    }
+}
@@ -4,7 +4,14 @@ use anyhow::Result;
 
 use crate::domain::{
     repositories::quest_viewing::QuestViewingRepository,
-    value_objects::{board_checking_filter::BoardCheckingFilter, quest_model::QuestModel},
+    value_objects::{
+        board_checking_filter::{BoardCheckingFilter, search_snippet_html},
+        quest_checkpoint_model::progress_percent,
+        quest_model::QuestModel,
+        quest_participants::QuestParticipants,
+        quest_revision_model::{QuestFieldsModel, QuestRevisionDiffModel, QuestRevisionModel},
+        quest_tag_model::TagModel,
+    },
 };
 
 pub struct QuestViewingUsecase<T>
@@ -24,19 +31,56 @@ where
         }
     }
 
-    pub async fn view_details(&self, quest_id: i32) -> Result<QuestModel> {
-        let result = self.quest_viewing_repository.view_details(quest_id).await?;
+    pub async fn view_details(
+        &self,
+        quest_id: i32,
+        viewer: Option<QuestParticipants>,
+    ) -> Result<QuestModel> {
+        let result = self
+            .quest_viewing_repository
+            .view_details(quest_id, viewer)
+            .await?;
 
         let adventurers_count = self
             .quest_viewing_repository
             .adventurers_counting_by_quest_id(quest_id)
             .await?;
 
-        Ok(result.to_model(adventurers_count))
+        let tags = self
+            .quest_viewing_repository
+            .tags_by_quest_id(quest_id)
+            .await?;
+
+        let (done_count, total_count) = self
+            .quest_viewing_repository
+            .checkpoints_counting_by_quest_id(quest_id)
+            .await?;
+
+        let mut quest_model = result.to_model(adventurers_count, tags);
+        quest_model.progress_percent = progress_percent(done_count, total_count);
+
+        Ok(quest_model)
     }
 
-    pub async fn board_checking(&self, filter: &BoardCheckingFilter) -> Result<Vec<QuestModel>> {
-        let results = self.quest_viewing_repository.board_checking(filter).await?;
+    pub async fn board_checking(
+        &self,
+        filter: &BoardCheckingFilter,
+        viewer: Option<QuestParticipants>,
+    ) -> Result<Vec<QuestModel>> {
+        let results = self
+            .quest_viewing_repository
+            .board_checking(filter, viewer)
+            .await?;
+
+        let search_highlights = match filter.search_text() {
+            Some(q) => {
+                let quest_ids = results.iter().map(|quest| quest.id).collect::<Vec<i32>>();
+                self.quest_viewing_repository
+                    .search_highlights(&q, &quest_ids)
+                    .await?
+            }
+            None => Vec::new(),
+        };
 
         let mut quests_model = Vec::<QuestModel>::new();
 
@@ -45,7 +89,24 @@ where
                 .quest_viewing_repository
                 .adventurers_counting_by_quest_id(result.id)
                 .await?;
-            quests_model.push(result.to_model(adventurers_count));
+            let tags = self
+                .quest_viewing_repository
+                .tags_by_quest_id(result.id)
+                .await?;
+            let (done_count, total_count) = self
+                .quest_viewing_repository
+                .checkpoints_counting_by_quest_id(result.id)
+                .await?;
+            let mut quest_model = result.to_model(adventurers_count, tags);
+            quest_model.progress_percent = progress_percent(done_count, total_count);
+            if let Some((_, rank, snippet)) = search_highlights
+                .iter()
+                .find(|(quest_id, _, _)| *quest_id == result.id)
+            {
+                quest_model.search_rank = Some(*rank);
+                quest_model.search_snippet = Some(search_snippet_html(snippet));
+            }
+            quests_model.push(quest_model);
         }
 
         Ok(quests_model)
@@ -58,4 +119,62 @@ where
             .await?;
         Ok(result)
     }
+
+    pub async fn tags(&self) -> Result<Vec<TagModel>> {
+        let results = self.quest_viewing_repository.tags_with_counts().await?;
+
+        Ok(results
+            .into_iter()
+            .map(|(name, quests_count)| TagModel { name, quests_count })
+            .collect())
+    }
+
+    pub async fn revisions(
+        &self,
+        quest_id: i32,
+        viewer: Option<QuestParticipants>,
+    ) -> Result<Vec<QuestRevisionModel>> {
+        self.quest_viewing_repository
+            .view_details(quest_id, viewer)
+            .await?;
+
+        let results = self
+            .quest_viewing_repository
+            .revisions_by_quest_id(quest_id)
+            .await?;
+
+        Ok(results.iter().map(|revision| revision.to_model()).collect())
+    }
+
+    pub async fn revisions_diff(
+        &self,
+        quest_id: i32,
+        from_revision: i32,
+        to_revision: i32,
+        viewer: Option<QuestParticipants>,
+    ) -> Result<QuestRevisionDiffModel> {
+        let quest = self
+            .quest_viewing_repository
+            .view_details(quest_id, viewer)
+            .await?;
+
+        let revisions = self
+            .quest_viewing_repository
+            .revisions_by_quest_id(quest_id)
+            .await?
+            .iter()
+            .map(|revision| revision.to_model())
+            .collect::<Vec<QuestRevisionModel>>();
+
+        let current_fields = QuestFieldsModel::from_entity(&quest);
+        let from_fields = current_fields.at_revision(&revisions, from_revision)?;
+        let to_fields = current_fields.at_revision(&revisions, to_revision)?;
+
+        Ok(QuestRevisionDiffModel {
+            quest_id,
+            from_revision,
+            to_revision,
+            changes: from_fields.changes_to(&to_fields)?,
+        })
+    }
 }
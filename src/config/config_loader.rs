@@ -1,6 +1,12 @@
-use crate::config::stage::Stage;
+use crate::{
+    config::stage::Stage,
+    domain::value_objects::quest_adventurer_juntion::MAX_ADVENTURERS_PER_QUEST,
+};
 
-use super::config_model::{AdventurersSecret, Database, DotEnvyConfig, Server};
+use super::config_model::{
+    AdminSecret, AdventurerLevels, AdventurersSecret, CrewLimits, Database, DotEnvyConfig,
+    IdempotencyKeys, QuestAttachments, QuestInvitations, QuestRetention, QuestScheduler, Server,
+};
 use anyhow::Result;
 
 pub fn load() -> Result<DotEnvyConfig> {
@@ -60,3 +66,121 @@ pub fn get_guild_commanders_secret_env() -> Result<AdventurersSecret> {
             .parse()?,
     })
 }
+
+pub fn get_crew_limits_env() -> Result<CrewLimits> {
+    dotenvy::dotenv().ok();
+
+    Ok(CrewLimits {
+        max_crew_ceiling: std::env::var("QUEST_MAX_CREW_CEILING")
+            .unwrap_or(MAX_ADVENTURERS_PER_QUEST.to_string())
+            .parse()?,
+    })
+}
+
+pub fn get_quest_invitations_env() -> Result<QuestInvitations> {
+    dotenvy::dotenv().ok();
+
+    Ok(QuestInvitations {
+        expiry_hours: std::env::var("QUEST_INVITATION_EXPIRY_HOURS")
+            .unwrap_or("72".to_string())
+            .parse()?,
+    })
+}
+
+pub fn get_quest_scheduler_env() -> Result<QuestScheduler> {
+    dotenvy::dotenv().ok();
+
+    let interval = std::env::var("QUEST_SCHEDULER_INTERVAL")
+        .unwrap_or("60".to_string())
+        .parse()?;
+
+    // tokio::time::interval panic ถ้า interval เป็น 0
+    if interval == 0 {
+        return Err(anyhow::anyhow!(
+            "QUEST_SCHEDULER_INTERVAL must be at least 1 second"
+        ));
+    }
+
+    Ok(QuestScheduler { interval })
+}
+
+pub fn get_adventurer_levels_env() -> Result<AdventurerLevels> {
+    dotenvy::dotenv().ok();
+
+    Ok(AdventurerLevels {
+        base_experience: std::env::var("LEVEL_BASE_EXPERIENCE")
+            .unwrap_or("100".to_string())
+            .parse()?,
+        growth_factor: std::env::var("LEVEL_GROWTH_FACTOR")
+            .unwrap_or("1.5".to_string())
+            .parse()?,
+        failure_experience_penalty: std::env::var("QUEST_FAILURE_EXPERIENCE_PENALTY")
+            .unwrap_or("0".to_string())
+            .parse()?,
+    })
+}
+
+pub fn get_quest_retention_env() -> Result<QuestRetention> {
+    dotenvy::dotenv().ok();
+
+    Ok(QuestRetention {
+        restore_grace_hours: std::env::var("QUEST_RESTORE_GRACE_HOURS")
+            .unwrap_or("72".to_string())
+            .parse()?,
+        purge_retention_days: std::env::var("QUEST_PURGE_RETENTION_DAYS")
+            .unwrap_or("30".to_string())
+            .parse()?,
+    })
+}
+
+pub fn get_admin_secret_env() -> Result<AdminSecret> {
+    dotenvy::dotenv().ok();
+
+    Ok(AdminSecret {
+        api_key: std::env::var("ADMIN_API_KEY")?,
+    })
+}
+
+pub fn get_idempotency_keys_env() -> Result<IdempotencyKeys> {
+    dotenvy::dotenv().ok();
+
+    Ok(IdempotencyKeys {
+        ttl_hours: std::env::var("IDEMPOTENCY_KEY_TTL_HOURS")
+            .unwrap_or("24".to_string())
+            .parse()?,
+        // request ที่ค้างนานเกิน timeout ของ server ถือว่าถูกยกเลิกไปแล้ว
+        in_progress_timeout_secs: std::env::var("SERVER_TIMEOUT")
+            .unwrap_or("30".to_string())
+            .parse()?,
+    })
+}
+
+pub fn get_quest_attachments_env() -> Result<QuestAttachments> {
+    dotenvy::dotenv().ok();
+
+    // ไฟล์แนบใหญ่ได้ไม่เกิน body limit ของ server (หน่วย MB)
+    let body_limit_bytes = std::env::var("SERVER_BODY_LIMIT")
+        .unwrap_or("10".to_string())
+        .parse::<usize>()?
+        * 1024
+        * 1024;
+
+    let max_file_size_bytes = std::env::var("ATTACHMENT_MAX_FILE_SIZE_BYTES")
+        .unwrap_or("5242880".to_string())
+        .parse::<usize>()?;
+
+    Ok(QuestAttachments {
+        storage_dir: std::env::var("ATTACHMENT_STORAGE_DIR")
+            .unwrap_or("./storage/attachments".to_string()),
+        max_file_size_bytes: max_file_size_bytes.min(body_limit_bytes),
+        allowed_content_types: std::env::var("ATTACHMENT_ALLOWED_CONTENT_TYPES")
+            .unwrap_or(
+                "image/png,image/jpeg,image/gif,image/webp,text/plain,application/json,application/pdf"
+                    .to_string(),
+            )
+            .split(',')
+            .map(|content_type| content_type.trim().to_lowercase())
+            .filter(|content_type| !content_type.is_empty())
+            .collect(),
+    })
+}
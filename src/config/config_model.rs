@@ -27,3 +27,49 @@ pub struct GuildCommandersSecret {
     pub secret: String,
     pub refresh_secret: String,
 }
+
+#[derive(Debug, Clone)]
+pub struct CrewLimits {
+    pub max_crew_ceiling: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct QuestInvitations {
+    pub expiry_hours: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct AdventurerLevels {
+    pub base_experience: i64,
+    pub growth_factor: f64,
+    pub failure_experience_penalty: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct QuestRetention {
+    pub restore_grace_hours: i64,
+    pub purge_retention_days: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct AdminSecret {
+    pub api_key: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct QuestScheduler {
+    pub interval: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct IdempotencyKeys {
+    pub ttl_hours: i64,
+    pub in_progress_timeout_secs: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct QuestAttachments {
+    pub storage_dir: String,
+    pub max_file_size_bytes: usize,
+    pub allowed_content_types: Vec<String>,
+}
@@ -0,0 +1,41 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::achievement_model::{AchievementModel, badge_definition},
+    infrastructure::postgres::schema::adventurer_achievements,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = adventurer_achievements)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AdventurerAchievementEntity {
+    pub id: i32,
+    pub adventurer_id: i32,
+    pub badge_code: String,
+    pub quest_id: Option<i32>,
+    pub awarded_at: NaiveDateTime,
+}
+
+impl AdventurerAchievementEntity {
+    pub fn to_model(&self) -> AchievementModel {
+        let badge = badge_definition(&self.badge_code);
+
+        AchievementModel {
+            badge_code: self.badge_code.clone(),
+            name: badge.map(|badge| badge.name.to_string()),
+            description: badge.map(|badge| badge.description.to_string()),
+            quest_id: self.quest_id,
+            awarded_at: self.awarded_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = adventurer_achievements)]
+pub struct AddAdventurerAchievementEntity {
+    pub adventurer_id: i32,
+    pub badge_code: String,
+    pub quest_id: Option<i32>,
+    pub awarded_at: NaiveDateTime,
+}
@@ -0,0 +1,47 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{
+        adventurer_ledger_model::AdventurerLedgerModel, ledger_entry_types::LedgerEntryTypes,
+    },
+    infrastructure::postgres::schema::adventurer_ledger,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = adventurer_ledger)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AdventurerLedgerEntity {
+    pub id: i32,
+    pub adventurer_id: i32,
+    pub quest_id: Option<i32>,
+    pub entry_type: LedgerEntryTypes,
+    pub gold: i32,
+    pub experience: i32,
+    pub created_at: NaiveDateTime,
+}
+
+impl AdventurerLedgerEntity {
+    pub fn to_model(&self) -> AdventurerLedgerModel {
+        AdventurerLedgerModel {
+            id: self.id,
+            adventurer_id: self.adventurer_id,
+            quest_id: self.quest_id,
+            entry_type: self.entry_type.clone(),
+            gold: self.gold,
+            experience: self.experience,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = adventurer_ledger)]
+pub struct AddAdventurerLedgerEntity {
+    pub adventurer_id: i32,
+    pub quest_id: Option<i32>,
+    pub entry_type: String,
+    pub gold: i32,
+    pub experience: i32,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,78 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{
+        guild_model::{GuildMemberModel, GuildModel},
+        guild_roles::GuildRoles,
+    },
+    infrastructure::postgres::schema::{guild_members, guilds},
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = guilds)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct GuildEntity {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl GuildEntity {
+    pub fn to_model(&self, members_count: i64) -> GuildModel {
+        GuildModel {
+            id: self.id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            members_count,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = guilds)]
+pub struct AddGuildEntity {
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = guild_members)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct GuildMemberEntity {
+    pub id: i32,
+    pub guild_id: i32,
+    pub guild_commander_id: Option<i32>,
+    pub adventurer_id: Option<i32>,
+    pub role: GuildRoles,
+    pub joined_at: NaiveDateTime,
+}
+
+impl GuildMemberEntity {
+    pub fn to_model(&self) -> GuildMemberModel {
+        GuildMemberModel {
+            id: self.id,
+            guild_id: self.guild_id,
+            guild_commander_id: self.guild_commander_id,
+            adventurer_id: self.adventurer_id,
+            role: self.role.clone(),
+            joined_at: self.joined_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = guild_members)]
+pub struct AddGuildMemberEntity {
+    pub guild_id: i32,
+    pub guild_commander_id: Option<i32>,
+    pub adventurer_id: Option<i32>,
+    pub role: String,
+    pub joined_at: NaiveDateTime,
+}
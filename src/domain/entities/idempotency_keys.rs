@@ -0,0 +1,45 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::postgres::schema::idempotency_keys;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = idempotency_keys)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IdempotencyKeyEntity {
+    pub id: i32,
+    pub user_scope: String,
+    pub idempotency_key: String,
+    pub request_method: String,
+    pub request_path: String,
+    pub status_code: Option<i32>,
+    pub content_type: Option<String>,
+    pub response_body: Option<Vec<u8>>,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+    pub request_fingerprint: String,
+    pub etag: Option<String>,
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = idempotency_keys)]
+pub struct AddIdempotencyKeyEntity {
+    pub user_scope: String,
+    pub idempotency_key: String,
+    pub request_method: String,
+    pub request_path: String,
+    pub request_fingerprint: String,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Queryable, AsChangeset)]
+#[diesel(table_name = idempotency_keys)]
+pub struct CompleteIdempotencyKeyEntity {
+    pub status_code: i32,
+    pub content_type: Option<String>,
+    pub etag: Option<String>,
+    pub location: Option<String>,
+    pub response_body: Vec<u8>,
+}
@@ -0,0 +1,46 @@
+use chrono::NaiveDate;
+use diesel::prelude::*;
+
+use crate::infrastructure::postgres::schema::{
+    adventurer_stats_daily, guild_commander_stats_daily,
+};
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = adventurer_stats_daily)]
+pub struct AddAdventurerStatsDailyEntity {
+    pub adventurer_id: i32,
+    pub stat_date: NaiveDate,
+    pub completed_count: i32,
+    pub failed_count: i32,
+    pub gold_earned: i32,
+    pub experience_earned: i32,
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = guild_commander_stats_daily)]
+pub struct AddGuildCommanderStatsDailyEntity {
+    pub guild_commander_id: i32,
+    pub stat_date: NaiveDate,
+    pub completed_count: i32,
+    pub failed_count: i32,
+    pub rating_score_total: i32,
+    pub ratings_count: i32,
+}
+
+#[derive(Debug, Clone, Queryable)]
+pub struct AdventurerLeaderboardEntity {
+    pub adventurer_id: i32,
+    pub completed_count: i64,
+    pub failed_count: i64,
+    pub gold_earned: i64,
+    pub experience_earned: i64,
+}
+
+#[derive(Debug, Clone, Queryable)]
+pub struct GuildCommanderLeaderboardEntity {
+    pub guild_commander_id: i32,
+    pub completed_count: i64,
+    pub failed_count: i64,
+    pub rating_score_total: i64,
+    pub ratings_count: i64,
+}
@@ -1,3 +1,22 @@
+pub mod adventurer_achievements;
+pub mod adventurer_ledger;
 pub mod adventurers;
 pub mod guild_commanders;
-pub mod quests;
\ No newline at end of file
+pub mod guilds;
+pub mod idempotency_keys;
+pub mod leaderboards;
+pub mod quest_checkpoints;
+pub mod quest_comments;
+pub mod quest_crew_bans;
+pub mod quest_crew_members;
+pub mod quest_final_crews;
+pub mod quest_invitations;
+pub mod quest_join_applications;
+pub mod quest_ratings;
+pub mod quest_recurrences;
+pub mod quest_reports;
+pub mod quest_revisions;
+pub mod quest_templates;
+pub mod quest_waitlist;
+pub mod quests;
+pub mod tags;
@@ -0,0 +1,65 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_checkpoint_model::QuestCheckpointModel,
+    infrastructure::postgres::schema::quest_checkpoints,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_checkpoints)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct QuestCheckpointEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub mandatory: bool,
+    pub position: i32,
+    pub completed_by_adventurer_id: Option<i32>,
+    pub completion_note: Option<String>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl QuestCheckpointEntity {
+    pub fn to_model(&self) -> QuestCheckpointModel {
+        QuestCheckpointModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            title: self.title.clone(),
+            description: self.description.clone(),
+            mandatory: self.mandatory,
+            position: self.position,
+            done: self.completed_at.is_some(),
+            completed_by_adventurer_id: self.completed_by_adventurer_id,
+            completion_note: self.completion_note.clone(),
+            completed_at: self.completed_at,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_checkpoints)]
+pub struct AddQuestCheckpointEntity {
+    pub quest_id: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub mandatory: bool,
+    pub position: i32,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Queryable, AsChangeset)]
+#[diesel(table_name = quest_checkpoints)]
+pub struct EditQuestCheckpointEntity {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub mandatory: Option<bool>,
+    pub position: Option<i32>,
+    pub updated_at: NaiveDateTime,
+}
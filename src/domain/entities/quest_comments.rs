@@ -0,0 +1,69 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{
+        quest_comment_model::QuestCommentModel, quest_participants::QuestParticipants,
+    },
+    infrastructure::postgres::schema::quest_comments,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_comments)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct QuestCommentEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub parent_comment_id: Option<i32>,
+    pub adventurer_id: Option<i32>,
+    pub guild_commander_id: Option<i32>,
+    pub body: String,
+    pub moderated: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+impl QuestCommentEntity {
+    pub fn to_model(&self, replies: Vec<QuestCommentModel>) -> QuestCommentModel {
+        QuestCommentModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            parent_comment_id: self.parent_comment_id,
+            adventurer_id: self.adventurer_id,
+            guild_commander_id: self.guild_commander_id,
+            body: match self.deleted_at {
+                Some(_) => None,
+                None => Some(self.body.clone()),
+            },
+            deleted: self.deleted_at.is_some(),
+            moderated: self.moderated,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            replies,
+        }
+    }
+
+    pub fn is_authored_by(&self, participant: QuestParticipants) -> bool {
+        match participant {
+            QuestParticipants::Adventurer(adventurer_id) => {
+                self.adventurer_id == Some(adventurer_id)
+            }
+            QuestParticipants::GuildCommander(guild_commander_id) => {
+                self.guild_commander_id == Some(guild_commander_id)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_comments)]
+pub struct AddQuestCommentEntity {
+    pub quest_id: i32,
+    pub parent_comment_id: Option<i32>,
+    pub adventurer_id: Option<i32>,
+    pub guild_commander_id: Option<i32>,
+    pub body: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
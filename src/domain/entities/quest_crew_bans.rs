@@ -0,0 +1,28 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_crew_ban_model::QuestCrewBanModel,
+    infrastructure::postgres::schema::quest_crew_bans,
+};
+
+#[derive(Debug, Clone, Selectable, Insertable, Queryable)]
+#[diesel(table_name = quest_crew_bans)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct QuestCrewBanEntity {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub guild_commander_id: i32,
+    pub created_at: NaiveDateTime,
+}
+
+impl QuestCrewBanEntity {
+    pub fn to_model(&self) -> QuestCrewBanModel {
+        QuestCrewBanModel {
+            quest_id: self.quest_id,
+            adventurer_id: self.adventurer_id,
+            guild_commander_id: self.guild_commander_id,
+            created_at: self.created_at,
+        }
+    }
+}
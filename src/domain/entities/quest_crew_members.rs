@@ -0,0 +1,14 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::postgres::schema::quest_adventurer_junction;
+
+#[derive(Debug, Clone, Selectable, Queryable)]
+#[diesel(table_name = quest_adventurer_junction)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct QuestCrewMemberEntity {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub joined_at: NaiveDateTime,
+    pub reconfirmation_required: bool,
+}
@@ -0,0 +1,12 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::postgres::schema::quest_final_crews;
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_final_crews)]
+pub struct AddQuestFinalCrewEntity {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub created_at: NaiveDateTime,
+}
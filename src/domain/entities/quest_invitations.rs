@@ -0,0 +1,42 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_invitation_model::QuestInvitationModel,
+    infrastructure::postgres::schema::quest_invitations,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_invitations)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct QuestInvitationEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub guild_commander_id: i32,
+    pub expires_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+impl QuestInvitationEntity {
+    pub fn to_model(&self) -> QuestInvitationModel {
+        QuestInvitationModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            adventurer_id: self.adventurer_id,
+            guild_commander_id: self.guild_commander_id,
+            expires_at: self.expires_at,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_invitations)]
+pub struct AddQuestInvitationEntity {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub guild_commander_id: i32,
+    pub expires_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,26 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_join_application_model::QuestJoinApplicationModel,
+    infrastructure::postgres::schema::quest_join_applications,
+};
+
+#[derive(Debug, Clone, Selectable, Insertable, Queryable)]
+#[diesel(table_name = quest_join_applications)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct QuestJoinApplicationEntity {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub created_at: NaiveDateTime,
+}
+
+impl QuestJoinApplicationEntity {
+    pub fn to_model(&self) -> QuestJoinApplicationModel {
+        QuestJoinApplicationModel {
+            quest_id: self.quest_id,
+            adventurer_id: self.adventurer_id,
+            created_at: self.created_at,
+        }
+    }
+}
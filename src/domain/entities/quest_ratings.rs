@@ -0,0 +1,48 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_rating_model::QuestRatingModel,
+    infrastructure::postgres::schema::quest_ratings,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_ratings)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct QuestRatingEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub guild_commander_id: i32,
+    pub adventurer_id: i32,
+    pub direction: String,
+    pub score: i32,
+    pub comment: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl QuestRatingEntity {
+    pub fn to_model(&self) -> QuestRatingModel {
+        QuestRatingModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            guild_commander_id: self.guild_commander_id,
+            adventurer_id: self.adventurer_id,
+            direction: self.direction.clone(),
+            score: self.score,
+            comment: self.comment.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_ratings)]
+pub struct AddQuestRatingEntity {
+    pub quest_id: i32,
+    pub guild_commander_id: i32,
+    pub adventurer_id: i32,
+    pub direction: String,
+    pub score: i32,
+    pub comment: Option<String>,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,63 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{
+        quest_recurrence_model::QuestRecurrenceModel, recurrence_statuses::RecurrenceStatuses,
+    },
+    infrastructure::postgres::schema::{quest_recurrence_instances, quest_recurrences},
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_recurrences)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct QuestRecurrenceEntity {
+    pub id: i32,
+    pub guild_commander_id: i32,
+    pub quest_template_id: i32,
+    pub schedule: String,
+    pub max_open_instances: i32,
+    pub status: RecurrenceStatuses,
+    pub next_run_at: NaiveDateTime,
+    pub last_run_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl QuestRecurrenceEntity {
+    pub fn to_model(&self) -> QuestRecurrenceModel {
+        QuestRecurrenceModel {
+            id: self.id,
+            guild_commander_id: self.guild_commander_id,
+            quest_template_id: self.quest_template_id,
+            schedule: self.schedule.clone(),
+            max_open_instances: self.max_open_instances,
+            status: self.status.clone(),
+            next_run_at: self.next_run_at,
+            last_run_at: self.last_run_at,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_recurrences)]
+pub struct AddQuestRecurrenceEntity {
+    pub guild_commander_id: i32,
+    pub quest_template_id: i32,
+    pub schedule: String,
+    pub max_open_instances: i32,
+    pub status: String,
+    pub next_run_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_recurrence_instances)]
+pub struct QuestRecurrenceInstanceEntity {
+    pub quest_recurrence_id: i32,
+    pub quest_id: i32,
+    pub created_at: NaiveDateTime,
+}
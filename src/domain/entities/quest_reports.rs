@@ -0,0 +1,82 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_report_model::{QuestAttachmentModel, QuestReportModel},
+    infrastructure::postgres::schema::{quest_attachments, quest_reports},
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_reports)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct QuestReportEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub guild_commander_id: i32,
+    pub outcome: String,
+    pub body: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl QuestReportEntity {
+    pub fn to_model(&self) -> QuestReportModel {
+        QuestReportModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            guild_commander_id: self.guild_commander_id,
+            outcome: self.outcome.clone(),
+            body: self.body.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_reports)]
+pub struct AddQuestReportEntity {
+    pub quest_id: i32,
+    pub guild_commander_id: i32,
+    pub outcome: String,
+    pub body: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_attachments)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct QuestAttachmentEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl QuestAttachmentEntity {
+    pub fn to_model(&self) -> QuestAttachmentModel {
+        QuestAttachmentModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            adventurer_id: self.adventurer_id,
+            file_name: self.file_name.clone(),
+            content_type: self.content_type.clone(),
+            size_bytes: self.size_bytes,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_attachments)]
+pub struct AddQuestAttachmentEntity {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+    pub created_at: NaiveDateTime,
+}
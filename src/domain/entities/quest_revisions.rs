@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_revision_model::{QuestFieldChangeModel, QuestRevisionModel},
+    infrastructure::postgres::schema::quest_revisions,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_revisions)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct QuestRevisionEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub revision_number: i32,
+    pub guild_commander_id: i32,
+    pub changes: serde_json::Value,
+    pub requires_reconfirmation: bool,
+    pub created_at: NaiveDateTime,
+}
+
+impl QuestRevisionEntity {
+    pub fn to_model(&self) -> QuestRevisionModel {
+        QuestRevisionModel {
+            id: self.id,
+            quest_id: self.quest_id,
+            revision_number: self.revision_number,
+            guild_commander_id: self.guild_commander_id,
+            changes: serde_json::from_value::<Vec<QuestFieldChangeModel>>(self.changes.clone())
+                .unwrap_or_default(),
+            requires_reconfirmation: self.requires_reconfirmation,
+            created_at: self.created_at,
+        }
+    }
+}
+
+// revision_number ถูกกำหนดตอน insert ใน transaction
+#[derive(Debug, Clone)]
+pub struct AddQuestRevisionEntity {
+    pub guild_commander_id: i32,
+    pub changes: serde_json::Value,
+    pub requires_reconfirmation: bool,
+    pub created_at: NaiveDateTime,
+}
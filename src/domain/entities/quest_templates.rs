@@ -0,0 +1,75 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{
+        join_policies::JoinPolicies, quest_template_model::QuestTemplateModel,
+        quest_visibilities::QuestVisibilities,
+    },
+    infrastructure::postgres::schema::quest_templates,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_templates)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct QuestTemplateEntity {
+    pub id: i32,
+    pub guild_commander_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: JoinPolicies,
+    pub reward_gold: i32,
+    pub reward_experience: i32,
+    pub min_level: i32,
+    pub tags: Vec<Option<String>>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub require_checkpoints: bool,
+    pub guild_id: Option<i32>,
+    pub visibility: QuestVisibilities,
+}
+
+impl QuestTemplateEntity {
+    pub fn to_model(&self) -> QuestTemplateModel {
+        QuestTemplateModel {
+            id: self.id,
+            guild_commander_id: self.guild_commander_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+            join_policy: self.join_policy.clone(),
+            reward_gold: self.reward_gold,
+            reward_experience: self.reward_experience,
+            min_level: self.min_level,
+            require_checkpoints: self.require_checkpoints,
+            guild_id: self.guild_id,
+            visibility: self.visibility.clone(),
+            tags: self.tags.iter().flatten().cloned().collect(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_templates)]
+pub struct AddQuestTemplateEntity {
+    pub guild_commander_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: String,
+    pub reward_gold: i32,
+    pub reward_experience: i32,
+    pub min_level: i32,
+    pub tags: Vec<Option<String>>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub require_checkpoints: bool,
+    pub guild_id: Option<i32>,
+    pub visibility: String,
+}
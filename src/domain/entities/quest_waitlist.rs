@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::quest_waitlist_model::QuestWaitlistModel,
+    infrastructure::postgres::schema::quest_waitlist,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = quest_waitlist)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct QuestWaitlistEntity {
+    pub id: i32,
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub created_at: NaiveDateTime,
+}
+
+impl QuestWaitlistEntity {
+    pub fn to_model(&self, position: i64) -> QuestWaitlistModel {
+        QuestWaitlistModel {
+            quest_id: self.quest_id,
+            adventurer_id: self.adventurer_id,
+            position,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_waitlist)]
+pub struct AddQuestWaitlistEntity {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub created_at: NaiveDateTime,
+}
@@ -2,7 +2,10 @@ use chrono::NaiveDateTime;
 use diesel::prelude::*;
 
 use crate::{
-    domain::value_objects::{quest_model::QuestModel, quest_statuses::QuestStatuses},
+    domain::value_objects::{
+        join_policies::JoinPolicies, quest_model::QuestModel, quest_statuses::QuestStatuses,
+        quest_visibilities::QuestVisibilities,
+    },
     infrastructure::postgres::schema::quests,
 };
 
@@ -19,10 +22,23 @@ pub struct QuestEntity {
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
     // pub deleted_at: Option<NaiveDateTime>,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: JoinPolicies,
+    pub starts_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub reward_gold: i32,
+    pub reward_experience: i32,
+    pub min_level: i32,
+    pub version: i32,
+    pub require_checkpoints: bool,
+    pub guild_id: Option<i32>,
+    pub visibility: QuestVisibilities,
 }
 
 impl QuestEntity {
-    pub fn to_model(&self, adventurers_count: i64) -> QuestModel {
+    pub fn to_model(&self, adventurers_count: i64, tags: Vec<String>) -> QuestModel {
         QuestModel {
             id: self.id,
             name: self.name.clone(),
@@ -31,6 +47,23 @@ impl QuestEntity {
             guild_commander_id: self.guild_commander_id,
             created_at: self.created_at,
             updated_at: self.updated_at,
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+            join_policy: self.join_policy.clone(),
+            starts_at: self.starts_at,
+            join_deadline: self.join_deadline,
+            due_at: self.due_at,
+            reward_gold: self.reward_gold,
+            reward_experience: self.reward_experience,
+            min_level: self.min_level,
+            version: self.version,
+            require_checkpoints: self.require_checkpoints,
+            guild_id: self.guild_id,
+            visibility: self.visibility.clone(),
+            progress_percent: None,
+            tags,
+            search_rank: None,
+            search_snippet: None,
             adventurers_count,
         }
     }
@@ -46,6 +79,18 @@ pub struct AddQuestEntity {
     pub guild_commander_id: i32,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: String,
+    pub starts_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub reward_gold: i32,
+    pub reward_experience: i32,
+    pub min_level: i32,
+    pub require_checkpoints: bool,
+    pub guild_id: Option<i32>,
+    pub visibility: String,
 }
 
 #[derive(Debug, Clone, Queryable, AsChangeset)]
@@ -61,7 +106,20 @@ pub struct QuestAdventurerJunctionEntity {
 #[diesel(table_name = quests)]
 pub struct EditQuestEntity {
     pub name: Option<String>,
-    pub description: Option<String>,
+    pub description: Option<Option<String>>,
     pub guild_commander_id: i32,
     pub updated_at: NaiveDateTime,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    pub join_policy: Option<String>,
+    // Some(None) ล้างค่าเป็น NULL ใช้ตอน revert
+    pub starts_at: Option<Option<NaiveDateTime>>,
+    pub join_deadline: Option<Option<NaiveDateTime>>,
+    pub due_at: Option<Option<NaiveDateTime>>,
+    pub reward_gold: Option<i32>,
+    pub reward_experience: Option<i32>,
+    pub min_level: Option<i32>,
+    pub require_checkpoints: Option<bool>,
+    pub guild_id: Option<Option<i32>>,
+    pub visibility: Option<String>,
 }
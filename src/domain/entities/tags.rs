@@ -0,0 +1,27 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::postgres::schema::{quest_tags, tags};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = tags)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct TagEntity {
+    pub id: i32,
+    pub name: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = tags)]
+pub struct AddTagEntity {
+    pub name: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable, Queryable)]
+#[diesel(table_name = quest_tags)]
+pub struct QuestTagEntity {
+    pub quest_id: i32,
+    pub tag_id: i32,
+}
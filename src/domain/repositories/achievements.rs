@@ -0,0 +1,28 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::adventurer_achievements::{
+        AddAdventurerAchievementEntity, AdventurerAchievementEntity,
+    },
+    value_objects::achievement_model::AdventurerAchievementStatsModel,
+};
+
+#[async_trait]
+#[automock]
+pub trait AchievementsRepository {
+    async fn achievement_stats(
+        &self,
+        adventurer_id: i32,
+    ) -> Result<AdventurerAchievementStatsModel>;
+    async fn crew_adventurer_ids(&self, quest_id: i32) -> Result<Vec<i32>>;
+    async fn by_adventurer_id(
+        &self,
+        adventurer_id: i32,
+    ) -> Result<Vec<AdventurerAchievementEntity>>;
+    async fn award(
+        &self,
+        add_adventurer_achievement_entities: Vec<AddAdventurerAchievementEntity>,
+    ) -> Result<Vec<String>>;
+}
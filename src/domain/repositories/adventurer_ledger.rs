@@ -0,0 +1,16 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::adventurer_ledger::AdventurerLedgerEntity;
+
+#[async_trait]
+#[automock]
+pub trait AdventurerLedgerRepository {
+    async fn gold_balance(&self, adventurer_id: i32) -> Result<i64>;
+    async fn experience_total(&self, adventurer_id: i32) -> Result<i64>;
+    async fn entries_by_adventurer_id(
+        &self,
+        adventurer_id: i32,
+    ) -> Result<Vec<AdventurerLedgerEntity>>;
+}
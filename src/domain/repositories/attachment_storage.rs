@@ -0,0 +1,11 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+#[async_trait]
+#[automock]
+pub trait AttachmentStorage {
+    async fn put(&self, storage_key: &str, content: Vec<u8>) -> Result<()>;
+    async fn get(&self, storage_key: &str) -> Result<Vec<u8>>;
+    async fn delete(&self, storage_key: &str) -> Result<()>;
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::entities::quest_invitations::{AddQuestInvitationEntity, QuestInvitationEntity};
+
+#[async_trait]
+#[automock]
+pub trait CrewInvitationsRepository {
+    async fn invite(&self, invitation_entities: Vec<AddQuestInvitationEntity>) -> Result<Vec<i32>>;
+    async fn pending_by_adventurer_id(
+        &self,
+        adventurer_id: i32,
+        now: NaiveDateTime,
+    ) -> Result<Vec<QuestInvitationEntity>>;
+    async fn pending_by_quest_id(
+        &self,
+        quest_id: i32,
+        now: NaiveDateTime,
+    ) -> Result<Vec<QuestInvitationEntity>>;
+    async fn find_pending(
+        &self,
+        invitation_id: i32,
+        adventurer_id: i32,
+        now: NaiveDateTime,
+    ) -> Result<QuestInvitationEntity>;
+    async fn remove(&self, invitation_id: i32) -> Result<()>;
+}
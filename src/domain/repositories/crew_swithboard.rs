@@ -2,11 +2,49 @@ use anyhow::Result;
 use axum::async_trait;
 use mockall::automock;
 
-use crate::domain::value_objects::quest_adventurer_juntion::QuestAdventurerJunction;
+use crate::domain::{
+    entities::{
+        quest_crew_bans::QuestCrewBanEntity,
+        quest_crew_members::QuestCrewMemberEntity,
+        quest_join_applications::QuestJoinApplicationEntity,
+        quest_waitlist::{AddQuestWaitlistEntity, QuestWaitlistEntity},
+    },
+    value_objects::quest_adventurer_juntion::QuestAdventurerJunction,
+};
 
 #[async_trait]
 #[automock]
 pub trait CrewSwithboardRepository {
-  async fn join(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
-  async fn leave(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
+    async fn join(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        waitlist_entity: Option<AddQuestWaitlistEntity>,
+    ) -> Result<bool>;
+    async fn leave(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
+    async fn kick(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        ban_entity: Option<QuestCrewBanEntity>,
+    ) -> Result<()>;
+    async fn unban(&self, quest_id: i32, adventurer_id: i32) -> Result<()>;
+    async fn is_banned(&self, quest_id: i32, adventurer_id: i32) -> Result<bool>;
+    async fn bans_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestCrewBanEntity>>;
+    async fn apply(&self, application_entity: QuestJoinApplicationEntity) -> Result<()>;
+    async fn remove_application(&self, quest_id: i32, adventurer_id: i32) -> Result<()>;
+    async fn applications_by_quest_id(
+        &self,
+        quest_id: i32,
+    ) -> Result<Vec<QuestJoinApplicationEntity>>;
+    async fn approve(&self, junction_body: QuestAdventurerJunction) -> Result<()>;
+    async fn is_crew_member(&self, quest_id: i32, adventurer_id: i32) -> Result<bool>;
+    async fn crew_member(&self, quest_id: i32, adventurer_id: i32)
+    -> Result<QuestCrewMemberEntity>;
+    async fn reconfirm(&self, quest_id: i32, adventurer_id: i32) -> Result<()>;
+    async fn reconfirmations_by_adventurer_id(&self, adventurer_id: i32) -> Result<Vec<i32>>;
+    async fn leave_waitlist(&self, quest_id: i32, adventurer_id: i32) -> Result<()>;
+    async fn waitlist_position(&self, quest_id: i32, adventurer_id: i32) -> Result<i64>;
+    async fn waitlist_by_adventurer_id(
+        &self,
+        adventurer_id: i32,
+    ) -> Result<Vec<QuestWaitlistEntity>>;
 }
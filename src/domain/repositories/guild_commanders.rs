@@ -1,7 +1,4 @@
-use crate::domain::entities::{
-    adventurers::RegisterAdventurerEntity,
-    guild_commanders::{GuildCommanderEntity, RegisterGuildCommanderEntity},
-};
+use crate::domain::entities::guild_commanders::{GuildCommanderEntity, RegisterGuildCommanderEntity};
 use anyhow::Result;
 use axum::async_trait;
 use mockall::automock;
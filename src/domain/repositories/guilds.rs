@@ -0,0 +1,36 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::guilds::{AddGuildEntity, AddGuildMemberEntity, GuildEntity, GuildMemberEntity},
+    value_objects::quest_participants::QuestParticipants,
+};
+
+#[async_trait]
+#[automock]
+pub trait GuildsRepository {
+    async fn create(
+        &self,
+        add_guild_entity: AddGuildEntity,
+        founder_guild_commander_id: i32,
+    ) -> Result<i32>;
+    async fn view_details(&self, guild_id: i32) -> Result<GuildEntity>;
+    async fn guilds(&self, limit: i64, offset: i64) -> Result<Vec<GuildEntity>>;
+    async fn guilds_counting(&self) -> Result<i64>;
+    async fn members_counting_by_guild_id(&self, guild_id: i32) -> Result<i64>;
+    async fn members_by_guild_id(&self, guild_id: i32) -> Result<Vec<GuildMemberEntity>>;
+    async fn member(
+        &self,
+        guild_id: i32,
+        participant: QuestParticipants,
+    ) -> Result<Option<GuildMemberEntity>>;
+    async fn member_details(&self, member_id: i32) -> Result<GuildMemberEntity>;
+    async fn memberships(
+        &self,
+        participant: QuestParticipants,
+    ) -> Result<Vec<(GuildMemberEntity, GuildEntity)>>;
+    async fn add_member(&self, add_guild_member_entity: AddGuildMemberEntity) -> Result<i32>;
+    async fn change_role(&self, member_id: i32, role: String) -> Result<i32>;
+    async fn remove_member(&self, member_id: i32) -> Result<()>;
+}
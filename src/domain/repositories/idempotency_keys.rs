@@ -0,0 +1,27 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::entities::idempotency_keys::{
+    AddIdempotencyKeyEntity, CompleteIdempotencyKeyEntity, IdempotencyKeyEntity,
+};
+
+#[async_trait]
+#[automock]
+pub trait IdempotencyKeysRepository {
+    async fn reserve(
+        &self,
+        add_idempotency_key_entity: AddIdempotencyKeyEntity,
+        abandoned_before: NaiveDateTime,
+    ) -> Result<bool>;
+    async fn find(&self, user_scope: &str, idempotency_key: &str) -> Result<IdempotencyKeyEntity>;
+    async fn complete(
+        &self,
+        user_scope: &str,
+        idempotency_key: &str,
+        complete_idempotency_key_entity: CompleteIdempotencyKeyEntity,
+    ) -> Result<()>;
+    async fn release(&self, user_scope: &str, idempotency_key: &str) -> Result<()>;
+    async fn purge_expired(&self, now: NaiveDateTime) -> Result<usize>;
+}
@@ -5,7 +5,25 @@ use mockall::automock;
 #[async_trait]
 #[automock]
 pub trait JourneyLedgerRepository {
-    async fn in_journey(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32>;
-    async fn to_completed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32>;
-    async fn to_failed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32>;
+    async fn in_journey(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        expected_version: Option<i32>,
+    ) -> Result<i32>;
+    async fn to_completed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        expected_version: Option<i32>,
+        report_body: Option<String>,
+    ) -> Result<i32>;
+    async fn to_failed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        expected_version: Option<i32>,
+        experience_penalty: i32,
+        report_body: Option<String>,
+    ) -> Result<i32>;
 }
@@ -0,0 +1,32 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDate;
+use mockall::automock;
+
+use crate::domain::{
+    entities::leaderboards::{AdventurerLeaderboardEntity, GuildCommanderLeaderboardEntity},
+    value_objects::leaderboard_model::{
+        AdventurerLeaderboardSorts, GuildCommanderLeaderboardSorts,
+    },
+};
+
+#[async_trait]
+#[automock]
+pub trait LeaderboardsRepository {
+    async fn adventurers(
+        &self,
+        since: Option<NaiveDate>,
+        sort: AdventurerLeaderboardSorts,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AdventurerLeaderboardEntity>>;
+    async fn adventurers_counting(&self, since: Option<NaiveDate>) -> Result<i64>;
+    async fn guild_commanders(
+        &self,
+        since: Option<NaiveDate>,
+        sort: GuildCommanderLeaderboardSorts,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<GuildCommanderLeaderboardEntity>>;
+    async fn guild_commanders_counting(&self, since: Option<NaiveDate>) -> Result<i64>;
+}
@@ -0,0 +1,28 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::quest_checkpoints::{
+    AddQuestCheckpointEntity, EditQuestCheckpointEntity, QuestCheckpointEntity,
+};
+
+#[async_trait]
+#[automock]
+pub trait QuestCheckpointsRepository {
+    async fn add(&self, add_quest_checkpoint_entity: AddQuestCheckpointEntity) -> Result<i32>;
+    async fn view_details(&self, checkpoint_id: i32) -> Result<QuestCheckpointEntity>;
+    async fn edit(
+        &self,
+        checkpoint_id: i32,
+        edit_quest_checkpoint_entity: EditQuestCheckpointEntity,
+    ) -> Result<i32>;
+    async fn remove(&self, checkpoint_id: i32) -> Result<()>;
+    async fn by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestCheckpointEntity>>;
+    async fn complete(
+        &self,
+        checkpoint_id: i32,
+        adventurer_id: i32,
+        note: Option<String>,
+    ) -> Result<i32>;
+    async fn reopen(&self, checkpoint_id: i32) -> Result<i32>;
+}
@@ -1,13 +1,40 @@
 use anyhow::Result;
 use axum::async_trait;
+use chrono::NaiveDateTime;
 use mockall::automock;
 
-use crate::domain::entities::quests::{AddQuestEntity, EditQuestEntity};
+use crate::domain::entities::{
+    quest_revisions::AddQuestRevisionEntity,
+    quests::{AddQuestEntity, EditQuestEntity, QuestEntity},
+};
 
 #[async_trait]
 #[automock]
 pub trait QuestOpsRepository {
-    async fn add(&self, add_quest_model: AddQuestEntity) -> Result<i32>;
-    async fn edit(&self, quest_id: i32, edit_quest_entity: EditQuestEntity) -> Result<i32>;
+    async fn add(&self, add_quest_entity: AddQuestEntity, tags: Vec<String>) -> Result<i32>;
+    async fn edit(
+        &self,
+        quest_id: i32,
+        expected_version: Option<i32>,
+        edit_quest_entity: EditQuestEntity,
+        add_quest_revision_entity: AddQuestRevisionEntity,
+    ) -> Result<i32>;
     async fn remove(&self, quest_id: i32, guild_commander_id: i32) -> Result<()>;
+    async fn deleted_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+        deleted_after: NaiveDateTime,
+    ) -> Result<Vec<QuestEntity>>;
+    async fn restore(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        deleted_after: NaiveDateTime,
+    ) -> Result<i32>;
+    // คืน id ของ quest ที่ถูกลบ กับ storage key ของไฟล์แนบที่ต้องลบตาม
+    async fn purge_deleted_before(
+        &self,
+        deleted_before: NaiveDateTime,
+    ) -> Result<(Vec<i32>, Vec<String>)>;
+    async fn set_tags(&self, quest_id: i32, tags: Vec<String>) -> Result<()>;
 }
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    entities::quest_ratings::{AddQuestRatingEntity, QuestRatingEntity},
+    value_objects::rating_directions::RatingDirections,
+};
+
+#[async_trait]
+#[automock]
+pub trait QuestRatingsRepository {
+    async fn add(&self, add_quest_rating_entity: AddQuestRatingEntity) -> Result<i32>;
+    async fn is_rated(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+        direction: RatingDirections,
+    ) -> Result<bool>;
+    async fn is_final_crew_member(&self, quest_id: i32, adventurer_id: i32) -> Result<bool>;
+    async fn by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestRatingEntity>>;
+    async fn adventurer_score_totals(&self, adventurer_id: i32) -> Result<(i64, i64)>;
+    async fn guild_commander_score_totals(&self, guild_commander_id: i32) -> Result<(i64, i64)>;
+}
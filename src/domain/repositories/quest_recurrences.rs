@@ -0,0 +1,38 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::domain::{
+    entities::{
+        quest_recurrences::{AddQuestRecurrenceEntity, QuestRecurrenceEntity},
+        quests::AddQuestEntity,
+    },
+    value_objects::recurrence_statuses::RecurrenceStatuses,
+};
+
+#[async_trait]
+#[automock]
+pub trait QuestRecurrencesRepository {
+    async fn add(&self, add_quest_recurrence_entity: AddQuestRecurrenceEntity) -> Result<i32>;
+    async fn view_details(&self, quest_recurrence_id: i32) -> Result<QuestRecurrenceEntity>;
+    async fn by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestRecurrenceEntity>>;
+    async fn update_status(
+        &self,
+        quest_recurrence_id: i32,
+        status: RecurrenceStatuses,
+        next_run_at: NaiveDateTime,
+    ) -> Result<()>;
+    async fn due(&self, now: NaiveDateTime) -> Result<Vec<QuestRecurrenceEntity>>;
+    async fn open_instances_count(&self, quest_recurrence_id: i32) -> Result<i64>;
+    async fn record_run(
+        &self,
+        quest_recurrence_id: i32,
+        spawned_quest: Option<(AddQuestEntity, Vec<String>)>,
+        ran_at: NaiveDateTime,
+        next_run_at: NaiveDateTime,
+    ) -> Result<Option<i32>>;
+}
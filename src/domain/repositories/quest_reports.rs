@@ -0,0 +1,20 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::quest_reports::{
+    AddQuestAttachmentEntity, QuestAttachmentEntity, QuestReportEntity,
+};
+
+#[async_trait]
+#[automock]
+pub trait QuestReportsRepository {
+    async fn reports_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestReportEntity>>;
+    async fn add_attachment(
+        &self,
+        add_quest_attachment_entity: AddQuestAttachmentEntity,
+    ) -> Result<i32>;
+    async fn attachment(&self, attachment_id: i32) -> Result<QuestAttachmentEntity>;
+    async fn attachments_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestAttachmentEntity>>;
+    async fn remove_attachment(&self, attachment_id: i32) -> Result<()>;
+}
@@ -0,0 +1,17 @@
+use anyhow::Result;
+use axum::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::quest_templates::{AddQuestTemplateEntity, QuestTemplateEntity};
+
+#[async_trait]
+#[automock]
+pub trait QuestTemplatesRepository {
+    async fn add(&self, add_quest_template_entity: AddQuestTemplateEntity) -> Result<i32>;
+    async fn view_details(&self, quest_template_id: i32) -> Result<QuestTemplateEntity>;
+    async fn by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestTemplateEntity>>;
+    async fn remove(&self, quest_template_id: i32, guild_commander_id: i32) -> Result<()>;
+}
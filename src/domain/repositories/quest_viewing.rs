@@ -1,15 +1,39 @@
 use anyhow::Result;
 use axum::async_trait;
+use chrono::NaiveDateTime;
 use mockall::automock;
 
 use crate::domain::{
-    entities::quests::QuestEntity, value_objects::board_checking_filter::BoardCheckingFilter,
+    entities::{quest_revisions::QuestRevisionEntity, quests::QuestEntity},
+    value_objects::{
+        board_checking_filter::BoardCheckingFilter, quest_participants::QuestParticipants,
+    },
 };
 
 #[async_trait]
 #[automock]
 pub trait QuestViewingRepository {
-    async fn view_details(&self, quest_id: i32) -> Result<QuestEntity>;
-    async fn board_checking(&self, filter: &BoardCheckingFilter) -> Result<Vec<QuestEntity>>;
+    async fn view_details(
+        &self,
+        quest_id: i32,
+        viewer: Option<QuestParticipants>,
+    ) -> Result<QuestEntity>;
+    async fn board_checking(
+        &self,
+        filter: &BoardCheckingFilter,
+        viewer: Option<QuestParticipants>,
+    ) -> Result<Vec<QuestEntity>>;
+    async fn search_highlights(
+        &self,
+        q: &str,
+        quest_ids: &[i32],
+    ) -> Result<Vec<(i32, f32, String)>>;
     async fn adventurers_counting_by_quest_id(&self, quest_id: i32) -> Result<i64>;
+    async fn tags_by_quest_id(&self, quest_id: i32) -> Result<Vec<String>>;
+    async fn tags_with_counts(&self) -> Result<Vec<(String, i64)>>;
+    async fn revisions_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestRevisionEntity>>;
+    async fn pending_reconfirmations_count(&self, quest_id: i32) -> Result<i64>;
+    async fn checkpoints_counting_by_quest_id(&self, quest_id: i32) -> Result<(i64, i64)>;
+    async fn pending_mandatory_checkpoints_count(&self, quest_id: i32) -> Result<i64>;
+    async fn overdue_in_journey(&self, now: NaiveDateTime) -> Result<Vec<QuestEntity>>;
 }
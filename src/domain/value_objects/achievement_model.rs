@@ -0,0 +1,171 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::quest_adventurer_juntion::MAX_ADVENTURERS_PER_QUEST;
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AchievementModel {
+    pub badge_code: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub quest_id: Option<i32>,
+    pub awarded_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AdventurerAchievementStatsModel {
+    pub joined_count: i64,
+    pub completed_count: i64,
+    pub failed_count: i64,
+    pub largest_crew_size: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AchievementRules {
+    JoinedQuestsAtLeast(i64),
+    CompletedQuestsAtLeast(i64),
+    CompletedWithoutFailureAtLeast(i64),
+    CrewSizeAtLeast(i64),
+}
+
+impl AchievementRules {
+    pub fn is_satisfied(&self, stats: &AdventurerAchievementStatsModel) -> bool {
+        match self {
+            AchievementRules::JoinedQuestsAtLeast(count) => stats.joined_count >= *count,
+            AchievementRules::CompletedQuestsAtLeast(count) => stats.completed_count >= *count,
+            AchievementRules::CompletedWithoutFailureAtLeast(count) => {
+                stats.completed_count >= *count && stats.failed_count == 0
+            }
+            AchievementRules::CrewSizeAtLeast(size) => stats.largest_crew_size >= *size,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BadgeDefinition {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub rule: AchievementRules,
+}
+
+// เพิ่ม badge ใหม่ได้ที่นี่ที่เดียว code ห้ามเปลี่ยนเพราะเก็บลง db
+pub const BADGE_DEFINITIONS: &[BadgeDefinition] = &[
+    BadgeDefinition {
+        code: "first_quest_joined",
+        name: "First Steps",
+        description: "Joined a quest crew for the first time",
+        rule: AchievementRules::JoinedQuestsAtLeast(1),
+    },
+    BadgeDefinition {
+        code: "first_quest_completed",
+        name: "Quest Complete",
+        description: "Completed a first quest",
+        rule: AchievementRules::CompletedQuestsAtLeast(1),
+    },
+    BadgeDefinition {
+        code: "veteran",
+        name: "Veteran",
+        description: "Completed 25 quests",
+        rule: AchievementRules::CompletedQuestsAtLeast(25),
+    },
+    BadgeDefinition {
+        code: "flawless_ten",
+        name: "Flawless",
+        description: "Completed 10 quests without a single failure",
+        rule: AchievementRules::CompletedWithoutFailureAtLeast(10),
+    },
+    BadgeDefinition {
+        code: "full_crew",
+        name: "Full Crew",
+        description: "Took part in a quest crew filled to the maximum size",
+        rule: AchievementRules::CrewSizeAtLeast(MAX_ADVENTURERS_PER_QUEST),
+    },
+];
+
+pub fn badge_definition(code: &str) -> Option<&'static BadgeDefinition> {
+    BADGE_DEFINITIONS.iter().find(|badge| badge.code == code)
+}
+
+pub fn earned_badge_codes(
+    stats: &AdventurerAchievementStatsModel,
+    awarded_badge_codes: &[String],
+) -> Vec<String> {
+    BADGE_DEFINITIONS
+        .iter()
+        .filter(|badge| !awarded_badge_codes.iter().any(|code| code == badge.code))
+        .filter(|badge| badge.rule.is_satisfied(stats))
+        .map(|badge| badge.code.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn earned_badge_codes_is_empty_for_new_adventurer() {
+        let stats = AdventurerAchievementStatsModel::default();
+
+        assert!(earned_badge_codes(&stats, &[]).is_empty());
+    }
+
+    #[test]
+    fn earned_badge_codes_returns_satisfied_badges() {
+        let stats = AdventurerAchievementStatsModel {
+            joined_count: 10,
+            completed_count: 10,
+            failed_count: 0,
+            largest_crew_size: MAX_ADVENTURERS_PER_QUEST,
+        };
+
+        assert_eq!(
+            earned_badge_codes(&stats, &[]),
+            vec![
+                "first_quest_joined",
+                "first_quest_completed",
+                "flawless_ten",
+                "full_crew"
+            ]
+        );
+    }
+
+    #[test]
+    fn earned_badge_codes_skips_awarded_badges() {
+        let stats = AdventurerAchievementStatsModel {
+            joined_count: 1,
+            completed_count: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            earned_badge_codes(&stats, &["first_quest_joined".to_string()]),
+            vec!["first_quest_completed"]
+        );
+    }
+
+    #[test]
+    fn earned_badge_codes_withholds_flawless_after_a_failure() {
+        let stats = AdventurerAchievementStatsModel {
+            joined_count: 11,
+            completed_count: 10,
+            failed_count: 1,
+            largest_crew_size: 1,
+        };
+
+        assert!(!earned_badge_codes(&stats, &[]).contains(&"flawless_ten".to_string()));
+    }
+
+    #[test]
+    fn badge_codes_are_unique() {
+        for badge in BADGE_DEFINITIONS {
+            let count = BADGE_DEFINITIONS
+                .iter()
+                .filter(|other| other.code == badge.code)
+                .count();
+
+            assert_eq!(count, 1, "duplicate badge code {}", badge.code);
+        }
+    }
+}
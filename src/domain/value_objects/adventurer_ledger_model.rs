@@ -0,0 +1,62 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::ledger_entry_types::LedgerEntryTypes;
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdventurerLedgerModel {
+    pub id: i32,
+    pub adventurer_id: i32,
+    pub quest_id: Option<i32>,
+    pub entry_type: LedgerEntryTypes,
+    pub gold: i32,
+    pub experience: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdventurerBalanceModel {
+    pub adventurer_id: i32,
+    pub gold: i64,
+}
+
+// แบ่งรางวัลเท่าๆ กัน เศษให้คนแรกๆ ตามลำดับ
+pub fn split_reward(total: i32, crew_size: usize) -> Vec<i32> {
+    if crew_size == 0 {
+        return Vec::new();
+    }
+
+    let share = total / crew_size as i32;
+    let remainder = (total % crew_size as i32) as usize;
+
+    (0..crew_size)
+        .map(|index| if index < remainder { share + 1 } else { share })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_reward_splits_evenly() {
+        assert_eq!(split_reward(90, 3), vec![30, 30, 30]);
+    }
+
+    #[test]
+    fn split_reward_gives_remainder_to_first_members() {
+        assert_eq!(split_reward(100, 3), vec![34, 33, 33]);
+        assert_eq!(split_reward(2, 4), vec![1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn split_reward_keeps_total() {
+        assert_eq!(split_reward(1001, 4).iter().sum::<i32>(), 1001);
+    }
+
+    #[test]
+    fn split_reward_returns_nothing_for_empty_crew() {
+        assert!(split_reward(100, 0).is_empty());
+    }
+}
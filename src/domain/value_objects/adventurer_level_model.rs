@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::{
+    achievement_model::AchievementModel, quest_rating_model::ReputationModel,
+};
+
+const MAX_ADVENTURER_LEVEL: i32 = 1000;
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdventurerLevelModel {
+    pub level: i32,
+    pub experience: i64,
+    pub current_level_experience: i64,
+    pub next_level_experience: i64,
+    pub progress: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdventurerProfileModel {
+    pub adventurer_id: i32,
+    #[serde(flatten)]
+    pub level: AdventurerLevelModel,
+    pub reputation: ReputationModel,
+    pub achievements: Vec<AchievementModel>,
+}
+
+// XP ที่ต้องใช้จาก level n ไป n + 1 = base_experience * growth_factor^(n - 1)
+pub fn level_progress(
+    experience: i64,
+    base_experience: i64,
+    growth_factor: f64,
+) -> AdventurerLevelModel {
+    let base_experience = base_experience.max(1);
+    let growth_factor = growth_factor.max(1.0);
+
+    let mut level = 1;
+    let mut current_level_experience = 0_i64;
+    let mut required_experience = base_experience;
+
+    // growth_factor ที่ใหญ่มากทำให้ required_experience เป็น i64::MAX จึงหยุดเมื่อบวกต่อไม่ได้
+    while level < MAX_ADVENTURER_LEVEL
+        && let Some(next_level_experience) =
+            current_level_experience.checked_add(required_experience)
+        && experience >= next_level_experience
+    {
+        current_level_experience = next_level_experience;
+        level += 1;
+        required_experience =
+            ((base_experience as f64) * growth_factor.powi(level - 1)).round() as i64;
+    }
+
+    let next_level_experience = current_level_experience.saturating_add(required_experience);
+    let progress = (experience - current_level_experience) as f64 / required_experience as f64;
+
+    AdventurerLevelModel {
+        level,
+        experience,
+        current_level_experience,
+        next_level_experience,
+        progress: progress.clamp(0.0, 1.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_progress_starts_at_level_one() {
+        let result = level_progress(0, 100, 1.5);
+
+        assert_eq!(result.level, 1);
+        assert_eq!(result.current_level_experience, 0);
+        assert_eq!(result.next_level_experience, 100);
+        assert_eq!(result.progress, 0.0);
+    }
+
+    #[test]
+    fn level_progress_grows_required_experience_per_level() {
+        // level 2 ที่ 100 XP, level 3 ที่ 100 + 150 XP
+        let result = level_progress(300, 100, 1.5);
+
+        assert_eq!(result.level, 3);
+        assert_eq!(result.current_level_experience, 250);
+        assert_eq!(result.next_level_experience, 250 + 225);
+    }
+
+    #[test]
+    fn level_progress_clamps_invalid_settings() {
+        let result = level_progress(5, 0, 0.5);
+
+        assert_eq!(result.level, 6);
+        assert_eq!(result.next_level_experience, 6);
+    }
+
+    #[test]
+    fn level_progress_does_not_overflow_with_huge_growth_factor() {
+        let result = level_progress(i64::MAX, 100, f64::INFINITY);
+
+        assert_eq!(result.level, 2);
+        assert_eq!(result.next_level_experience, i64::MAX);
+    }
+
+    #[test]
+    fn level_progress_stops_at_max_level() {
+        let result = level_progress(i64::MAX, 1, 1.0);
+
+        assert_eq!(result.level, MAX_ADVENTURER_LEVEL);
+    }
+}
@@ -1,10 +1,205 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
 use crate::domain::value_objects::quest_statuses::QuestStatuses;
 
+// ts_headline ครอบคำที่ตรงด้วยตัวคั่นเหล่านี้ แล้วค่อยแปลงเป็น <mark> หลัง escape HTML
+pub const SEARCH_HIGHLIGHT_START: char = '\u{2}';
+pub const SEARCH_HIGHLIGHT_STOP: char = '\u{3}';
+
 // Serialize,Deserialize แปลง json
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BoardCheckingFilter {
     pub name: Option<String>,
+    // full-text search ใน name และ description
+    pub q: Option<String>,
     pub status: Option<QuestStatuses>,
+    // comma separated เช่น ?statuses=Open,Failed
+    pub statuses: Option<String>,
+    pub guild_commander_id: Option<i32>,
+    pub created_from: Option<NaiveDateTime>,
+    pub created_to: Option<NaiveDateTime>,
+    pub updated_from: Option<NaiveDateTime>,
+    pub updated_to: Option<NaiveDateTime>,
+    pub has_free_slots: Option<bool>,
+    pub min_crew_count: Option<i64>,
+    pub max_crew_count: Option<i64>,
+    pub exclude_joined: Option<bool>,
+    // comma separated เช่น ?tags=dragon,cave
+    pub tags: Option<String>,
+    pub tag_match: Option<TagMatchModes>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TagMatchModes {
+    #[default]
+    Any,
+    All,
+}
+
+impl BoardCheckingFilter {
+    pub fn search_text(&self) -> Option<String> {
+        self.q
+            .as_deref()
+            .map(|q| q.trim().to_string())
+            .filter(|q| !q.is_empty())
+    }
+
+    pub fn status_names(&self) -> Result<Vec<String>> {
+        let known_statuses = [
+            QuestStatuses::Open,
+            QuestStatuses::InJourney,
+            QuestStatuses::Completed,
+            QuestStatuses::Failed,
+        ]
+        .map(|status| status.to_string());
+
+        let mut status_names = Vec::<String>::new();
+
+        for status in self.statuses.as_deref().unwrap_or_default().split(',') {
+            let status = status.trim();
+            if status.is_empty() {
+                continue;
+            }
+
+            match known_statuses
+                .iter()
+                .find(|known| known.eq_ignore_ascii_case(status))
+            {
+                Some(known) => status_names.push(known.clone()),
+                None => return Err(anyhow::anyhow!("Unknown quest status: {}", status)),
+            }
+        }
+
+        Ok(status_names)
+    }
+
+    pub fn tag_names(&self) -> Vec<String> {
+        let mut tag_names = Vec::<String>::new();
+
+        for tag in self.tags.as_deref().unwrap_or_default().split(',') {
+            let tag = tag.trim().to_lowercase();
+            if !tag.is_empty() && !tag_names.contains(&tag) {
+                tag_names.push(tag);
+            }
+        }
+
+        tag_names
+    }
+}
+
+// name/description มาจาก guild commander จึงต้อง escape ก่อนใส่ <mark>
+pub fn search_snippet_html(headline: &str) -> String {
+    let mut snippet = String::with_capacity(headline.len());
+
+    for c in headline.chars() {
+        match c {
+            SEARCH_HIGHLIGHT_START => snippet.push_str("<mark>"),
+            SEARCH_HIGHLIGHT_STOP => snippet.push_str("</mark>"),
+            '&' => snippet.push_str("&amp;"),
+            '<' => snippet.push_str("&lt;"),
+            '>' => snippet.push_str("&gt;"),
+            '"' => snippet.push_str("&quot;"),
+            '\'' => snippet.push_str("&#39;"),
+            c => snippet.push(c),
+        }
+    }
+
+    snippet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_snippet_html_marks_highlighted_words() {
+        let headline = format!(
+            "Slay the {}dragon{} in the cave",
+            SEARCH_HIGHLIGHT_START, SEARCH_HIGHLIGHT_STOP
+        );
+
+        assert_eq!(
+            search_snippet_html(&headline),
+            "Slay the <mark>dragon</mark> in the cave"
+        );
+    }
+
+    #[test]
+    fn search_snippet_html_escapes_commander_markup() {
+        let headline = format!(
+            "<img src=x onerror=\"alert('{}x{}')\"> & <mark>",
+            SEARCH_HIGHLIGHT_START, SEARCH_HIGHLIGHT_STOP
+        );
+
+        assert_eq!(
+            search_snippet_html(&headline),
+            "&lt;img src=x onerror=&quot;alert(&#39;<mark>x</mark>&#39;)&quot;&gt; &amp; &lt;mark&gt;"
+        );
+    }
+
+    fn filter() -> BoardCheckingFilter {
+        BoardCheckingFilter {
+            name: None,
+            q: None,
+            status: None,
+            statuses: None,
+            guild_commander_id: None,
+            created_from: None,
+            created_to: None,
+            updated_from: None,
+            updated_to: None,
+            has_free_slots: None,
+            min_crew_count: None,
+            max_crew_count: None,
+            exclude_joined: None,
+            tags: None,
+            tag_match: None,
+        }
+    }
+
+    #[test]
+    fn tag_names_normalizes_and_deduplicates() {
+        let filter = BoardCheckingFilter {
+            tags: Some(" Dragon,cave,,DRAGON , ".to_string()),
+            ..filter()
+        };
+
+        assert_eq!(filter.tag_names(), vec!["dragon", "cave"]);
+    }
+
+    #[test]
+    fn tag_names_is_empty_without_tags() {
+        assert!(filter().tag_names().is_empty());
+    }
+
+    #[test]
+    fn status_names_matches_known_statuses_case_insensitively() {
+        let filter = BoardCheckingFilter {
+            statuses: Some("open, INJOURNEY,,failed".to_string()),
+            ..filter()
+        };
+
+        assert_eq!(
+            filter.status_names().unwrap(),
+            vec!["Open", "InJourney", "Failed"]
+        );
+    }
+
+    #[test]
+    fn status_names_rejects_unknown_status() {
+        let filter = BoardCheckingFilter {
+            statuses: Some("Open,Lost".to_string()),
+            ..filter()
+        };
+
+        assert!(filter.status_names().is_err());
+    }
+
+    #[test]
+    fn status_names_is_empty_without_statuses() {
+        assert!(filter().status_names().unwrap().is_empty());
+    }
 }
@@ -0,0 +1,101 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::guilds::AddGuildEntity,
+    value_objects::{guild_roles::GuildRoles, quest_participants::QuestParticipants},
+};
+
+pub const MAX_GUILD_DESCRIPTION_LENGTH: usize = 2000;
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildModel {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub members_count: i64,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddGuildModel {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl AddGuildModel {
+    pub fn to_entity(&self) -> AddGuildEntity {
+        AddGuildEntity {
+            name: self.name.trim().to_string(),
+            description: self.description.clone(),
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMemberModel {
+    pub id: i32,
+    pub guild_id: i32,
+    pub guild_commander_id: Option<i32>,
+    pub adventurer_id: Option<i32>,
+    pub role: GuildRoles,
+    pub joined_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMembershipModel {
+    pub guild: GuildModel,
+    pub member_id: i32,
+    pub role: GuildRoles,
+    pub joined_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddGuildMemberModel {
+    pub guild_commander_id: Option<i32>,
+    pub adventurer_id: Option<i32>,
+    pub role: Option<GuildRoles>,
+}
+
+impl AddGuildMemberModel {
+    pub fn participant(&self) -> Result<QuestParticipants> {
+        match (self.adventurer_id, self.guild_commander_id) {
+            (Some(adventurer_id), None) => Ok(QuestParticipants::Adventurer(adventurer_id)),
+            (None, Some(guild_commander_id)) => {
+                Ok(QuestParticipants::GuildCommander(guild_commander_id))
+            }
+            _ => Err(anyhow::anyhow!(
+                "Exactly one of adventurer_id or guild_commander_id is required"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeGuildRoleModel {
+    pub role: GuildRoles,
+}
+
+pub fn validate_guild(name: &str, description: &Option<String>) -> Result<()> {
+    let name = name.trim();
+
+    if name.is_empty() || name.chars().count() > 255 {
+        return Err(anyhow::anyhow!("Guild name must be 1 to 255 characters"));
+    }
+
+    if let Some(description) = description
+        && description.chars().count() > MAX_GUILD_DESCRIPTION_LENGTH
+    {
+        return Err(anyhow::anyhow!(
+            "Guild description must not exceed {} characters",
+            MAX_GUILD_DESCRIPTION_LENGTH
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+use std::io::Write;
+
+use diesel::{
+    AsExpression, FromSqlRow,
+    deserialize::{self, FromSql},
+    pg::Pg,
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+// Serialize,Deserialize แปลง json
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Varchar)]
+pub enum GuildRoles {
+    Owner,
+    Officer,
+    #[default]
+    Member,
+}
+
+impl fmt::Display for GuildRoles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuildRoles::Owner => write!(f, "Owner"),
+            GuildRoles::Officer => write!(f, "Officer"),
+            GuildRoles::Member => write!(f, "Member"),
+        }
+    }
+}
+
+impl FromSql<Varchar, Pg> for GuildRoles {
+    fn from_sql(
+        bytes: <Pg as diesel::backend::Backend>::RawValue<'_>,
+    ) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        match value.as_str() {
+            "Owner" => Ok(GuildRoles::Owner),
+            "Officer" => Ok(GuildRoles::Officer),
+            "Member" => Ok(GuildRoles::Member),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for GuildRoles {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let value = self.to_string();
+        out.write_all(value.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl GuildRoles {
+    // Owner กับ Officer เพิ่มหรือเอาสมาชิกออกได้ เปลี่ยน role ได้เฉพาะ Owner
+    pub fn can_manage_members(&self) -> bool {
+        matches!(self, GuildRoles::Owner | GuildRoles::Officer)
+    }
+}
@@ -0,0 +1,15 @@
+pub const IDEMPOTENCY_KEY_MAX_LENGTH: usize = 255;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdempotencyOutcomes {
+    Proceed,
+    Replay {
+        status_code: u16,
+        content_type: Option<String>,
+        etag: Option<String>,
+        location: Option<String>,
+        response_body: Vec<u8>,
+    },
+    InProgress,
+    Mismatch,
+}
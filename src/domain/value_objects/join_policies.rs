@@ -0,0 +1,51 @@
+use std::fmt;
+use std::io::Write;
+
+use diesel::{
+    deserialize::{self, FromSql},
+    pg::Pg,
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+    AsExpression, FromSqlRow,
+};
+use serde::{Deserialize, Serialize};
+
+// Serialize,Deserialize แปลง json
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Varchar)]
+pub enum JoinPolicies {
+    #[default]
+    Open,
+    ApprovalRequired,
+    InviteOnly,
+}
+
+impl fmt::Display for JoinPolicies {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinPolicies::Open => write!(f, "Open"),
+            JoinPolicies::ApprovalRequired => write!(f, "ApprovalRequired"),
+            JoinPolicies::InviteOnly => write!(f, "InviteOnly"),
+        }
+    }
+}
+
+impl FromSql<Varchar, Pg> for JoinPolicies {
+    fn from_sql(bytes: <Pg as diesel::backend::Backend>::RawValue<'_>) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        match value.as_str() {
+            "Open" => Ok(JoinPolicies::Open),
+            "ApprovalRequired" => Ok(JoinPolicies::ApprovalRequired),
+            "InviteOnly" => Ok(JoinPolicies::InviteOnly),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for JoinPolicies {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let value = self.to_string();
+        out.write_all(value.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
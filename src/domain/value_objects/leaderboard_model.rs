@@ -0,0 +1,115 @@
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::leaderboards::{
+    AdventurerLeaderboardEntity, GuildCommanderLeaderboardEntity,
+};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardWindows {
+    #[default]
+    AllTime,
+    Week,
+    Month,
+}
+
+impl LeaderboardWindows {
+    // week กับ month เป็นช่วงย้อนหลังแบบ rolling นับรวมวันนี้
+    pub fn since(&self, today: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            LeaderboardWindows::AllTime => None,
+            LeaderboardWindows::Week => today.checked_sub_days(Days::new(6)),
+            LeaderboardWindows::Month => today.checked_sub_days(Days::new(29)),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AdventurerLeaderboardSorts {
+    #[default]
+    Completed,
+    SuccessRate,
+    Rewards,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GuildCommanderLeaderboardSorts {
+    #[default]
+    Completed,
+    Satisfaction,
+}
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdventurerLeaderboardQuery {
+    pub window: Option<LeaderboardWindows>,
+    pub sort: Option<AdventurerLeaderboardSorts>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GuildCommanderLeaderboardQuery {
+    pub window: Option<LeaderboardWindows>,
+    pub sort: Option<GuildCommanderLeaderboardSorts>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdventurerLeaderboardEntryModel {
+    pub rank: i64,
+    pub adventurer_id: i32,
+    pub completed_count: i64,
+    pub failed_count: i64,
+    pub success_rate: Option<f64>,
+    pub gold_earned: i64,
+    pub experience_earned: i64,
+}
+
+impl AdventurerLeaderboardEntryModel {
+    pub fn from_entity(rank: i64, entity: &AdventurerLeaderboardEntity) -> Self {
+        Self {
+            rank,
+            adventurer_id: entity.adventurer_id,
+            completed_count: entity.completed_count,
+            failed_count: entity.failed_count,
+            success_rate: ratio(
+                entity.completed_count,
+                entity.completed_count + entity.failed_count,
+            ),
+            gold_earned: entity.gold_earned,
+            experience_earned: entity.experience_earned,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildCommanderLeaderboardEntryModel {
+    pub rank: i64,
+    pub guild_commander_id: i32,
+    pub completed_count: i64,
+    pub failed_count: i64,
+    pub crew_satisfaction: Option<f64>,
+    pub ratings_count: i64,
+}
+
+impl GuildCommanderLeaderboardEntryModel {
+    pub fn from_entity(rank: i64, entity: &GuildCommanderLeaderboardEntity) -> Self {
+        Self {
+            rank,
+            guild_commander_id: entity.guild_commander_id,
+            completed_count: entity.completed_count,
+            failed_count: entity.failed_count,
+            crew_satisfaction: ratio(entity.rating_score_total, entity.ratings_count),
+            ratings_count: entity.ratings_count,
+        }
+    }
+}
+
+fn ratio(numerator: i64, denominator: i64) -> Option<f64> {
+    if denominator <= 0 {
+        return None;
+    }
+
+    Some((numerator as f64 / denominator as f64 * 100.0).round() / 100.0)
+}
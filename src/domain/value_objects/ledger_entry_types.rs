@@ -0,0 +1,49 @@
+use std::fmt;
+use std::io::Write;
+
+use diesel::{
+    AsExpression, FromSqlRow,
+    deserialize::{self, FromSql},
+    pg::Pg,
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Varchar)]
+pub enum LedgerEntryTypes {
+    QuestReward,
+    QuestFailurePenalty,
+}
+
+impl fmt::Display for LedgerEntryTypes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LedgerEntryTypes::QuestReward => write!(f, "QuestReward"),
+            LedgerEntryTypes::QuestFailurePenalty => write!(f, "QuestFailurePenalty"),
+        }
+    }
+}
+
+impl FromSql<Varchar, Pg> for LedgerEntryTypes {
+    fn from_sql(
+        bytes: <Pg as diesel::backend::Backend>::RawValue<'_>,
+    ) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        match value.as_str() {
+            "QuestReward" => Ok(LedgerEntryTypes::QuestReward),
+            "QuestFailurePenalty" => Ok(LedgerEntryTypes::QuestFailurePenalty),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for LedgerEntryTypes {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let value = self.to_string();
+        out.write_all(value.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
@@ -1,6 +1,33 @@
+pub mod achievement_model;
+pub mod adventurer_ledger_model;
+pub mod adventurer_level_model;
 pub mod adventurer_model;
 pub mod board_checking_filter;
 pub mod guild_commander_model;
+pub mod guild_model;
+pub mod guild_roles;
+pub mod idempotency_model;
+pub mod join_policies;
+pub mod leaderboard_model;
+pub mod ledger_entry_types;
+pub mod pagination;
 pub mod quest_adventurer_juntion;
+pub mod quest_checkpoint_model;
+pub mod quest_comment_model;
+pub mod quest_crew_ban_model;
+pub mod quest_invitation_model;
+pub mod quest_join_application_model;
 pub mod quest_model;
-pub mod quest_statuses;
\ No newline at end of file
+pub mod quest_participants;
+pub mod quest_rating_model;
+pub mod quest_recurrence_model;
+pub mod quest_report_model;
+pub mod quest_revision_model;
+pub mod quest_statuses;
+pub mod quest_tag_model;
+pub mod quest_template_model;
+pub mod quest_version;
+pub mod quest_visibilities;
+pub mod quest_waitlist_model;
+pub mod rating_directions;
+pub mod recurrence_statuses;
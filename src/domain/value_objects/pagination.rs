@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+pub const MAX_PAGE_SIZE: i64 = 100;
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaginationQuery {
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+impl PaginationQuery {
+    pub fn page(&self) -> i64 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    pub fn page_size(&self) -> i64 {
+        self.page_size
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE)
+    }
+
+    pub fn offset(&self) -> i64 {
+        // page ใหญ่เกินไปต้องไม่ทำให้ offset overflow
+        (self.page() - 1).saturating_mul(self.page_size())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginatedModel<T> {
+    pub items: Vec<T>,
+    pub page: i64,
+    pub page_size: i64,
+    pub total: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(page: Option<i64>, page_size: Option<i64>) -> PaginationQuery {
+        PaginationQuery { page, page_size }
+    }
+
+    #[test]
+    fn offset_defaults_to_first_page() {
+        let query = PaginationQuery::default();
+
+        assert_eq!(query.page(), 1);
+        assert_eq!(query.page_size(), DEFAULT_PAGE_SIZE);
+        assert_eq!(query.offset(), 0);
+    }
+
+    #[test]
+    fn offset_skips_previous_pages() {
+        assert_eq!(query(Some(3), Some(10)).offset(), 20);
+    }
+
+    #[test]
+    fn offset_clamps_page_and_page_size() {
+        assert_eq!(query(Some(-5), Some(10)).offset(), 0);
+        assert_eq!(query(Some(2), Some(0)).page_size(), 1);
+        assert_eq!(query(Some(2), Some(1000)).offset(), MAX_PAGE_SIZE);
+    }
+
+    #[test]
+    fn offset_saturates_for_huge_page() {
+        assert_eq!(
+            query(Some(i64::MAX), Some(MAX_PAGE_SIZE)).offset(),
+            i64::MAX
+        );
+    }
+}
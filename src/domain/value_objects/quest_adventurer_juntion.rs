@@ -1,3 +1,4 @@
+use anyhow::Result;
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -17,3 +18,88 @@ pub struct QuestAdventurerJunction {
     pub adventurer_id: i32,
     pub quest_id: i32,
 }
+
+// ceiling ที่ตั้งไว้ต่ำกว่าค่า default ต้องไม่ทำให้ quest ที่ไม่ได้ระบุ max_crew สร้างไม่ได้
+pub fn default_max_crew(max_crew_ceiling: i32) -> i32 {
+    (MAX_ADVENTURERS_PER_QUEST as i32).min(max_crew_ceiling)
+}
+
+pub fn validate_crew_limits(min_crew: i32, max_crew: i32, max_crew_ceiling: i32) -> Result<()> {
+    if min_crew < 1 {
+        return Err(anyhow::anyhow!("min_crew must be at least 1"));
+    }
+
+    if max_crew < min_crew {
+        return Err(anyhow::anyhow!("max_crew must not be less than min_crew"));
+    }
+
+    if max_crew > max_crew_ceiling {
+        return Err(anyhow::anyhow!(
+            "max_crew must not exceed {}",
+            max_crew_ceiling
+        ));
+    }
+
+    Ok(())
+}
+
+// crew ที่อยู่แล้วต้องไม่เกิน max_crew และถ้าปิดรับคนแล้ว min_crew ต้องไม่เกินจำนวน crew ปัจจุบัน
+pub fn validate_crew_limits_for_crew(
+    min_crew: i32,
+    max_crew: i32,
+    adventurers_count: i64,
+    is_joining_closed: bool,
+) -> Result<()> {
+    if (max_crew as i64) < adventurers_count {
+        return Err(anyhow::anyhow!(
+            "max_crew must not be less than the current crew size of {}",
+            adventurers_count
+        ));
+    }
+
+    if is_joining_closed && (min_crew as i64) > adventurers_count {
+        return Err(anyhow::anyhow!(
+            "min_crew must not exceed the current crew size of {} after the join deadline",
+            adventurers_count
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_max_crew_respects_lower_ceiling() {
+        assert_eq!(default_max_crew(10), MAX_ADVENTURERS_PER_QUEST as i32);
+        assert_eq!(default_max_crew(2), 2);
+    }
+
+    #[test]
+    fn validate_crew_limits_accepts_limits_within_ceiling() {
+        assert!(validate_crew_limits(1, 4, 4).is_ok());
+        assert!(validate_crew_limits(3, 3, 4).is_ok());
+    }
+
+    #[test]
+    fn validate_crew_limits_rejects_invalid_limits() {
+        assert!(validate_crew_limits(0, 4, 4).is_err());
+        assert!(validate_crew_limits(3, 2, 4).is_err());
+        assert!(validate_crew_limits(1, 5, 4).is_err());
+    }
+
+    #[test]
+    fn validate_crew_limits_for_crew_rejects_max_below_current_crew() {
+        assert!(validate_crew_limits_for_crew(1, 2, 3, false).is_err());
+        assert!(validate_crew_limits_for_crew(1, 3, 3, false).is_ok());
+    }
+
+    #[test]
+    fn validate_crew_limits_for_crew_checks_min_only_after_join_deadline() {
+        assert!(validate_crew_limits_for_crew(3, 4, 2, false).is_ok());
+        assert!(validate_crew_limits_for_crew(3, 4, 2, true).is_err());
+        assert!(validate_crew_limits_for_crew(2, 4, 2, true).is_ok());
+    }
+}
@@ -0,0 +1,106 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::quest_checkpoints::{
+    AddQuestCheckpointEntity, EditQuestCheckpointEntity,
+};
+
+pub const MAX_CHECKPOINT_NOTE_LENGTH: usize = 1000;
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestCheckpointModel {
+    pub id: i32,
+    pub quest_id: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub mandatory: bool,
+    pub position: i32,
+    pub done: bool,
+    pub completed_by_adventurer_id: Option<i32>,
+    pub completion_note: Option<String>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddQuestCheckpointModel {
+    pub title: String,
+    pub description: Option<String>,
+    pub mandatory: Option<bool>,
+    pub position: Option<i32>,
+}
+
+impl AddQuestCheckpointModel {
+    pub fn to_entity(&self, quest_id: i32) -> AddQuestCheckpointEntity {
+        AddQuestCheckpointEntity {
+            quest_id,
+            title: self.title.trim().to_string(),
+            description: self.description.clone(),
+            mandatory: self.mandatory.unwrap_or(true),
+            position: self.position.unwrap_or(0),
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditQuestCheckpointModel {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub mandatory: Option<bool>,
+    pub position: Option<i32>,
+}
+
+impl EditQuestCheckpointModel {
+    pub fn to_entity(&self) -> EditQuestCheckpointEntity {
+        EditQuestCheckpointEntity {
+            title: self.title.as_ref().map(|title| title.trim().to_string()),
+            description: self.description.clone(),
+            mandatory: self.mandatory,
+            position: self.position,
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CompleteQuestCheckpointModel {
+    pub note: Option<String>,
+}
+
+pub fn validate_checkpoint_title(title: &str) -> Result<()> {
+    let title = title.trim();
+
+    if title.is_empty() || title.chars().count() > 255 {
+        return Err(anyhow::anyhow!(
+            "Checkpoint title must be 1 to 255 characters"
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_checkpoint_note(note: &Option<String>) -> Result<()> {
+    if let Some(note) = note
+        && note.chars().count() > MAX_CHECKPOINT_NOTE_LENGTH
+    {
+        return Err(anyhow::anyhow!(
+            "Checkpoint note must not exceed {} characters",
+            MAX_CHECKPOINT_NOTE_LENGTH
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn progress_percent(done_count: i64, total_count: i64) -> Option<i32> {
+    if total_count <= 0 {
+        return None;
+    }
+
+    Some((done_count * 100 / total_count) as i32)
+}
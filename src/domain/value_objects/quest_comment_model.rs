@@ -0,0 +1,75 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::quest_comments::AddQuestCommentEntity,
+    value_objects::quest_participants::QuestParticipants,
+};
+
+pub const MAX_COMMENT_LENGTH: usize = 2000;
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestCommentModel {
+    pub id: i32,
+    pub quest_id: i32,
+    pub parent_comment_id: Option<i32>,
+    pub adventurer_id: Option<i32>,
+    pub guild_commander_id: Option<i32>,
+    // comment ที่ถูกลบแล้วจะไม่แสดงข้อความ แต่ยังคงอยู่ใน thread
+    pub body: Option<String>,
+    pub deleted: bool,
+    pub moderated: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub replies: Vec<QuestCommentModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddQuestCommentModel {
+    pub body: String,
+    pub parent_comment_id: Option<i32>,
+}
+
+impl AddQuestCommentModel {
+    pub fn to_entity(
+        &self,
+        quest_id: i32,
+        parent_comment_id: Option<i32>,
+        author: QuestParticipants,
+    ) -> AddQuestCommentEntity {
+        AddQuestCommentEntity {
+            quest_id,
+            parent_comment_id,
+            adventurer_id: author.adventurer_id(),
+            guild_commander_id: author.guild_commander_id(),
+            body: self.body.trim().to_string(),
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditQuestCommentModel {
+    pub body: String,
+}
+
+pub fn validate_comment_body(body: &str) -> Result<()> {
+    let body = body.trim();
+
+    if body.is_empty() {
+        return Err(anyhow::anyhow!("Comment must not be empty"));
+    }
+
+    if body.chars().count() > MAX_COMMENT_LENGTH {
+        return Err(anyhow::anyhow!(
+            "Comment must not exceed {} characters",
+            MAX_COMMENT_LENGTH
+        ));
+    }
+
+    Ok(())
+}
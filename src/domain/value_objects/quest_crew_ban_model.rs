@@ -0,0 +1,16 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestCrewBanModel {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub guild_commander_id: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KickAdventurerModel {
+    pub ban: Option<bool>,
+}
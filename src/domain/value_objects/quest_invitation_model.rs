@@ -0,0 +1,18 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestInvitationModel {
+    pub id: i32,
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub guild_commander_id: i32,
+    pub expires_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InviteAdventurersModel {
+    pub adventurer_ids: Vec<i32>,
+}
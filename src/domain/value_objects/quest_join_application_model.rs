@@ -0,0 +1,10 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestJoinApplicationModel {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub created_at: NaiveDateTime,
+}
@@ -1,9 +1,13 @@
+use anyhow::Result;
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
 use crate::domain::{
     entities::quests::{AddQuestEntity, EditQuestEntity},
-    value_objects::quest_statuses::QuestStatuses,
+    value_objects::{
+        join_policies::JoinPolicies, quest_adventurer_juntion::default_max_crew,
+        quest_statuses::QuestStatuses, quest_visibilities::QuestVisibilities,
+    },
 };
 
 // Serialize,Deserialize แปลง json
@@ -15,6 +19,26 @@ pub struct QuestModel {
     pub status: QuestStatuses,
     pub guild_commander_id: i32,
     pub adventurers_count: i64,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: JoinPolicies,
+    pub starts_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub reward_gold: i32,
+    pub reward_experience: i32,
+    pub min_level: i32,
+    pub version: i32,
+    pub require_checkpoints: bool,
+    pub guild_id: Option<i32>,
+    pub visibility: QuestVisibilities,
+    // เปอร์เซ็นต์ checkpoint ที่ทำเสร็จ ไม่มี checkpoint เป็น null
+    pub progress_percent: Option<i32>,
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_rank: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_snippet: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
@@ -23,17 +47,42 @@ pub struct QuestModel {
 pub struct AddQuestModel {
     pub name: String,
     pub description: Option<String>,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    pub join_policy: Option<JoinPolicies>,
+    pub starts_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub reward_gold: Option<i32>,
+    pub reward_experience: Option<i32>,
+    pub min_level: Option<i32>,
+    pub require_checkpoints: Option<bool>,
+    pub tags: Option<Vec<String>>,
+    pub guild_id: Option<i32>,
+    pub visibility: Option<QuestVisibilities>,
 }
 
 impl AddQuestModel {
-    pub fn to_entity(&self, guild_commander_id: i32) -> AddQuestEntity {
+    pub fn to_entity(&self, guild_commander_id: i32, max_crew_ceiling: i32) -> AddQuestEntity {
         AddQuestEntity {
             name: self.name.clone(),
             description: self.description.clone(),
-            guild_commander_id: guild_commander_id,
+            guild_commander_id,
             status: QuestStatuses::Open.to_string(),
             created_at: chrono::Utc::now().naive_utc(),
             updated_at: chrono::Utc::now().naive_utc(),
+            min_crew: self.min_crew.unwrap_or(1),
+            max_crew: self.max_crew.unwrap_or(default_max_crew(max_crew_ceiling)),
+            join_policy: self.join_policy.clone().unwrap_or_default().to_string(),
+            starts_at: self.starts_at,
+            join_deadline: self.join_deadline,
+            due_at: self.due_at,
+            reward_gold: self.reward_gold.unwrap_or(0),
+            reward_experience: self.reward_experience.unwrap_or(0),
+            min_level: self.min_level.unwrap_or(1),
+            require_checkpoints: self.require_checkpoints.unwrap_or(false),
+            guild_id: self.guild_id,
+            visibility: self.visibility.clone().unwrap_or_default().to_string(),
         }
     }
 }
@@ -42,15 +91,148 @@ impl AddQuestModel {
 pub struct EditQuestModel {
     pub name: Option<String>,
     pub description: Option<String>,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    pub join_policy: Option<JoinPolicies>,
+    pub starts_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub reward_gold: Option<i32>,
+    pub reward_experience: Option<i32>,
+    pub min_level: Option<i32>,
+    pub require_checkpoints: Option<bool>,
+    pub guild_id: Option<i32>,
+    pub visibility: Option<QuestVisibilities>,
 }
 
 impl EditQuestModel {
     pub fn to_entity(&self, guild_commander_id: i32) -> EditQuestEntity {
         EditQuestEntity {
             name: self.name.clone(),
-            description: self.description.clone(),
-            guild_commander_id: guild_commander_id,
+            description: self.description.clone().map(Some),
+            guild_commander_id,
             updated_at: chrono::Utc::now().naive_utc(),
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+            join_policy: self
+                .join_policy
+                .as_ref()
+                .map(|join_policy| join_policy.to_string()),
+            starts_at: self.starts_at.map(Some),
+            join_deadline: self.join_deadline.map(Some),
+            due_at: self.due_at.map(Some),
+            reward_gold: self.reward_gold,
+            reward_experience: self.reward_experience,
+            min_level: self.min_level,
+            require_checkpoints: self.require_checkpoints,
+            guild_id: self.guild_id.map(Some),
+            visibility: self
+                .visibility
+                .as_ref()
+                .map(|visibility| visibility.to_string()),
         }
     }
 }
+
+pub fn validate_quest_schedule(
+    starts_at: Option<NaiveDateTime>,
+    join_deadline: Option<NaiveDateTime>,
+    due_at: Option<NaiveDateTime>,
+) -> Result<()> {
+    if let (Some(starts_at), Some(due_at)) = (starts_at, due_at)
+        && starts_at >= due_at
+    {
+        return Err(anyhow::anyhow!("starts_at must be before due_at"));
+    }
+
+    if let (Some(join_deadline), Some(due_at)) = (join_deadline, due_at)
+        && join_deadline > due_at
+    {
+        return Err(anyhow::anyhow!("join_deadline must not be after due_at"));
+    }
+
+    if let (Some(starts_at), Some(join_deadline)) = (starts_at, join_deadline)
+        && join_deadline > starts_at
+    {
+        return Err(anyhow::anyhow!("join_deadline must not be after starts_at"));
+    }
+
+    Ok(())
+}
+
+// ตรวจเฉพาะตอนตั้ง due_at ใหม่ quest เดิมที่เลย due_at แล้วยังแก้ field อื่นได้
+pub fn validate_quest_due_at(due_at: Option<NaiveDateTime>, now: NaiveDateTime) -> Result<()> {
+    if let Some(due_at) = due_at
+        && due_at <= now
+    {
+        return Err(anyhow::anyhow!("due_at must be in the future"));
+    }
+
+    Ok(())
+}
+
+pub fn validate_quest_reward(reward_gold: i32, reward_experience: i32) -> Result<()> {
+    if reward_gold < 0 || reward_experience < 0 {
+        return Err(anyhow::anyhow!("Quest reward must not be negative"));
+    }
+
+    Ok(())
+}
+
+pub fn validate_quest_min_level(min_level: i32) -> Result<()> {
+    if min_level < 1 {
+        return Err(anyhow::anyhow!("min_level must be at least 1"));
+    }
+
+    Ok(())
+}
+
+pub fn validate_quest_visibility(guild_id: Option<i32>, visibility: &str) -> Result<()> {
+    if visibility == QuestVisibilities::GuildOnly.to_string() && guild_id.is_none() {
+        return Err(anyhow::anyhow!("GuildOnly quests must belong to a guild"));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(hour: u32) -> NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(2026, 1, 1)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn validate_quest_schedule_accepts_ordered_schedule() {
+        assert!(validate_quest_schedule(Some(at(2)), Some(at(1)), Some(at(3))).is_ok());
+        assert!(validate_quest_schedule(Some(at(2)), Some(at(2)), Some(at(3))).is_ok());
+        assert!(validate_quest_schedule(None, None, None).is_ok());
+    }
+
+    #[test]
+    fn validate_quest_schedule_rejects_start_after_due() {
+        assert!(validate_quest_schedule(Some(at(3)), None, Some(at(3))).is_err());
+    }
+
+    #[test]
+    fn validate_quest_schedule_rejects_join_deadline_after_due() {
+        assert!(validate_quest_schedule(None, Some(at(4)), Some(at(3))).is_err());
+    }
+
+    #[test]
+    fn validate_quest_schedule_rejects_join_deadline_after_start() {
+        assert!(validate_quest_schedule(Some(at(1)), Some(at(2)), Some(at(3))).is_err());
+    }
+
+    #[test]
+    fn validate_quest_due_at_rejects_past_due_at() {
+        assert!(validate_quest_due_at(Some(at(1)), at(2)).is_err());
+        assert!(validate_quest_due_at(Some(at(2)), at(2)).is_err());
+        assert!(validate_quest_due_at(Some(at(3)), at(2)).is_ok());
+        assert!(validate_quest_due_at(None, at(2)).is_ok());
+    }
+}
@@ -0,0 +1,22 @@
+// ผู้เรียก endpoint ที่ใช้ร่วมกันระหว่าง adventurer กับ guild commander
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuestParticipants {
+    Adventurer(i32),
+    GuildCommander(i32),
+}
+
+impl QuestParticipants {
+    pub fn adventurer_id(&self) -> Option<i32> {
+        match self {
+            QuestParticipants::Adventurer(adventurer_id) => Some(*adventurer_id),
+            QuestParticipants::GuildCommander(_) => None,
+        }
+    }
+
+    pub fn guild_commander_id(&self) -> Option<i32> {
+        match self {
+            QuestParticipants::Adventurer(_) => None,
+            QuestParticipants::GuildCommander(guild_commander_id) => Some(*guild_commander_id),
+        }
+    }
+}
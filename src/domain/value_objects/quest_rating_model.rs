@@ -0,0 +1,85 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+pub const MIN_RATING_SCORE: i32 = 1;
+pub const MAX_RATING_SCORE: i32 = 5;
+pub const MAX_RATING_COMMENT_LENGTH: usize = 1000;
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestRatingModel {
+    pub id: i32,
+    pub quest_id: i32,
+    pub guild_commander_id: i32,
+    pub adventurer_id: i32,
+    pub direction: String,
+    pub score: i32,
+    pub comment: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+// commander ต้องระบุ adventurer_id ที่จะให้คะแนน ส่วน adventurer ให้คะแนน commander ของ quest เสมอ
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddQuestRatingModel {
+    pub adventurer_id: Option<i32>,
+    pub score: i32,
+    pub comment: Option<String>,
+}
+
+impl AddQuestRatingModel {
+    pub fn comment(&self) -> Option<String> {
+        self.comment
+            .as_ref()
+            .map(|comment| comment.trim().to_string())
+            .filter(|comment| !comment.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReputationModel {
+    pub average_score: Option<f64>,
+    pub ratings_count: i64,
+}
+
+impl ReputationModel {
+    pub fn from_totals(score_total: i64, ratings_count: i64) -> Self {
+        let average_score = if ratings_count > 0 {
+            Some((score_total as f64 / ratings_count as f64 * 100.0).round() / 100.0)
+        } else {
+            None
+        };
+
+        Self {
+            average_score,
+            ratings_count,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildCommanderProfileModel {
+    pub guild_commander_id: i32,
+    pub reputation: ReputationModel,
+}
+
+pub fn validate_rating(score: i32, comment: &Option<String>) -> Result<()> {
+    if !(MIN_RATING_SCORE..=MAX_RATING_SCORE).contains(&score) {
+        return Err(anyhow::anyhow!(
+            "Score must be between {} and {}",
+            MIN_RATING_SCORE,
+            MAX_RATING_SCORE
+        ));
+    }
+
+    if let Some(comment) = comment
+        && comment.chars().count() > MAX_RATING_COMMENT_LENGTH
+    {
+        return Err(anyhow::anyhow!(
+            "Rating comment must not exceed {} characters",
+            MAX_RATING_COMMENT_LENGTH
+        ));
+    }
+
+    Ok(())
+}
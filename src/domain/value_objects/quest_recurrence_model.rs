@@ -0,0 +1,192 @@
+use anyhow::Result;
+use chrono::{Datelike, Duration, NaiveDateTime, NaiveTime, Timelike, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::recurrence_statuses::RecurrenceStatuses;
+
+// หาเวลาถัดไปได้ไม่เกิน 5 ปี
+const MAX_LOOKAHEAD_DAYS: i64 = 366 * 5;
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestRecurrenceModel {
+    pub id: i32,
+    pub guild_commander_id: i32,
+    pub quest_template_id: i32,
+    pub schedule: String,
+    pub max_open_instances: i32,
+    pub status: RecurrenceStatuses,
+    pub next_run_at: NaiveDateTime,
+    pub last_run_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddQuestRecurrenceModel {
+    pub quest_template_id: i32,
+    pub rule: RecurrenceRuleModel,
+    pub max_open_instances: Option<i32>,
+}
+
+// เวลาทั้งหมดเป็น UTC
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum RecurrenceRuleModel {
+    Daily {
+        at: NaiveTime,
+    },
+    Weekly {
+        weekdays: Vec<Weekday>,
+        at: NaiveTime,
+    },
+    Cron {
+        expression: String,
+    },
+}
+
+impl RecurrenceRuleModel {
+    pub fn to_cron_expression(&self) -> Result<String> {
+        match self {
+            RecurrenceRuleModel::Daily { at } => Ok(format!("{} {} * * *", at.minute(), at.hour())),
+            RecurrenceRuleModel::Weekly { weekdays, at } => {
+                if weekdays.is_empty() {
+                    return Err(anyhow::anyhow!(
+                        "Weekly recurrence needs at least one weekday"
+                    ));
+                }
+
+                let weekdays = weekdays
+                    .iter()
+                    .map(|weekday| weekday.num_days_from_sunday().to_string())
+                    .collect::<Vec<String>>()
+                    .join(",");
+
+                Ok(format!("{} {} * * {}", at.minute(), at.hour(), weekdays))
+            }
+            RecurrenceRuleModel::Cron { expression } => {
+                CronSchedule::parse(expression)?;
+                Ok(expression.trim().to_string())
+            }
+        }
+    }
+}
+
+// cron 5 ช่อง: minute hour day-of-month month day-of-week
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronSchedule {
+    minutes: Vec<u32>,
+    hours: Vec<u32>,
+    days_of_month: Vec<u32>,
+    months: Vec<u32>,
+    days_of_week: Vec<u32>,
+    days_of_month_restricted: bool,
+    days_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> Result<Self> {
+        let fields = expression.split_whitespace().collect::<Vec<&str>>();
+
+        if fields.len() != 5 {
+            return Err(anyhow::anyhow!(
+                "Cron expression must have 5 fields: minute hour day-of-month month day-of-week"
+            ));
+        }
+
+        // 7 คือวันอาทิตย์เหมือน 0
+        let mut days_of_week = parse_cron_field(fields[4], 0, 7)?
+            .into_iter()
+            .map(|day| day % 7)
+            .collect::<Vec<u32>>();
+        days_of_week.sort();
+        days_of_week.dedup();
+
+        Ok(Self {
+            minutes: parse_cron_field(fields[0], 0, 59)?,
+            hours: parse_cron_field(fields[1], 0, 23)?,
+            days_of_month: parse_cron_field(fields[2], 1, 31)?,
+            months: parse_cron_field(fields[3], 1, 12)?,
+            days_of_week,
+            days_of_month_restricted: fields[2] != "*",
+            days_of_week_restricted: fields[4] != "*",
+        })
+    }
+
+    pub fn next_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let start = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+
+        for day_offset in 0..MAX_LOOKAHEAD_DAYS {
+            let date = start.date() + Duration::days(day_offset);
+
+            if !self.months.contains(&date.month()) || !self.matches_day(date.day(), date.weekday())
+            {
+                continue;
+            }
+
+            for hour in &self.hours {
+                for minute in &self.minutes {
+                    let candidate = date.and_hms_opt(*hour, *minute, 0)?;
+                    if candidate >= start {
+                        return Some(candidate);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn matches_day(&self, day_of_month: u32, weekday: Weekday) -> bool {
+        let day_of_month_matches = self.days_of_month.contains(&day_of_month);
+        let day_of_week_matches = self.days_of_week.contains(&weekday.num_days_from_sunday());
+
+        // เหมือน cron ปกติ ถ้ากำหนดทั้งสองช่อง ตรงอย่างใดอย่างหนึ่งก็พอ
+        match (self.days_of_month_restricted, self.days_of_week_restricted) {
+            (true, true) => day_of_month_matches || day_of_week_matches,
+            (true, false) => day_of_month_matches,
+            (false, true) => day_of_week_matches,
+            (false, false) => true,
+        }
+    }
+}
+
+fn parse_cron_field(field: &str, min: u32, max: u32) -> Result<Vec<u32>> {
+    let mut values = Vec::<u32>::new();
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>()?),
+            None => (part, 1),
+        };
+
+        if step == 0 {
+            return Err(anyhow::anyhow!("Cron step must be greater than 0"));
+        }
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (start.parse::<u32>()?, end.parse::<u32>()?)
+        } else {
+            let value = range.parse::<u32>()?;
+            (value, if part.contains('/') { max } else { value })
+        };
+
+        if start < min || end > max || start > end {
+            return Err(anyhow::anyhow!(
+                "Cron field `{}` must be within {}-{}",
+                field,
+                min,
+                max
+            ));
+        }
+
+        values.extend((start..=end).step_by(step as usize));
+    }
+
+    values.sort();
+    values.dedup();
+
+    Ok(values)
+}
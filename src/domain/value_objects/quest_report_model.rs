@@ -0,0 +1,112 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_REPORT_BODY_LENGTH: usize = 10000;
+pub const MAX_ATTACHMENT_FILE_NAME_LENGTH: usize = 255;
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestReportModel {
+    pub id: i32,
+    pub quest_id: i32,
+    pub guild_commander_id: i32,
+    pub outcome: String,
+    pub body: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AddQuestReportModel {
+    pub body: Option<String>,
+}
+
+impl AddQuestReportModel {
+    pub fn body(&self) -> Option<String> {
+        self.body
+            .as_ref()
+            .map(|body| body.trim().to_string())
+            .filter(|body| !body.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestAttachmentModel {
+    pub id: i32,
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestEvidenceModel {
+    pub reports: Vec<QuestReportModel>,
+    pub attachments: Vec<QuestAttachmentModel>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadQuestAttachmentModel {
+    pub file_name: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct QuestAttachmentContentModel {
+    pub file_name: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
+
+pub fn validate_report_body(body: &Option<String>) -> Result<()> {
+    if let Some(body) = body
+        && body.chars().count() > MAX_REPORT_BODY_LENGTH
+    {
+        return Err(anyhow::anyhow!(
+            "Report body must not exceed {} characters",
+            MAX_REPORT_BODY_LENGTH
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_attachment(
+    upload_quest_attachment_model: &UploadQuestAttachmentModel,
+    max_file_size_bytes: usize,
+    allowed_content_types: &[String],
+) -> Result<()> {
+    let file_name = upload_quest_attachment_model.file_name.trim();
+
+    if file_name.is_empty() || file_name.chars().count() > MAX_ATTACHMENT_FILE_NAME_LENGTH {
+        return Err(anyhow::anyhow!(
+            "File name must be 1 to {} characters",
+            MAX_ATTACHMENT_FILE_NAME_LENGTH
+        ));
+    }
+
+    if upload_quest_attachment_model.content.is_empty() {
+        return Err(anyhow::anyhow!("File is empty"));
+    }
+
+    if upload_quest_attachment_model.content.len() > max_file_size_bytes {
+        return Err(anyhow::anyhow!(
+            "File must not exceed {} bytes",
+            max_file_size_bytes
+        ));
+    }
+
+    let content_type = upload_quest_attachment_model.content_type.to_lowercase();
+
+    if !allowed_content_types.contains(&content_type) {
+        return Err(anyhow::anyhow!(
+            "Content type {} is not allowed",
+            upload_quest_attachment_model.content_type
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,403 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::quests::{EditQuestEntity, QuestEntity},
+    value_objects::{
+        join_policies::JoinPolicies, quest_model::EditQuestModel,
+        quest_visibilities::QuestVisibilities,
+    },
+};
+
+// field ที่กระทบ crew ต้องให้ crew ยืนยันใหม่
+pub const MATERIAL_QUEST_FIELDS: [&str; 8] = [
+    "min_crew",
+    "max_crew",
+    "starts_at",
+    "join_deadline",
+    "due_at",
+    "reward_gold",
+    "reward_experience",
+    "min_level",
+];
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QuestFieldChangeModel {
+    pub field: String,
+    pub from: serde_json::Value,
+    pub to: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestRevisionModel {
+    pub id: i32,
+    pub quest_id: i32,
+    pub revision_number: i32,
+    pub guild_commander_id: i32,
+    pub changes: Vec<QuestFieldChangeModel>,
+    pub requires_reconfirmation: bool,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrewQuestRevisionsModel {
+    pub quest_id: i32,
+    pub joined_at: NaiveDateTime,
+    pub reconfirmation_required: bool,
+    pub revisions: Vec<QuestRevisionModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestRevisionDiffQuery {
+    pub from: i32,
+    pub to: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestRevisionDiffModel {
+    pub quest_id: i32,
+    pub from_revision: i32,
+    pub to_revision: i32,
+    pub changes: Vec<QuestFieldChangeModel>,
+}
+
+// ค่าของ field ที่ revision เก็บไว้ ณ revision หนึ่ง (revision 0 คือค่าตอนสร้าง quest)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QuestFieldsModel {
+    pub name: String,
+    pub description: Option<String>,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: JoinPolicies,
+    pub starts_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+    pub reward_gold: i32,
+    pub reward_experience: i32,
+    pub min_level: i32,
+    pub require_checkpoints: bool,
+    pub guild_id: Option<i32>,
+    pub visibility: QuestVisibilities,
+}
+
+impl QuestFieldsModel {
+    pub fn from_entity(quest: &QuestEntity) -> Self {
+        Self {
+            name: quest.name.clone(),
+            description: quest.description.clone(),
+            min_crew: quest.min_crew,
+            max_crew: quest.max_crew,
+            join_policy: quest.join_policy.clone(),
+            starts_at: quest.starts_at,
+            join_deadline: quest.join_deadline,
+            due_at: quest.due_at,
+            reward_gold: quest.reward_gold,
+            reward_experience: quest.reward_experience,
+            min_level: quest.min_level,
+            require_checkpoints: quest.require_checkpoints,
+            guild_id: quest.guild_id,
+            visibility: quest.visibility.clone(),
+        }
+    }
+
+    pub fn at_revision(
+        &self,
+        revisions: &[QuestRevisionModel],
+        revision_number: i32,
+    ) -> Result<Self> {
+        let latest_revision_number = revisions
+            .iter()
+            .map(|revision| revision.revision_number)
+            .max()
+            .unwrap_or(0);
+
+        if revision_number < 0 || revision_number > latest_revision_number {
+            return Err(anyhow::anyhow!(
+                "Revision {} does not exist",
+                revision_number
+            ));
+        }
+
+        let mut later_revisions = revisions
+            .iter()
+            .filter(|revision| revision.revision_number > revision_number)
+            .collect::<Vec<&QuestRevisionModel>>();
+        later_revisions.sort_by_key(|revision| std::cmp::Reverse(revision.revision_number));
+
+        // ย้อนค่าปัจจุบันกลับทีละ revision จากล่าสุด
+        let mut fields = serde_json::to_value(self)?;
+        for revision in later_revisions {
+            for change in &revision.changes {
+                fields[change.field.as_str()] = change.from.clone();
+            }
+        }
+
+        Ok(serde_json::from_value::<Self>(fields)?)
+    }
+
+    pub fn changes_to(&self, other: &Self) -> Result<Vec<QuestFieldChangeModel>> {
+        let from_fields = serde_json::to_value(self)?;
+        let to_fields = serde_json::to_value(other)?;
+
+        let mut changes = Vec::<QuestFieldChangeModel>::new();
+
+        if let (Some(from_fields), Some(to_fields)) =
+            (from_fields.as_object(), to_fields.as_object())
+        {
+            for (field, from) in from_fields {
+                let to = to_fields.get(field).cloned().unwrap_or_default();
+                if *from != to {
+                    changes.push(QuestFieldChangeModel {
+                        field: field.clone(),
+                        from: from.clone(),
+                        to,
+                    });
+                }
+            }
+        }
+
+        Ok(changes)
+    }
+
+    pub fn to_edit_entity(&self, guild_commander_id: i32) -> EditQuestEntity {
+        EditQuestEntity {
+            name: Some(self.name.clone()),
+            description: Some(self.description.clone()),
+            guild_commander_id,
+            updated_at: chrono::Utc::now().naive_utc(),
+            min_crew: Some(self.min_crew),
+            max_crew: Some(self.max_crew),
+            join_policy: Some(self.join_policy.to_string()),
+            starts_at: Some(self.starts_at),
+            join_deadline: Some(self.join_deadline),
+            due_at: Some(self.due_at),
+            reward_gold: Some(self.reward_gold),
+            reward_experience: Some(self.reward_experience),
+            min_level: Some(self.min_level),
+            require_checkpoints: Some(self.require_checkpoints),
+            guild_id: Some(self.guild_id),
+            visibility: Some(self.visibility.to_string()),
+        }
+    }
+}
+
+pub fn quest_edit_changes(
+    quest: &QuestEntity,
+    edit_quest_model: &EditQuestModel,
+) -> Vec<QuestFieldChangeModel> {
+    let mut changes = Vec::<QuestFieldChangeModel>::new();
+
+    push_change(&mut changes, "name", &quest.name, &edit_quest_model.name);
+    push_change(
+        &mut changes,
+        "description",
+        &quest.description,
+        &edit_quest_model.description.clone().map(Some),
+    );
+    push_change(
+        &mut changes,
+        "min_crew",
+        &quest.min_crew,
+        &edit_quest_model.min_crew,
+    );
+    push_change(
+        &mut changes,
+        "max_crew",
+        &quest.max_crew,
+        &edit_quest_model.max_crew,
+    );
+    push_change(
+        &mut changes,
+        "join_policy",
+        &quest.join_policy,
+        &edit_quest_model.join_policy,
+    );
+    push_change(
+        &mut changes,
+        "starts_at",
+        &quest.starts_at,
+        &edit_quest_model.starts_at.map(Some),
+    );
+    push_change(
+        &mut changes,
+        "join_deadline",
+        &quest.join_deadline,
+        &edit_quest_model.join_deadline.map(Some),
+    );
+    push_change(
+        &mut changes,
+        "due_at",
+        &quest.due_at,
+        &edit_quest_model.due_at.map(Some),
+    );
+    push_change(
+        &mut changes,
+        "reward_gold",
+        &quest.reward_gold,
+        &edit_quest_model.reward_gold,
+    );
+    push_change(
+        &mut changes,
+        "reward_experience",
+        &quest.reward_experience,
+        &edit_quest_model.reward_experience,
+    );
+    push_change(
+        &mut changes,
+        "min_level",
+        &quest.min_level,
+        &edit_quest_model.min_level,
+    );
+    push_change(
+        &mut changes,
+        "require_checkpoints",
+        &quest.require_checkpoints,
+        &edit_quest_model.require_checkpoints,
+    );
+    push_change(
+        &mut changes,
+        "guild_id",
+        &quest.guild_id,
+        &edit_quest_model.guild_id.map(Some),
+    );
+    push_change(
+        &mut changes,
+        "visibility",
+        &quest.visibility,
+        &edit_quest_model.visibility,
+    );
+
+    changes
+}
+
+pub fn has_material_changes(changes: &[QuestFieldChangeModel]) -> bool {
+    changes
+        .iter()
+        .any(|change| MATERIAL_QUEST_FIELDS.contains(&change.field.as_str()))
+}
+
+fn push_change<T>(changes: &mut Vec<QuestFieldChangeModel>, field: &str, from: &T, to: &Option<T>)
+where
+    T: Serialize + PartialEq,
+{
+    if let Some(to) = to
+        && to != from
+    {
+        changes.push(QuestFieldChangeModel {
+            field: field.to_string(),
+            from: serde_json::to_value(from).unwrap_or_default(),
+            to: serde_json::to_value(to).unwrap_or_default(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields() -> QuestFieldsModel {
+        QuestFieldsModel {
+            name: "Slay the dragon".to_string(),
+            description: None,
+            min_crew: 1,
+            max_crew: 4,
+            join_policy: JoinPolicies::default(),
+            starts_at: None,
+            join_deadline: None,
+            due_at: None,
+            reward_gold: 300,
+            reward_experience: 100,
+            min_level: 1,
+            require_checkpoints: false,
+            guild_id: None,
+            visibility: QuestVisibilities::default(),
+        }
+    }
+
+    fn revision(revision_number: i32, changes: Vec<QuestFieldChangeModel>) -> QuestRevisionModel {
+        QuestRevisionModel {
+            id: revision_number,
+            quest_id: 1,
+            revision_number,
+            guild_commander_id: 1,
+            changes,
+            requires_reconfirmation: false,
+            created_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+
+    fn change(
+        field: &str,
+        from: serde_json::Value,
+        to: serde_json::Value,
+    ) -> QuestFieldChangeModel {
+        QuestFieldChangeModel {
+            field: field.to_string(),
+            from,
+            to,
+        }
+    }
+
+    // ค่าปัจจุบันคือ fields() ที่ผ่านมาแล้ว 2 revision
+    fn revisions() -> Vec<QuestRevisionModel> {
+        vec![
+            revision(1, vec![change("reward_gold", 100.into(), 200.into())]),
+            revision(
+                2,
+                vec![
+                    change("reward_gold", 200.into(), 300.into()),
+                    change("name", "Find the dragon".into(), "Slay the dragon".into()),
+                ],
+            ),
+        ]
+    }
+
+    #[test]
+    fn at_revision_rewinds_later_changes() {
+        let current = fields();
+
+        let first = current.at_revision(&revisions(), 1).unwrap();
+        assert_eq!(first.reward_gold, 200);
+        assert_eq!(first.name, "Find the dragon");
+
+        let original = current.at_revision(&revisions(), 0).unwrap();
+        assert_eq!(original.reward_gold, 100);
+        assert_eq!(original.name, "Find the dragon");
+    }
+
+    #[test]
+    fn at_revision_of_latest_revision_is_current_fields() {
+        let current = fields();
+
+        assert_eq!(current.at_revision(&revisions(), 2).unwrap(), current);
+    }
+
+    #[test]
+    fn at_revision_rejects_unknown_revision() {
+        let current = fields();
+
+        assert!(current.at_revision(&revisions(), 3).is_err());
+        assert!(current.at_revision(&revisions(), -1).is_err());
+    }
+
+    #[test]
+    fn changes_to_lists_only_changed_fields() {
+        let current = fields();
+        let target = QuestFieldsModel {
+            reward_gold: 100,
+            visibility: QuestVisibilities::GuildOnly,
+            guild_id: Some(7),
+            ..fields()
+        };
+
+        let changes = current.changes_to(&target).unwrap();
+
+        assert_eq!(changes.len(), 3);
+        assert!(changes.contains(&change("reward_gold", 300.into(), 100.into())));
+        assert!(changes.contains(&change("visibility", "Public".into(), "GuildOnly".into())));
+        assert!(changes.contains(&change("guild_id", serde_json::Value::Null, 7.into())));
+        assert!(current.changes_to(&current).unwrap().is_empty());
+    }
+}
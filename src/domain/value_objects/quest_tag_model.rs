@@ -0,0 +1,50 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_TAGS_PER_QUEST: usize = 10;
+pub const MAX_TAG_LENGTH: usize = 64;
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagModel {
+    pub name: String,
+    pub quests_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetQuestTagsModel {
+    pub tags: Vec<String>,
+}
+
+// ตัดช่องว่าง แปลงเป็นตัวเล็ก และตัดตัวซ้ำ
+pub fn normalize_tags(tags: &[String]) -> Result<Vec<String>> {
+    let mut normalized_tags = Vec::<String>::new();
+
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+
+        if tag.is_empty() {
+            return Err(anyhow::anyhow!("Tag must not be empty"));
+        }
+
+        if tag.chars().count() > MAX_TAG_LENGTH {
+            return Err(anyhow::anyhow!(
+                "Tag must not exceed {} characters",
+                MAX_TAG_LENGTH
+            ));
+        }
+
+        if !normalized_tags.contains(&tag) {
+            normalized_tags.push(tag);
+        }
+    }
+
+    if normalized_tags.len() > MAX_TAGS_PER_QUEST {
+        return Err(anyhow::anyhow!(
+            "Quest must not have more than {} tags",
+            MAX_TAGS_PER_QUEST
+        ));
+    }
+
+    Ok(normalized_tags)
+}
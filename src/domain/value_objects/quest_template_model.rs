@@ -0,0 +1,106 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::quest_templates::AddQuestTemplateEntity,
+    value_objects::{
+        join_policies::JoinPolicies, quest_adventurer_juntion::default_max_crew,
+        quest_model::AddQuestModel, quest_visibilities::QuestVisibilities,
+    },
+};
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestTemplateModel {
+    pub id: i32,
+    pub guild_commander_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: JoinPolicies,
+    pub reward_gold: i32,
+    pub reward_experience: i32,
+    pub min_level: i32,
+    pub require_checkpoints: bool,
+    pub guild_id: Option<i32>,
+    pub visibility: QuestVisibilities,
+    pub tags: Vec<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl QuestTemplateModel {
+    pub fn to_add_quest_model(&self, overrides: CreateQuestFromTemplateModel) -> AddQuestModel {
+        AddQuestModel {
+            name: overrides.name.unwrap_or(self.name.clone()),
+            description: overrides.description.or(self.description.clone()),
+            min_crew: Some(self.min_crew),
+            max_crew: Some(self.max_crew),
+            join_policy: Some(self.join_policy.clone()),
+            starts_at: overrides.starts_at,
+            join_deadline: overrides.join_deadline,
+            due_at: overrides.due_at,
+            reward_gold: Some(self.reward_gold),
+            reward_experience: Some(self.reward_experience),
+            min_level: Some(self.min_level),
+            require_checkpoints: Some(self.require_checkpoints),
+            tags: Some(self.tags.clone()),
+            guild_id: self.guild_id,
+            visibility: Some(self.visibility.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddQuestTemplateModel {
+    pub name: String,
+    pub description: Option<String>,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    pub join_policy: Option<JoinPolicies>,
+    pub reward_gold: Option<i32>,
+    pub reward_experience: Option<i32>,
+    pub min_level: Option<i32>,
+    pub require_checkpoints: Option<bool>,
+    pub guild_id: Option<i32>,
+    pub visibility: Option<QuestVisibilities>,
+    pub tags: Option<Vec<String>>,
+}
+
+impl AddQuestTemplateModel {
+    pub fn to_entity(
+        &self,
+        guild_commander_id: i32,
+        tags: Vec<String>,
+        max_crew_ceiling: i32,
+    ) -> AddQuestTemplateEntity {
+        AddQuestTemplateEntity {
+            guild_commander_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            min_crew: self.min_crew.unwrap_or(1),
+            max_crew: self.max_crew.unwrap_or(default_max_crew(max_crew_ceiling)),
+            join_policy: self.join_policy.clone().unwrap_or_default().to_string(),
+            reward_gold: self.reward_gold.unwrap_or(0),
+            reward_experience: self.reward_experience.unwrap_or(0),
+            min_level: self.min_level.unwrap_or(1),
+            tags: tags.into_iter().map(Some).collect(),
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+            require_checkpoints: self.require_checkpoints.unwrap_or(false),
+            guild_id: self.guild_id,
+            visibility: self.visibility.clone().unwrap_or_default().to_string(),
+        }
+    }
+}
+
+// ค่าที่เปลี่ยนทุกครั้งที่สร้าง quest จาก template
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateQuestFromTemplateModel {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub starts_at: Option<NaiveDateTime>,
+    pub join_deadline: Option<NaiveDateTime>,
+    pub due_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,20 @@
+use std::fmt;
+
+// If-Match ไม่ตรงกับ version ปัจจุบันของ quest
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuestVersionMismatch {
+    pub expected: i32,
+    pub current: i32,
+}
+
+impl fmt::Display for QuestVersionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Quest version mismatch: expected {}, current {}",
+            self.expected, self.current
+        )
+    }
+}
+
+impl std::error::Error for QuestVersionMismatch {}
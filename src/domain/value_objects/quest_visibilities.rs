@@ -0,0 +1,50 @@
+use std::fmt;
+use std::io::Write;
+
+use diesel::{
+    AsExpression, FromSqlRow,
+    deserialize::{self, FromSql},
+    pg::Pg,
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+// Serialize,Deserialize แปลง json
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Varchar)]
+pub enum QuestVisibilities {
+    #[default]
+    Public,
+    GuildOnly,
+}
+
+impl fmt::Display for QuestVisibilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuestVisibilities::Public => write!(f, "Public"),
+            QuestVisibilities::GuildOnly => write!(f, "GuildOnly"),
+        }
+    }
+}
+
+impl FromSql<Varchar, Pg> for QuestVisibilities {
+    fn from_sql(
+        bytes: <Pg as diesel::backend::Backend>::RawValue<'_>,
+    ) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        match value.as_str() {
+            "Public" => Ok(QuestVisibilities::Public),
+            "GuildOnly" => Ok(QuestVisibilities::GuildOnly),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for QuestVisibilities {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let value = self.to_string();
+        out.write_all(value.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
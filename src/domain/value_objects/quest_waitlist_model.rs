@@ -0,0 +1,17 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+// Serialize,Deserialize แปลง json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestWaitlistModel {
+    pub quest_id: i32,
+    pub adventurer_id: i32,
+    pub position: i64,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JoinOutcomes {
+    Joined,
+    Waitlisted { position: i64 },
+}
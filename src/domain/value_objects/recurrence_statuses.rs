@@ -0,0 +1,53 @@
+use std::fmt;
+use std::io::Write;
+
+use diesel::{
+    AsExpression, FromSqlRow,
+    deserialize::{self, FromSql},
+    pg::Pg,
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+// Serialize,Deserialize แปลง json
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Varchar)]
+pub enum RecurrenceStatuses {
+    #[default]
+    Active,
+    Paused,
+    Stopped,
+}
+
+impl fmt::Display for RecurrenceStatuses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecurrenceStatuses::Active => write!(f, "Active"),
+            RecurrenceStatuses::Paused => write!(f, "Paused"),
+            RecurrenceStatuses::Stopped => write!(f, "Stopped"),
+        }
+    }
+}
+
+impl FromSql<Varchar, Pg> for RecurrenceStatuses {
+    fn from_sql(
+        bytes: <Pg as diesel::backend::Backend>::RawValue<'_>,
+    ) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        match value.as_str() {
+            "Active" => Ok(RecurrenceStatuses::Active),
+            "Paused" => Ok(RecurrenceStatuses::Paused),
+            "Stopped" => Ok(RecurrenceStatuses::Stopped),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for RecurrenceStatuses {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let value = self.to_string();
+        out.write_all(value.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
@@ -11,14 +11,14 @@ pub fn hash(password: String) -> Result<String> {
     let argon2 = Argon2::default();
     let password_hash = argon2
         .hash_password(bytes_password, &salt)
-        .map_err(|e| anyhow::anyhow!("Failed to hash password: {}", e.to_string()))?;
+        .map_err(|e| anyhow::anyhow!("Failed to hash password: {}", e))?;
 
     Ok(password_hash.to_string())
 }
 
 pub fn verify(password: String, hashed_password: String) -> Result<bool> {
     let parsed_hash = PasswordHash::new(&hashed_password)
-        .map_err(|e| anyhow::anyhow!("Failed to parse hash: {}", e.to_string()))?;
+        .map_err(|e| anyhow::anyhow!("Failed to parse hash: {}", e))?;
 
     let bytes_password = password.as_bytes();
 
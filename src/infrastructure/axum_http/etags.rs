@@ -0,0 +1,88 @@
+use axum::http::{HeaderMap, StatusCode, header};
+
+use crate::domain::value_objects::quest_version::QuestVersionMismatch;
+
+pub fn quest_etag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
+// None คือ If-Match: * ยอมรับทุก version
+pub fn if_match_version(headers: &HeaderMap) -> Result<Option<i32>, (StatusCode, &'static str)> {
+    let invalid = (
+        StatusCode::PRECONDITION_FAILED,
+        "If-Match header must be a quest version or *",
+    );
+
+    let if_match = headers
+        .get(header::IF_MATCH)
+        .ok_or((
+            StatusCode::PRECONDITION_REQUIRED,
+            "If-Match header is required",
+        ))?
+        .to_str()
+        .map_err(|_| invalid)?
+        .trim();
+
+    if if_match == "*" {
+        return Ok(None);
+    }
+
+    if_match
+        .trim_start_matches("W/")
+        .trim_matches('"')
+        .parse::<i32>()
+        .map(Some)
+        .map_err(|_| invalid)
+}
+
+pub fn quest_error_status(e: &anyhow::Error) -> StatusCode {
+    if e.downcast_ref::<QuestVersionMismatch>().is_some() {
+        StatusCode::PRECONDITION_FAILED
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(if_match: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_MATCH, if_match.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn if_match_version_requires_header() {
+        let result = if_match_version(&HeaderMap::new());
+
+        assert_eq!(
+            result.unwrap_err(),
+            (
+                StatusCode::PRECONDITION_REQUIRED,
+                "If-Match header is required"
+            )
+        );
+    }
+
+    #[test]
+    fn if_match_version_accepts_any_version() {
+        assert_eq!(if_match_version(&headers("*")), Ok(None));
+    }
+
+    #[test]
+    fn if_match_version_parses_strong_and_weak_etags() {
+        assert_eq!(if_match_version(&headers("\"3\"")), Ok(Some(3)));
+        assert_eq!(if_match_version(&headers("W/\"3\"")), Ok(Some(3)));
+        assert_eq!(if_match_version(&headers(" 3 ")), Ok(Some(3)));
+    }
+
+    #[test]
+    fn if_match_version_rejects_unparseable_value() {
+        let (status, message) = if_match_version(&headers("\"abc\"")).unwrap_err();
+
+        assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+        assert_ne!(message, "If-Match header is required");
+    }
+}
@@ -1,15 +1,29 @@
 use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use crate::{
+    application::usecases::idempotency_keys::IdempotencyKeysUsecase,
     config::config_model::DotEnvyConfig,
     infrastructure::{
-        axum_http::{default_routers, routers},
-        postgres::postgres_connection::PgPoolSquad,
+        axum_http::{
+            default_routers,
+            idempotency::{IDEMPOTENCY_KEY_HEADER, IDEMPOTENT_REPLAYED_HEADER, idempotency},
+            routers,
+        },
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::idempotency_keys::IdempotencyKeysPostgres,
+        },
+        schedulers,
     },
 };
 
 use anyhow::Result;
-use axum::{Router, http::Method, routing::get};
+use axum::{
+    Router,
+    http::{HeaderName, Method, header},
+    middleware,
+    routing::get,
+};
 use tokio::net::TcpListener;
 use tower_http::{
     cors::{Any, CorsLayer},
@@ -20,6 +34,14 @@ use tower_http::{
 use tracing::info;
 
 pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
+    tokio::spawn(schedulers::quest_deadlines::run(Arc::clone(&db_pool)));
+    tokio::spawn(schedulers::quest_recurrences::run(Arc::clone(&db_pool)));
+    tokio::spawn(schedulers::quest_purge::run(Arc::clone(&db_pool)));
+    tokio::spawn(schedulers::idempotency_keys::run(Arc::clone(&db_pool)));
+
+    let idempotency_keys_usecase =
+        IdempotencyKeysUsecase::new(Arc::new(IdempotencyKeysPostgres::new(Arc::clone(&db_pool))));
+
     let app = Router::new()
         .fallback(default_routers::not_found)
         .nest(
@@ -30,10 +52,38 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
             "/quest-ops",
             routers::quest_ops::routes(Arc::clone(&db_pool)),
         )
+        .nest(
+            "/quest-templates",
+            routers::quest_templates::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/quest-recurrences",
+            routers::quest_recurrences::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/quest-comments",
+            routers::quest_comments::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/quest-checkpoints",
+            routers::quest_checkpoints::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/quest-reports",
+            routers::quest_reports::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/quest-ratings",
+            routers::quest_ratings::routes(Arc::clone(&db_pool)),
+        )
         .nest(
             "/crew-switchboard",
             routers::crew_swithboard::routes(Arc::clone(&db_pool)),
         )
+        .nest(
+            "/crew-invitations",
+            routers::crew_invitations::routes(Arc::clone(&db_pool)),
+        )
         .nest(
             "/guild-commanders",
             routers::guild_commanders::routes(Arc::clone(&db_pool)),
@@ -50,7 +100,17 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
             "/authentication",
             routers::authentication::routes(Arc::clone(&db_pool)),
         )
+        .nest(
+            "/leaderboards",
+            routers::leaderboards::routes(Arc::clone(&db_pool)),
+        )
+        .nest("/guilds", routers::guilds::routes(Arc::clone(&db_pool)))
+        .nest("/admin", routers::admin::routes(Arc::clone(&db_pool)))
         .route("/health-check", get(default_routers::health_check))
+        .layer(middleware::from_fn_with_state(
+            Arc::new(idempotency_keys_usecase),
+            idempotency,
+        ))
         .layer(TimeoutLayer::new(Duration::from_secs(
             config.server.timeout,
         )))
@@ -66,7 +126,16 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
                     Method::PATCH,
                     Method::DELETE,
                 ])
-                .allow_origin(Any),
+                .allow_origin(Any)
+                .allow_headers([
+                    header::CONTENT_TYPE,
+                    header::IF_MATCH,
+                    HeaderName::from_static(IDEMPOTENCY_KEY_HEADER),
+                ])
+                .expose_headers([
+                    header::ETAG,
+                    HeaderName::from_static(IDEMPOTENT_REPLAYED_HEADER),
+                ]),
         )
         .layer(TraceLayer::new_for_http());
     // ด่านแรกสุด อยู่ล่าง TraceLayer
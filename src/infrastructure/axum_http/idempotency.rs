@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{Body, to_bytes},
+    extract::{Request, State},
+    http::{HeaderValue, Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+use tracing::error;
+
+use crate::{
+    application::usecases::idempotency_keys::IdempotencyKeysUsecase,
+    domain::{
+        entities::idempotency_keys::CompleteIdempotencyKeyEntity,
+        repositories::idempotency_keys::IdempotencyKeysRepository,
+        value_objects::idempotency_model::{IDEMPOTENCY_KEY_MAX_LENGTH, IdempotencyOutcomes},
+    },
+    infrastructure::axum_http::middlewares::get_user_scope,
+};
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+
+// ใช้กับ router ไหนก็ได้ผ่าน middleware::from_fn_with_state
+pub async fn idempotency<T>(
+    State(idempotency_keys_usecase): State<Arc<IdempotencyKeysUsecase<T>>>,
+    req: Request,
+    next: Next,
+) -> Response
+where
+    T: IdempotencyKeysRepository + Send + Sync,
+{
+    let is_mutating = matches!(
+        *req.method(),
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    );
+
+    if !is_mutating {
+        return next.run(req).await;
+    }
+
+    let Some(idempotency_key) = req
+        .headers()
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim().to_string())
+    else {
+        return next.run(req).await;
+    };
+
+    if idempotency_key.is_empty() || idempotency_key.len() > IDEMPOTENCY_KEY_MAX_LENGTH {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Idempotency-Key must be 1 to {} characters",
+                IDEMPOTENCY_KEY_MAX_LENGTH
+            ),
+        )
+            .into_response();
+    }
+
+    // request ที่ไม่ได้ login ไม่มี user ให้ผูก key จึงไม่เก็บ response
+    let Some(user_scope) = get_user_scope(&req) else {
+        return next.run(req).await;
+    };
+
+    let request_method = req.method().to_string();
+    let request_path = req.uri().path().to_string();
+
+    // body ถูกจำกัดขนาดโดย RequestBodyLimitLayer ก่อนถึง middleware นี้แล้ว
+    let (parts, body) = req.into_parts();
+    let request_body = match to_bytes(body, usize::MAX).await {
+        Ok(request_body) => request_body,
+        Err(e) => {
+            return (StatusCode::BAD_REQUEST, format!("Error: {}", e)).into_response();
+        }
+    };
+    let request_fingerprint = request_fingerprint(parts.uri.query(), &request_body);
+    let req = Request::from_parts(parts, Body::from(request_body));
+
+    match idempotency_keys_usecase
+        .begin(
+            &user_scope,
+            &idempotency_key,
+            &request_method,
+            &request_path,
+            &request_fingerprint,
+        )
+        .await
+    {
+        Ok(IdempotencyOutcomes::Proceed) => {}
+        Ok(IdempotencyOutcomes::Replay {
+            status_code,
+            content_type,
+            etag,
+            location,
+            response_body,
+        }) => {
+            return replay_response(status_code, content_type, etag, location, response_body);
+        }
+        Ok(IdempotencyOutcomes::InProgress) => {
+            return (
+                StatusCode::CONFLICT,
+                "A request with this Idempotency-Key is still in progress",
+            )
+                .into_response();
+        }
+        Ok(IdempotencyOutcomes::Mismatch) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "Idempotency-Key was already used for a different request",
+            )
+                .into_response();
+        }
+        Err(e) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, format!("Error: {}", e)).into_response();
+        }
+    }
+
+    let response = next.run(req).await;
+
+    // เก็บทุก response รวมถึง 4xx/5xx ที่ handler ตอบกลับมา เพราะ handler อาจทำงานไปบางส่วนแล้ว
+    // คืน key เฉพาะ 502/503/504 ซึ่งหมายถึง request ยังไม่ถูกประมวลผล
+    if matches!(
+        response.status(),
+        StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT
+    ) {
+        if let Err(e) = idempotency_keys_usecase
+            .release(&user_scope, &idempotency_key)
+            .await
+        {
+            error!("Failed to release idempotency key :{}", e);
+        }
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+
+    let response_body = match to_bytes(body, usize::MAX).await {
+        Ok(response_body) => response_body,
+        Err(e) => {
+            if let Err(e) = idempotency_keys_usecase
+                .release(&user_scope, &idempotency_key)
+                .await
+            {
+                error!("Failed to release idempotency key :{}", e);
+            }
+            return (StatusCode::INTERNAL_SERVER_ERROR, format!("Error: {}", e)).into_response();
+        }
+    };
+
+    let header_value = |name: header::HeaderName| {
+        parts
+            .headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string())
+    };
+
+    let complete_idempotency_key_entity = CompleteIdempotencyKeyEntity {
+        status_code: parts.status.as_u16() as i32,
+        content_type: header_value(header::CONTENT_TYPE),
+        etag: header_value(header::ETAG),
+        location: header_value(header::LOCATION),
+        response_body: response_body.to_vec(),
+    };
+
+    if let Err(e) = idempotency_keys_usecase
+        .complete(
+            &user_scope,
+            &idempotency_key,
+            complete_idempotency_key_entity,
+        )
+        .await
+    {
+        error!("Failed to store idempotent response :{}", e);
+    }
+
+    Response::from_parts(parts, Body::from(response_body))
+}
+
+// key เดียวกันต้องมาพร้อม query และ body เดิม
+fn request_fingerprint(query: Option<&str>, request_body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(query.unwrap_or_default().as_bytes());
+    hasher.update(b"\n");
+    hasher.update(request_body);
+
+    format!("{:x}", hasher.finalize())
+}
+
+fn replay_response(
+    status_code: u16,
+    content_type: Option<String>,
+    etag: Option<String>,
+    location: Option<String>,
+    response_body: Vec<u8>,
+) -> Response {
+    let mut response = Response::new(Body::from(response_body));
+
+    *response.status_mut() =
+        StatusCode::from_u16(status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+    for (name, value) in [
+        (header::CONTENT_TYPE, content_type),
+        (header::ETAG, etag),
+        (header::LOCATION, location),
+    ] {
+        if let Some(value) = value
+            && let Ok(value) = HeaderValue::from_str(&value)
+        {
+            response.headers_mut().insert(name, value);
+        }
+    }
+
+    response
+        .headers_mut()
+        .insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_fingerprint_is_stable_sha256_hex() {
+        let fingerprint = request_fingerprint(Some("page=1"), br#"{"name":"Quest"}"#);
+
+        assert_eq!(fingerprint.len(), 64);
+        assert_eq!(
+            fingerprint,
+            request_fingerprint(Some("page=1"), br#"{"name":"Quest"}"#)
+        );
+    }
+
+    #[test]
+    fn request_fingerprint_changes_with_body_or_query() {
+        let fingerprint = request_fingerprint(Some("page=1"), br#"{"name":"Quest"}"#);
+
+        assert_ne!(
+            fingerprint,
+            request_fingerprint(Some("page=1"), br#"{"name":"Other"}"#)
+        );
+        assert_ne!(
+            fingerprint,
+            request_fingerprint(Some("page=2"), br#"{"name":"Quest"}"#)
+        );
+    }
+
+    #[test]
+    fn request_fingerprint_treats_missing_query_as_empty() {
+        assert_eq!(
+            request_fingerprint(None, b""),
+            request_fingerprint(Some(""), b"")
+        );
+    }
+}
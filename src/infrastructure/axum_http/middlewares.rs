@@ -6,7 +6,10 @@ use axum::{
 };
 
 use crate::{
-    config::config_loader::{get_adventurer_secret_env, get_guild_commanders_secret_env},
+    config::config_loader::{
+        get_admin_secret_env, get_adventurer_secret_env, get_guild_commanders_secret_env,
+    },
+    domain::value_objects::quest_participants::QuestParticipants,
     infrastructure::jwt_authentication,
 };
 
@@ -14,48 +17,98 @@ pub async fn adventures_authorization(
     mut req: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    if let Some(cookie_header) = req.headers().get(header::COOKIE) {
-        if let Ok(cookie_str) = cookie_header.to_str() {
-            let access_token = get_cookie_value(cookie_str, "act");
+    if let Some(adventurer_id) = get_adventurer_id(&req) {
+        req.extensions_mut().insert(adventurer_id);
+        return Ok(next.run(req).await);
+    }
+    Err(StatusCode::UNAUTHORIZED)
+}
 
-            if let Some(token) = access_token {
-                if let Ok(secret_env) = get_adventurer_secret_env() {
-                    if let Ok(claims) = jwt_authentication::verify_token(secret_env.secret, token) {
-                        if let Ok(adventurer_id) = claims.sub.parse::<i32>() {
-                            req.extensions_mut().insert(adventurer_id);
-                            return Ok(next.run(req).await);
-                        }
-                    }
-                }
-            }
+// ไม่บังคับ login ถ้ามี cookie ของ adventurer หรือ guild commander ก็แนบผู้เรียกไปด้วย
+pub async fn quest_participants_identification(mut req: Request, next: Next) -> Response {
+    match (get_adventurer_id(&req), get_guild_commander_id(&req)) {
+        (Some(adventurer_id), _) => {
+            req.extensions_mut()
+                .insert(QuestParticipants::Adventurer(adventurer_id));
+        }
+        (None, Some(guild_commander_id)) => {
+            req.extensions_mut()
+                .insert(QuestParticipants::GuildCommander(guild_commander_id));
         }
+        (None, None) => {}
     }
-    Err(StatusCode::UNAUTHORIZED)
+    next.run(req).await
 }
 
 pub async fn guild_commanders_authorization(
     mut req: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    if let Some(cookie_header) = req.headers().get(header::COOKIE) {
-        if let Ok(cookie_str) = cookie_header.to_str() {
-            let access_token = get_cookie_value(cookie_str, "act");
+    if let Some(guild_commander_id) = get_guild_commander_id(&req) {
+        req.extensions_mut().insert(guild_commander_id);
+        return Ok(next.run(req).await);
+    }
+    Err(StatusCode::UNAUTHORIZED)
+}
+
+// ใช้กับ endpoint ที่ทั้ง adventurer และ guild commander เรียกได้
+pub async fn quest_participants_authorization(
+    mut req: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let participant = match (get_adventurer_id(&req), get_guild_commander_id(&req)) {
+        (Some(adventurer_id), _) => QuestParticipants::Adventurer(adventurer_id),
+        (None, Some(guild_commander_id)) => QuestParticipants::GuildCommander(guild_commander_id),
+        (None, None) => return Err(StatusCode::UNAUTHORIZED),
+    };
 
-            if let Some(token) = access_token {
-                if let Ok(secret_env) = get_guild_commanders_secret_env() {
-                    if let Ok(claims) = jwt_authentication::verify_token(secret_env.secret, token) {
-                        if let Ok(adventurer_id) = claims.sub.parse::<i32>() {
-                            req.extensions_mut().insert(adventurer_id);
-                            return Ok(next.run(req).await);
-                        }
-                    }
-                }
-            }
-        }
+    req.extensions_mut().insert(participant);
+    Ok(next.run(req).await)
+}
+
+pub async fn admin_authorization(req: Request, next: Next) -> Result<Response, StatusCode> {
+    if let Some(api_key) = req.headers().get("x-admin-key")
+        && let Ok(api_key) = api_key.to_str()
+        && let Ok(admin_secret) = get_admin_secret_env()
+        && !admin_secret.api_key.is_empty()
+        && api_key == admin_secret.api_key
+    {
+        return Ok(next.run(req).await);
     }
     Err(StatusCode::UNAUTHORIZED)
 }
 
+// ใช้แยก idempotency key ของแต่ละ user
+pub fn get_user_scope(req: &Request) -> Option<String> {
+    if let Some(adventurer_id) = get_adventurer_id(req) {
+        return Some(format!("adventurer:{}", adventurer_id));
+    }
+
+    if let Some(guild_commander_id) = get_guild_commander_id(req) {
+        return Some(format!("guild_commander:{}", guild_commander_id));
+    }
+
+    None
+}
+
+fn get_adventurer_id(req: &Request) -> Option<i32> {
+    let cookie_str = req.headers().get(header::COOKIE)?.to_str().ok()?;
+    let token = get_cookie_value(cookie_str, "act")?;
+    let secret_env = get_adventurer_secret_env().ok()?;
+    let claims = jwt_authentication::verify_token(secret_env.secret, token).ok()?;
+
+    claims.sub.parse::<i32>().ok()
+}
+
+fn get_guild_commander_id(req: &Request) -> Option<i32> {
+    let cookie_str = req.headers().get(header::COOKIE)?.to_str().ok()?;
+    let token = get_cookie_value(cookie_str, "act")?;
+    let secret_env = get_guild_commanders_secret_env().ok()?;
+    let claims = jwt_authentication::verify_token(secret_env.secret, token).ok()?;
+
+    claims.sub.parse::<i32>().ok()
+}
+
 fn get_cookie_value(cookie_header: &str, key: &str) -> Option<String> {
     cookie_header.split("; ").find_map(|cookie: &str| {
         let mut parts = cookie.splitn(2, '=');
@@ -1,4 +1,5 @@
 pub mod routers;
 pub mod http_serve;
 pub mod default_routers;
-pub mod middlewares;
\ No newline at end of file
+pub mod middlewares;pub mod etags;
+pub mod idempotency;
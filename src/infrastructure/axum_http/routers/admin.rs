@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router, extract::State, http::StatusCode, middleware, response::IntoResponse,
+    routing::post,
+};
+
+use crate::{
+    application::usecases::quest_purge::QuestPurgeUsecase,
+    config::config_loader::get_quest_attachments_env,
+    domain::repositories::{attachment_storage::AttachmentStorage, quest_ops::QuestOpsRepository},
+    infrastructure::{
+        axum_http::middlewares::admin_authorization,
+        local_storage::LocalAttachmentStorage,
+        postgres::{postgres_connection::PgPoolSquad, repositories::quest_ops::QuestOpsPostgres},
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_attachments =
+        get_quest_attachments_env().expect("Quest attachments config is invalid");
+
+    let quest_ops_repository = QuestOpsPostgres::new(db_pool);
+    let attachment_storage = LocalAttachmentStorage::new(quest_attachments.storage_dir);
+    let quest_purge_usecase =
+        QuestPurgeUsecase::new(Arc::new(quest_ops_repository), Arc::new(attachment_storage));
+
+    Router::new()
+        .route("/quests/purge", post(purge_quests))
+        .route_layer(middleware::from_fn(admin_authorization))
+        .with_state(Arc::new(quest_purge_usecase))
+}
+
+pub async fn purge_quests<T1, T2>(
+    State(quest_purge_usecase): State<Arc<QuestPurgeUsecase<T1, T2>>>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: AttachmentStorage + Send + Sync,
+{
+    match quest_purge_usecase.purge_expired().await {
+        Ok(purged_quest_ids) => (StatusCode::OK, Json(purged_quest_ids).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
@@ -1,26 +1,68 @@
 use std::sync::Arc;
 
-use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
 
 use crate::{
-    application::usecases::adventures::AdventuresUsecase,
+    application::usecases::{
+        adventurer_ledger::AdventurerLedgerUsecase, adventures::AdventuresUsecase,
+    },
     domain::{
-        repositories::adventures::AdventuresRepository,
+        repositories::{
+            achievements::AchievementsRepository, adventurer_ledger::AdventurerLedgerRepository,
+            adventures::AdventuresRepository, quest_ratings::QuestRatingsRepository,
+        },
         value_objects::adventurer_model::RegisterAdventurerModel,
     },
-    infrastructure::postgres::{
-        postgres_connection::PgPoolSquad, repositories::adventures::AdventurerPostgres,
+    infrastructure::{
+        axum_http::middlewares::adventures_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                achievements::AchievementsPostgres, adventurer_ledger::AdventurerLedgerPostgres,
+                adventures::AdventurerPostgres, quest_ratings::QuestRatingsPostgres,
+            },
+        },
     },
 };
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     // ในสุด ไปนอกสุด
-    let adventurer_repository = AdventurerPostgres::new(db_pool);
+    let adventurer_repository = AdventurerPostgres::new(db_pool.clone());
     let adventurer_usecase = AdventuresUsecase::new(Arc::new(adventurer_repository));
 
+    let adventurer_ledger_repository = AdventurerLedgerPostgres::new(db_pool.clone());
+    let quest_ratings_repository = QuestRatingsPostgres::new(db_pool.clone());
+    let achievements_repository = AchievementsPostgres::new(db_pool);
+    let adventurer_ledger_usecase = Arc::new(AdventurerLedgerUsecase::new(
+        Arc::new(adventurer_ledger_repository),
+        Arc::new(quest_ratings_repository),
+        Arc::new(achievements_repository),
+    ));
+
+    let me_routes = Router::new()
+        .route("/me", get(profile))
+        .route("/me/balance", get(balance))
+        .route("/me/ledger", get(ledger))
+        .route_layer(middleware::from_fn(adventures_authorization))
+        .with_state(Arc::clone(&adventurer_ledger_usecase));
+
+    // profile สาธารณะ ดูได้โดยไม่ต้อง login
+    let public_routes = Router::new()
+        .route("/:adventurer_id", get(public_profile))
+        .with_state(adventurer_ledger_usecase);
+
     Router::new()
         .route("/", post(register))
         .with_state(Arc::new(adventurer_usecase))
+        .merge(me_routes)
+        .merge(public_routes)
 }
 
 pub async fn register<T>(
@@ -42,8 +84,83 @@ where
             StatusCode::INTERNAL_SERVER_ERROR,
             format!(
                 "Error: {}",
-                e.to_string()
+                e
             ).into_response(),
         ),
     }
 }
+
+pub async fn balance<T1, T2, T3>(
+    State(adventurer_ledger_usecase): State<Arc<AdventurerLedgerUsecase<T1, T2, T3>>>,
+    Extension(adventurer_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: AdventurerLedgerRepository + Send + Sync,
+    T2: QuestRatingsRepository + Send + Sync,
+    T3: AchievementsRepository + Send + Sync,
+{
+    match adventurer_ledger_usecase.balance(adventurer_id).await {
+        Ok(balance) => (StatusCode::OK, Json(balance).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn ledger<T1, T2, T3>(
+    State(adventurer_ledger_usecase): State<Arc<AdventurerLedgerUsecase<T1, T2, T3>>>,
+    Extension(adventurer_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: AdventurerLedgerRepository + Send + Sync,
+    T2: QuestRatingsRepository + Send + Sync,
+    T3: AchievementsRepository + Send + Sync,
+{
+    match adventurer_ledger_usecase
+        .entries_by_adventurer_id(adventurer_id)
+        .await
+    {
+        Ok(entries) => (StatusCode::OK, Json(entries).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn profile<T1, T2, T3>(
+    State(adventurer_ledger_usecase): State<Arc<AdventurerLedgerUsecase<T1, T2, T3>>>,
+    Extension(adventurer_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: AdventurerLedgerRepository + Send + Sync,
+    T2: QuestRatingsRepository + Send + Sync,
+    T3: AchievementsRepository + Send + Sync,
+{
+    match adventurer_ledger_usecase.profile(adventurer_id).await {
+        Ok(profile) => (StatusCode::OK, Json(profile).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn public_profile<T1, T2, T3>(
+    State(adventurer_ledger_usecase): State<Arc<AdventurerLedgerUsecase<T1, T2, T3>>>,
+    Path(adventurer_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: AdventurerLedgerRepository + Send + Sync,
+    T2: QuestRatingsRepository + Send + Sync,
+    T3: AchievementsRepository + Send + Sync,
+{
+    match adventurer_ledger_usecase.profile(adventurer_id).await {
+        Ok(profile) => (StatusCode::OK, Json(profile).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use crate::{
+    application::usecases::crew_invitations::CrewInvitationsUsecase,
+    domain::{
+        repositories::{
+            achievements::AchievementsRepository, adventurer_ledger::AdventurerLedgerRepository,
+            crew_invitations::CrewInvitationsRepository, crew_swithboard::CrewSwithboardRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::quest_invitation_model::InviteAdventurersModel,
+    },
+    infrastructure::{
+        axum_http::middlewares::{adventures_authorization, guild_commanders_authorization},
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                achievements::AchievementsPostgres, adventurer_ledger::AdventurerLedgerPostgres,
+                crew_invitations::CrewInvitationsPostgres, crew_swithboard::CrewSwithboardPostgres,
+                quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+type CrewInvitationsState<T1, T2, T3, T4, T5> =
+    State<Arc<CrewInvitationsUsecase<T1, T2, T3, T4, T5>>>;
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let crew_invitations_repository = CrewInvitationsPostgres::new(db_pool.clone());
+    let crew_swithboard_repository = CrewSwithboardPostgres::new(db_pool.clone());
+    let quest_viewing_repository = QuestViewingPostgres::new(db_pool.clone());
+    let adventurer_ledger_repository = AdventurerLedgerPostgres::new(db_pool.clone());
+    let achievements_repository = AchievementsPostgres::new(db_pool);
+    let crew_invitations_usecase = CrewInvitationsUsecase::new(
+        Arc::new(crew_invitations_repository),
+        Arc::new(crew_swithboard_repository),
+        Arc::new(quest_viewing_repository),
+        Arc::new(adventurer_ledger_repository),
+        Arc::new(achievements_repository),
+    );
+
+    let adventurers_routes = Router::new()
+        .route("/", get(pending_by_adventurer_id))
+        .route("/:invitation_id/accept", post(accept))
+        .route("/:invitation_id/decline", post(decline))
+        .route_layer(middleware::from_fn(adventures_authorization));
+
+    let guild_commanders_routes = Router::new()
+        .route("/quests/:quest_id", post(invite))
+        .route("/quests/:quest_id", get(pending_by_quest_id))
+        .route_layer(middleware::from_fn(guild_commanders_authorization));
+
+    Router::new()
+        .merge(adventurers_routes)
+        .merge(guild_commanders_routes)
+        .with_state(Arc::new(crew_invitations_usecase))
+}
+
+pub async fn invite<T1, T2, T3, T4, T5>(
+    State(crew_invitations_usecase): CrewInvitationsState<T1, T2, T3, T4, T5>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+    Json(invite_adventurers_model): Json<InviteAdventurersModel>,
+) -> impl IntoResponse
+where
+    T1: CrewInvitationsRepository + Send + Sync,
+    T2: CrewSwithboardRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: AdventurerLedgerRepository + Send + Sync,
+    T5: AchievementsRepository + Send + Sync,
+{
+    match crew_invitations_usecase
+        .invite(quest_id, guild_commander_id, invite_adventurers_model)
+        .await
+    {
+        Ok(invitation_ids) => (StatusCode::CREATED, Json(invitation_ids).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn pending_by_quest_id<T1, T2, T3, T4, T5>(
+    State(crew_invitations_usecase): CrewInvitationsState<T1, T2, T3, T4, T5>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewInvitationsRepository + Send + Sync,
+    T2: CrewSwithboardRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: AdventurerLedgerRepository + Send + Sync,
+    T5: AchievementsRepository + Send + Sync,
+{
+    match crew_invitations_usecase
+        .pending_by_quest_id(quest_id, guild_commander_id)
+        .await
+    {
+        Ok(invitations) => (StatusCode::OK, Json(invitations).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn pending_by_adventurer_id<T1, T2, T3, T4, T5>(
+    State(crew_invitations_usecase): CrewInvitationsState<T1, T2, T3, T4, T5>,
+    Extension(adventurer_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewInvitationsRepository + Send + Sync,
+    T2: CrewSwithboardRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: AdventurerLedgerRepository + Send + Sync,
+    T5: AchievementsRepository + Send + Sync,
+{
+    match crew_invitations_usecase
+        .pending_by_adventurer_id(adventurer_id)
+        .await
+    {
+        Ok(invitations) => (StatusCode::OK, Json(invitations).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn accept<T1, T2, T3, T4, T5>(
+    State(crew_invitations_usecase): CrewInvitationsState<T1, T2, T3, T4, T5>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(invitation_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewInvitationsRepository + Send + Sync,
+    T2: CrewSwithboardRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: AdventurerLedgerRepository + Send + Sync,
+    T5: AchievementsRepository + Send + Sync,
+{
+    match crew_invitations_usecase
+        .accept(invitation_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            "Invitation accepted successfully".into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn decline<T1, T2, T3, T4, T5>(
+    State(crew_invitations_usecase): CrewInvitationsState<T1, T2, T3, T4, T5>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(invitation_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewInvitationsRepository + Send + Sync,
+    T2: CrewSwithboardRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+    T4: AdventurerLedgerRepository + Send + Sync,
+    T5: AchievementsRepository + Send + Sync,
+{
+    match crew_invitations_usecase
+        .decline(invitation_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            "Invitation declined successfully".into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
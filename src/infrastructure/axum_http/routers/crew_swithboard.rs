@@ -1,24 +1,31 @@
 use std::sync::Arc;
 
 use axum::{
-    Extension, Router,
-    extract::{Path, State},
+    Extension, Json, Router,
+    extract::{Path, Query, State},
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{delete, post},
+    routing::{delete, get, post},
 };
 
 use crate::{
     application::usecases::crew_swithboard::CrewSwithboardUsecase,
-    domain::repositories::{
-        crew_swithboard::CrewSwithboardRepository, quest_viewing::QuestViewingRepository,
+    domain::{
+        repositories::{
+            achievements::AchievementsRepository, adventurer_ledger::AdventurerLedgerRepository,
+            crew_swithboard::CrewSwithboardRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_crew_ban_model::KickAdventurerModel, quest_waitlist_model::JoinOutcomes,
+        },
     },
     infrastructure::{
-        axum_http::middlewares::adventures_authorization,
+        axum_http::middlewares::{adventures_authorization, guild_commanders_authorization},
         postgres::{
             postgres_connection::PgPoolSquad,
             repositories::{
+                achievements::AchievementsPostgres, adventurer_ledger::AdventurerLedgerPostgres,
                 crew_swithboard::CrewSwithboardPostgres, quest_viewing::QuestViewingPostgres,
             },
         },
@@ -27,51 +34,437 @@ use crate::{
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let crew_swithboard_repository = CrewSwithboardPostgres::new(db_pool.clone());
-    let quest_viewing_repository = QuestViewingPostgres::new(db_pool);
+    let quest_viewing_repository = QuestViewingPostgres::new(db_pool.clone());
+    let adventurer_ledger_repository = AdventurerLedgerPostgres::new(db_pool.clone());
+    let achievements_repository = AchievementsPostgres::new(db_pool);
     let crew_swithboard_usecase = CrewSwithboardUsecase::new(
         Arc::new(crew_swithboard_repository),
         Arc::new(quest_viewing_repository),
+        Arc::new(adventurer_ledger_repository),
+        Arc::new(achievements_repository),
     );
 
-    Router::new()
+    let adventurers_routes = Router::new()
         .route("/join/:quest_id", post(join))
         .route("/leave/:quest_id", delete(leave))
-        .route_layer(middleware::from_fn(adventures_authorization))
+        .route("/apply/:quest_id", post(apply))
+        .route("/apply/:quest_id", delete(withdraw))
+        .route("/waitlist", get(waitlist_by_adventurer_id))
+        .route("/waitlist/:quest_id", get(waitlist_position))
+        .route("/waitlist/:quest_id", delete(leave_waitlist))
+        .route("/reconfirmations", get(reconfirmations_by_adventurer_id))
+        .route("/reconfirm/:quest_id", post(reconfirm))
+        .route("/revisions/:quest_id", get(revisions_since_joined))
+        .route_layer(middleware::from_fn(adventures_authorization));
+
+    let guild_commanders_routes = Router::new()
+        .route("/kick/:quest_id/:adventurer_id", delete(kick))
+        .route("/bans/:quest_id", get(bans_by_quest_id))
+        .route("/bans/:quest_id/:adventurer_id", delete(unban))
+        .route("/applications/:quest_id", get(applications_by_quest_id))
+        .route(
+            "/applications/:quest_id/:adventurer_id/approve",
+            post(approve),
+        )
+        .route(
+            "/applications/:quest_id/:adventurer_id/reject",
+            post(reject),
+        )
+        .route_layer(middleware::from_fn(guild_commanders_authorization));
+
+    Router::new()
+        .merge(adventurers_routes)
+        .merge(guild_commanders_routes)
         .with_state(Arc::new(crew_swithboard_usecase))
 }
 
-pub async fn join<T1, T2>(
-    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2>>>,
+pub async fn join<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
     Extension(adventurer_id): Extension<i32>,
     Path(quest_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: CrewSwithboardRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
 {
     match crew_swithboard_usecase.join(quest_id, adventurer_id).await {
-        Ok(()) => (StatusCode::OK, "Quest joined successfully".into_response()),
+        Ok(JoinOutcomes::Joined) => (StatusCode::OK, "Quest joined successfully".into_response()),
+        Ok(JoinOutcomes::Waitlisted { position }) => (
+            StatusCode::ACCEPTED,
+            format!("Quest crew is full, waitlisted at position: {}", position).into_response(),
+        ),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Error: {}", e.to_string()).into_response(),
+            format!("Error: {}", e).into_response(),
         ),
     }
 }
 
-pub async fn leave<T1, T2>(
-    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2>>>,
+pub async fn leave<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
     Extension(adventurer_id): Extension<i32>,
     Path(quest_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: CrewSwithboardRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
 {
     match crew_swithboard_usecase.leave(quest_id, adventurer_id).await {
         Ok(()) => (StatusCode::OK, "Quest left successfully".into_response()),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Error: {}", e.to_string()).into_response(),
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn kick<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+    Query(kick_adventurer_model): Query<KickAdventurerModel>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase
+        .kick(
+            quest_id,
+            guild_commander_id,
+            adventurer_id,
+            kick_adventurer_model,
+        )
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            "Adventurer kicked successfully".into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn bans_by_quest_id<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase
+        .bans_by_quest_id(quest_id, guild_commander_id)
+        .await
+    {
+        Ok(bans) => (StatusCode::OK, Json(bans).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn unban<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase
+        .unban(quest_id, guild_commander_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            "Adventurer unbanned successfully".into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn apply<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase.apply(quest_id, adventurer_id).await {
+        Ok(()) => (
+            StatusCode::CREATED,
+            "Join application sent successfully".into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn withdraw<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase
+        .withdraw(quest_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            "Join application withdrawn successfully".into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn applications_by_quest_id<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase
+        .applications_by_quest_id(quest_id, guild_commander_id)
+        .await
+    {
+        Ok(applications) => (StatusCode::OK, Json(applications).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn approve<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase
+        .approve(quest_id, guild_commander_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            "Join application approved successfully".into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn reject<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, adventurer_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase
+        .reject(quest_id, guild_commander_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            "Join application rejected successfully".into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn waitlist_by_adventurer_id<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(adventurer_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase
+        .waitlist_by_adventurer_id(adventurer_id)
+        .await
+    {
+        Ok(waitlist) => (StatusCode::OK, Json(waitlist).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn waitlist_position<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase
+        .waitlist_position(quest_id, adventurer_id)
+        .await
+    {
+        Ok(waitlist_entry) => (StatusCode::OK, Json(waitlist_entry).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn leave_waitlist<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase
+        .leave_waitlist(quest_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            "Quest waitlist left successfully".into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn reconfirmations_by_adventurer_id<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(adventurer_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase
+        .reconfirmations_by_adventurer_id(adventurer_id)
+        .await
+    {
+        Ok(quest_ids) => (StatusCode::OK, Json(quest_ids).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn reconfirm<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase
+        .reconfirm(quest_id, adventurer_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            "Quest changes reconfirmed successfully".into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn revisions_since_joined<T1, T2, T3, T4>(
+    State(crew_swithboard_usecase): State<Arc<CrewSwithboardUsecase<T1, T2, T3, T4>>>,
+    Extension(adventurer_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewSwithboardRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: AdventurerLedgerRepository + Send + Sync,
+    T4: AchievementsRepository + Send + Sync,
+{
+    match crew_swithboard_usecase
+        .revisions_since_joined(quest_id, adventurer_id)
+        .await
+    {
+        Ok(crew_quest_revisions) => (StatusCode::OK, Json(crew_quest_revisions).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
         ),
     }
 }
@@ -1,26 +1,56 @@
 use std::sync::Arc;
 
-use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{get, post},
+};
 
 use crate::{
-    application::usecases::guild_commanders::GuildCommandersUsecase,
+    application::usecases::{
+        guild_commanders::GuildCommandersUsecase, quest_ratings::QuestRatingsUsecase,
+    },
     domain::{
-        repositories::guild_commanders::GuildCommandersRepository,
+        repositories::{
+            crew_swithboard::CrewSwithboardRepository, guild_commanders::GuildCommandersRepository,
+            quest_ratings::QuestRatingsRepository, quest_viewing::QuestViewingRepository,
+        },
         value_objects::guild_commander_model::RegisterGuildCommanderModel,
     },
     infrastructure::postgres::{
-        postgres_connection::PgPoolSquad, repositories::guild_commanders::GuildCommandersPostgres,
+        postgres_connection::PgPoolSquad,
+        repositories::{
+            crew_swithboard::CrewSwithboardPostgres, guild_commanders::GuildCommandersPostgres,
+            quest_ratings::QuestRatingsPostgres, quest_viewing::QuestViewingPostgres,
+        },
     },
 };
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let guild_commanders_repository = GuildCommandersPostgres::new(db_pool);
+    let guild_commanders_repository = GuildCommandersPostgres::new(db_pool.clone());
     let guild_commanders_usecase =
         GuildCommandersUsecase::new(Arc::new(guild_commanders_repository));
 
+    let quest_ratings_repository = QuestRatingsPostgres::new(db_pool.clone());
+    let quest_viewing_repository = QuestViewingPostgres::new(db_pool.clone());
+    let crew_swithboard_repository = CrewSwithboardPostgres::new(db_pool);
+    let quest_ratings_usecase = QuestRatingsUsecase::new(
+        Arc::new(quest_ratings_repository),
+        Arc::new(quest_viewing_repository),
+        Arc::new(crew_swithboard_repository),
+    );
+
+    // profile สาธารณะ ดูได้โดยไม่ต้อง login
+    let public_routes = Router::new()
+        .route("/:guild_commander_id", get(public_profile))
+        .with_state(Arc::new(quest_ratings_usecase));
+
     Router::new()
         .route("/", post(register))
         .with_state(Arc::new(guild_commanders_usecase))
+        .merge(public_routes)
 }
 
 pub async fn register<T>(
@@ -30,7 +60,10 @@ pub async fn register<T>(
 where
     T: GuildCommandersRepository + Send + Sync,
 {
-    match guild_commanders_usecase.register(register_guild_commander_model).await {
+    match guild_commanders_usecase
+        .register(register_guild_commander_model)
+        .await
+    {
         Ok(guild_commander_id) => (
             StatusCode::CREATED,
             format!(
@@ -41,7 +74,28 @@ where
         ),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Error: {}", e.to_string()).into_response(),
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn public_profile<T1, T2, T3>(
+    State(quest_ratings_usecase): State<Arc<QuestRatingsUsecase<T1, T2, T3>>>,
+    Path(guild_commander_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestRatingsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    match quest_ratings_usecase
+        .guild_commander_profile(guild_commander_id)
+        .await
+    {
+        Ok(profile) => (StatusCode::OK, Json(profile).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
         ),
     }
 }
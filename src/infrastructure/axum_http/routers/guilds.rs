@@ -0,0 +1,232 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+};
+
+use crate::{
+    application::usecases::guilds::GuildsUsecase,
+    domain::{
+        repositories::guilds::GuildsRepository,
+        value_objects::{
+            guild_model::{AddGuildMemberModel, AddGuildModel, ChangeGuildRoleModel},
+            pagination::PaginationQuery,
+            quest_participants::QuestParticipants,
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::{
+            guild_commanders_authorization, quest_participants_authorization,
+        },
+        postgres::{postgres_connection::PgPoolSquad, repositories::guilds::GuildsPostgres},
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let guilds_repository = GuildsPostgres::new(db_pool);
+    let guilds_usecase = Arc::new(GuildsUsecase::new(Arc::new(guilds_repository)));
+
+    let guild_commanders_routes = Router::new()
+        .route("/", post(create))
+        .route_layer(middleware::from_fn(guild_commanders_authorization));
+
+    // จัดการสมาชิกได้ทั้ง adventurer และ guild commander ตาม role ใน guild
+    let members_routes = Router::new()
+        .route("/me", get(memberships))
+        .route("/:guild_id/members", post(add_member))
+        .route("/:guild_id/members/:member_id", patch(change_role))
+        .route("/:guild_id/members/:member_id", delete(remove_member))
+        .route("/:guild_id/leave", post(leave))
+        .route_layer(middleware::from_fn(quest_participants_authorization));
+
+    let public_routes = Router::new()
+        .route("/", get(guilds))
+        .route("/:guild_id", get(view_details))
+        .route("/:guild_id/members", get(members));
+
+    Router::new()
+        .merge(guild_commanders_routes)
+        .merge(members_routes)
+        .merge(public_routes)
+        .with_state(guilds_usecase)
+}
+
+pub async fn create<T>(
+    State(guilds_usecase): State<Arc<GuildsUsecase<T>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Json(add_guild_model): Json<AddGuildModel>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_usecase
+        .create(guild_commander_id, add_guild_model)
+        .await
+    {
+        Ok(guild_id) => (
+            StatusCode::CREATED,
+            format!("Guild Add successfully with ID: {}", guild_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn guilds<T>(
+    State(guilds_usecase): State<Arc<GuildsUsecase<T>>>,
+    Query(pagination): Query<PaginationQuery>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_usecase.guilds(pagination).await {
+        Ok(guilds) => (StatusCode::OK, Json(guilds).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn view_details<T>(
+    State(guilds_usecase): State<Arc<GuildsUsecase<T>>>,
+    Path(guild_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_usecase.view_details(guild_id).await {
+        Ok(guild) => (StatusCode::OK, Json(guild).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn members<T>(
+    State(guilds_usecase): State<Arc<GuildsUsecase<T>>>,
+    Path(guild_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_usecase.members(guild_id).await {
+        Ok(members) => (StatusCode::OK, Json(members).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn memberships<T>(
+    State(guilds_usecase): State<Arc<GuildsUsecase<T>>>,
+    Extension(participant): Extension<QuestParticipants>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_usecase.memberships(participant).await {
+        Ok(memberships) => (StatusCode::OK, Json(memberships).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn add_member<T>(
+    State(guilds_usecase): State<Arc<GuildsUsecase<T>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path(guild_id): Path<i32>,
+    Json(add_guild_member_model): Json<AddGuildMemberModel>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_usecase
+        .add_member(guild_id, participant, add_guild_member_model)
+        .await
+    {
+        Ok(member_id) => (
+            StatusCode::CREATED,
+            format!("Guild member Add successfully with ID: {}", member_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn change_role<T>(
+    State(guilds_usecase): State<Arc<GuildsUsecase<T>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path((guild_id, member_id)): Path<(i32, i32)>,
+    Json(change_guild_role_model): Json<ChangeGuildRoleModel>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_usecase
+        .change_role(guild_id, participant, member_id, change_guild_role_model)
+        .await
+    {
+        Ok(member_id) => (
+            StatusCode::OK,
+            format!("Guild member Edit successfully with ID: {}", member_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn remove_member<T>(
+    State(guilds_usecase): State<Arc<GuildsUsecase<T>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path((guild_id, member_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_usecase
+        .remove_member(guild_id, participant, member_id)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            "Guild member removed successfully".into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn leave<T>(
+    State(guilds_usecase): State<Arc<GuildsUsecase<T>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path(guild_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: GuildsRepository + Send + Sync,
+{
+    match guilds_usecase.leave(guild_id, participant).await {
+        Ok(()) => (StatusCode::OK, "Guild left successfully".into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
@@ -2,8 +2,9 @@ use std::sync::Arc;
 
 use axum::{
     Extension, Json, Router,
+    body::Bytes,
     extract::{Path, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode, header},
     middleware,
     response::IntoResponse,
     routing::post,
@@ -11,15 +12,23 @@ use axum::{
 
 use crate::{
     application::usecases::journey_ledger::JourneyLedgerUsecase,
-    domain::repositories::{
-        journey_ledger::JourneyLedgerRepository, quest_viewing::QuestViewingRepository,
+    domain::{
+        repositories::{
+            achievements::AchievementsRepository, journey_ledger::JourneyLedgerRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::quest_report_model::AddQuestReportModel,
     },
     infrastructure::{
-        axum_http::middlewares::guild_commanders_authorization,
+        axum_http::{
+            etags::{if_match_version, quest_error_status},
+            middlewares::guild_commanders_authorization,
+        },
         postgres::{
             postgres_connection::PgPoolSquad,
             repositories::{
-                journey_ledger::JourneyLedgerPostgres, quest_viewing::QuestViewingPostgres,
+                achievements::AchievementsPostgres, journey_ledger::JourneyLedgerPostgres,
+                quest_viewing::QuestViewingPostgres,
             },
         },
     },
@@ -27,10 +36,12 @@ use crate::{
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let journey_ledger_repository = JourneyLedgerPostgres::new(db_pool.clone());
-    let quest_viewing_repository = QuestViewingPostgres::new(db_pool);
+    let quest_viewing_repository = QuestViewingPostgres::new(db_pool.clone());
+    let achievements_repository = AchievementsPostgres::new(db_pool);
     let journey_ledger_usecase = JourneyLedgerUsecase::new(
         Arc::new(journey_ledger_repository),
         Arc::new(quest_viewing_repository),
+        Arc::new(achievements_repository),
     );
 
     Router::new()
@@ -41,65 +52,169 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .with_state(Arc::new(journey_ledger_usecase))
 }
 
-pub async fn in_journey<T1, T2>(
-    State(journey_ledger_usecase): State<Arc<JourneyLedgerUsecase<T1, T2>>>,
+pub async fn in_journey<T1, T2, T3>(
+    State(journey_ledger_usecase): State<Arc<JourneyLedgerUsecase<T1, T2, T3>>>,
     Extension(guild_commander_id): Extension<i32>,
     Path(quest_id): Path<i32>,
+    headers: HeaderMap,
 ) -> impl IntoResponse
 where
     T1: JourneyLedgerRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: AchievementsRepository + Send + Sync,
 {
+    let expected_version = match if_match_version(&headers) {
+        Ok(expected_version) => expected_version,
+        Err((status, message)) => return (status, message.into_response()),
+    };
+
     match journey_ledger_usecase
-        .in_journey(quest_id, guild_commander_id)
+        .in_journey(quest_id, guild_commander_id, expected_version)
         .await
     {
         Ok(result) => (StatusCode::OK, Json(result).into_response()),
         Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Error: {}", e.to_string()).into_response(),
+            quest_error_status(&e),
+            format!("Error: {}", e).into_response(),
         ),
     }
 }
 
-pub async fn to_completed<T1, T2>(
-    State(journey_ledger_usecase): State<Arc<JourneyLedgerUsecase<T1, T2>>>,
+pub async fn to_completed<T1, T2, T3>(
+    State(journey_ledger_usecase): State<Arc<JourneyLedgerUsecase<T1, T2, T3>>>,
     Extension(guild_commander_id): Extension<i32>,
     Path(quest_id): Path<i32>,
+    headers: HeaderMap,
+    body: Bytes,
 ) -> impl IntoResponse
 where
     T1: JourneyLedgerRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: AchievementsRepository + Send + Sync,
 {
+    let expected_version = match if_match_version(&headers) {
+        Ok(expected_version) => expected_version,
+        Err((status, message)) => return (status, message.into_response()),
+    };
+
+    let add_quest_report_model = match optional_report(&headers, &body) {
+        Ok(add_quest_report_model) => add_quest_report_model,
+        Err(message) => return (StatusCode::BAD_REQUEST, message.into_response()),
+    };
+
     match journey_ledger_usecase
-        .to_completed(quest_id, guild_commander_id)
+        .to_completed(
+            quest_id,
+            guild_commander_id,
+            expected_version,
+            add_quest_report_model,
+        )
         .await
     {
         Ok(result) => (StatusCode::OK, Json(result).into_response()),
         Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Error: {}", e.to_string()).into_response(),
+            quest_error_status(&e),
+            format!("Error: {}", e).into_response(),
         ),
     }
 }
 
-pub async fn to_failed<T1, T2>(
-    State(journey_ledger_usecase): State<Arc<JourneyLedgerUsecase<T1, T2>>>,
+pub async fn to_failed<T1, T2, T3>(
+    State(journey_ledger_usecase): State<Arc<JourneyLedgerUsecase<T1, T2, T3>>>,
     Extension(guild_commander_id): Extension<i32>,
     Path(quest_id): Path<i32>,
+    headers: HeaderMap,
+    body: Bytes,
 ) -> impl IntoResponse
 where
     T1: JourneyLedgerRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
+    T3: AchievementsRepository + Send + Sync,
 {
+    let expected_version = match if_match_version(&headers) {
+        Ok(expected_version) => expected_version,
+        Err((status, message)) => return (status, message.into_response()),
+    };
+
+    let add_quest_report_model = match optional_report(&headers, &body) {
+        Ok(add_quest_report_model) => add_quest_report_model,
+        Err(message) => return (StatusCode::BAD_REQUEST, message.into_response()),
+    };
+
     match journey_ledger_usecase
-        .to_failed(quest_id, guild_commander_id)
+        .to_failed(
+            quest_id,
+            guild_commander_id,
+            expected_version,
+            add_quest_report_model,
+        )
         .await
     {
         Ok(result) => (StatusCode::OK, Json(result).into_response()),
         Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Error: {}", e.to_string()).into_response(),
+            quest_error_status(&e),
+            format!("Error: {}", e).into_response(),
         ),
     }
 }
+
+// ไม่ส่ง body มาเลยถือว่าไม่มีรายงาน แต่ body ที่ส่งมาผิดรูปแบบต้องไม่ถูกทิ้งเงียบ ๆ
+fn optional_report(headers: &HeaderMap, body: &Bytes) -> Result<AddQuestReportModel, String> {
+    if body.is_empty() {
+        return Ok(AddQuestReportModel::default());
+    }
+
+    let is_json = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with("application/json"));
+
+    if !is_json {
+        return Err("Expected request with `Content-Type: application/json`".to_string());
+    }
+
+    Json::<AddQuestReportModel>::from_bytes(body)
+        .map(|Json(add_quest_report_model)| add_quest_report_model)
+        .map_err(|rejection| rejection.body_text())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn json_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_TYPE, "application/json".parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn optional_report_defaults_when_body_is_missing() {
+        let result = optional_report(&HeaderMap::new(), &Bytes::new()).unwrap();
+
+        assert_eq!(result.body, None);
+    }
+
+    #[test]
+    fn optional_report_parses_json_body() {
+        let body = Bytes::from_static(br#"{"body":"Dragon slain"}"#);
+
+        let result = optional_report(&json_headers(), &body).unwrap();
+
+        assert_eq!(result.body.as_deref(), Some("Dragon slain"));
+    }
+
+    #[test]
+    fn optional_report_rejects_malformed_body() {
+        let body = Bytes::from_static(br#"{"body":"#);
+
+        assert!(optional_report(&json_headers(), &body).is_err());
+    }
+
+    #[test]
+    fn optional_report_rejects_body_without_json_content_type() {
+        let body = Bytes::from_static(br#"{"body":"Dragon slain"}"#);
+
+        assert!(optional_report(&HeaderMap::new(), &body).is_err());
+    }
+}
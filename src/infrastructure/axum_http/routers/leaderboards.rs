@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+};
+
+use crate::{
+    application::usecases::leaderboards::LeaderboardsUsecase,
+    domain::{
+        repositories::leaderboards::LeaderboardsRepository,
+        value_objects::{
+            leaderboard_model::{AdventurerLeaderboardQuery, GuildCommanderLeaderboardQuery},
+            pagination::PaginationQuery,
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad, repositories::leaderboards::LeaderboardsPostgres,
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let leaderboards_repository = LeaderboardsPostgres::new(db_pool);
+    let leaderboards_usecase = LeaderboardsUsecase::new(Arc::new(leaderboards_repository));
+
+    Router::new()
+        .route("/adventurers", get(adventurers))
+        .route("/guild-commanders", get(guild_commanders))
+        .with_state(Arc::new(leaderboards_usecase))
+}
+
+pub async fn adventurers<T>(
+    State(leaderboards_usecase): State<Arc<LeaderboardsUsecase<T>>>,
+    Query(leaderboard_query): Query<AdventurerLeaderboardQuery>,
+    Query(pagination): Query<PaginationQuery>,
+) -> impl IntoResponse
+where
+    T: LeaderboardsRepository + Send + Sync,
+{
+    match leaderboards_usecase
+        .adventurers(leaderboard_query, pagination)
+        .await
+    {
+        Ok(leaderboard) => (StatusCode::OK, Json(leaderboard).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn guild_commanders<T>(
+    State(leaderboards_usecase): State<Arc<LeaderboardsUsecase<T>>>,
+    Query(leaderboard_query): Query<GuildCommanderLeaderboardQuery>,
+    Query(pagination): Query<PaginationQuery>,
+) -> impl IntoResponse
+where
+    T: LeaderboardsRepository + Send + Sync,
+{
+    match leaderboards_usecase
+        .guild_commanders(leaderboard_query, pagination)
+        .await
+    {
+        Ok(leaderboard) => (StatusCode::OK, Json(leaderboard).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
@@ -1,7 +1,17 @@
+pub mod admin;
 pub mod adventures;
+pub mod authentication;
+pub mod crew_invitations;
 pub mod crew_swithboard;
 pub mod guild_commanders;
+pub mod guilds;
 pub mod journey_ledger;
+pub mod leaderboards;
+pub mod quest_checkpoints;
+pub mod quest_comments;
 pub mod quest_ops;
+pub mod quest_ratings;
+pub mod quest_recurrences;
+pub mod quest_reports;
+pub mod quest_templates;
 pub mod quest_viewing;
-pub mod authentication;
\ No newline at end of file
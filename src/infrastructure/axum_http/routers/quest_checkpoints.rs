@@ -0,0 +1,217 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+};
+
+use crate::{
+    application::usecases::quest_checkpoints::QuestCheckpointsUsecase,
+    domain::{
+        repositories::{
+            crew_swithboard::CrewSwithboardRepository,
+            quest_checkpoints::QuestCheckpointsRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_checkpoint_model::{
+                AddQuestCheckpointModel, CompleteQuestCheckpointModel, EditQuestCheckpointModel,
+            },
+            quest_participants::QuestParticipants,
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::quest_participants_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                crew_swithboard::CrewSwithboardPostgres,
+                quest_checkpoints::QuestCheckpointsPostgres, quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_checkpoints_repository = QuestCheckpointsPostgres::new(db_pool.clone());
+    let quest_viewing_repository = QuestViewingPostgres::new(db_pool.clone());
+    let crew_swithboard_repository = CrewSwithboardPostgres::new(db_pool);
+    let quest_checkpoints_usecase = QuestCheckpointsUsecase::new(
+        Arc::new(quest_checkpoints_repository),
+        Arc::new(quest_viewing_repository),
+        Arc::new(crew_swithboard_repository),
+    );
+
+    Router::new()
+        .route("/:quest_id", get(checkpoints))
+        .route("/:quest_id", post(add))
+        .route("/:quest_id/:checkpoint_id", patch(edit))
+        .route("/:quest_id/:checkpoint_id", delete(remove))
+        .route("/:quest_id/:checkpoint_id/complete", post(complete))
+        .route("/:quest_id/:checkpoint_id/complete", delete(reopen))
+        .route_layer(middleware::from_fn(quest_participants_authorization))
+        .with_state(Arc::new(quest_checkpoints_usecase))
+}
+
+pub async fn checkpoints<T1, T2, T3>(
+    State(quest_checkpoints_usecase): State<Arc<QuestCheckpointsUsecase<T1, T2, T3>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestCheckpointsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    match quest_checkpoints_usecase
+        .checkpoints(quest_id, participant)
+        .await
+    {
+        Ok(checkpoints) => (StatusCode::OK, Json(checkpoints).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn add<T1, T2, T3>(
+    State(quest_checkpoints_usecase): State<Arc<QuestCheckpointsUsecase<T1, T2, T3>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path(quest_id): Path<i32>,
+    Json(add_quest_checkpoint_model): Json<AddQuestCheckpointModel>,
+) -> impl IntoResponse
+where
+    T1: QuestCheckpointsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    match quest_checkpoints_usecase
+        .add(quest_id, participant, add_quest_checkpoint_model)
+        .await
+    {
+        Ok(checkpoint_id) => (
+            StatusCode::CREATED,
+            format!("Checkpoint Add successfully with ID: {}", checkpoint_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn edit<T1, T2, T3>(
+    State(quest_checkpoints_usecase): State<Arc<QuestCheckpointsUsecase<T1, T2, T3>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path((quest_id, checkpoint_id)): Path<(i32, i32)>,
+    Json(edit_quest_checkpoint_model): Json<EditQuestCheckpointModel>,
+) -> impl IntoResponse
+where
+    T1: QuestCheckpointsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    match quest_checkpoints_usecase
+        .edit(
+            quest_id,
+            checkpoint_id,
+            participant,
+            edit_quest_checkpoint_model,
+        )
+        .await
+    {
+        Ok(checkpoint_id) => (
+            StatusCode::OK,
+            format!("Checkpoint Edit successfully with ID: {}", checkpoint_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn remove<T1, T2, T3>(
+    State(quest_checkpoints_usecase): State<Arc<QuestCheckpointsUsecase<T1, T2, T3>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path((quest_id, checkpoint_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestCheckpointsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    match quest_checkpoints_usecase
+        .remove(quest_id, checkpoint_id, participant)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Checkpoint Delete successfully with ID: {}", checkpoint_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn complete<T1, T2, T3>(
+    State(quest_checkpoints_usecase): State<Arc<QuestCheckpointsUsecase<T1, T2, T3>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path((quest_id, checkpoint_id)): Path<(i32, i32)>,
+    Json(complete_quest_checkpoint_model): Json<CompleteQuestCheckpointModel>,
+) -> impl IntoResponse
+where
+    T1: QuestCheckpointsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    match quest_checkpoints_usecase
+        .complete(
+            quest_id,
+            checkpoint_id,
+            participant,
+            complete_quest_checkpoint_model,
+        )
+        .await
+    {
+        Ok(checkpoint_id) => (
+            StatusCode::OK,
+            format!("Checkpoint Done successfully with ID: {}", checkpoint_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn reopen<T1, T2, T3>(
+    State(quest_checkpoints_usecase): State<Arc<QuestCheckpointsUsecase<T1, T2, T3>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path((quest_id, checkpoint_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestCheckpointsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    match quest_checkpoints_usecase
+        .reopen(quest_id, checkpoint_id, participant)
+        .await
+    {
+        Ok(checkpoint_id) => (
+            StatusCode::OK,
+            format!("Checkpoint Reopen successfully with ID: {}", checkpoint_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+};
+
+use crate::{
+    application::usecases::quest_comments::QuestCommentsUsecase,
+    domain::{
+        repositories::{
+            crew_swithboard::CrewSwithboardRepository, quest_comments::QuestCommentsRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            pagination::PaginationQuery,
+            quest_comment_model::{AddQuestCommentModel, EditQuestCommentModel},
+            quest_participants::QuestParticipants,
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::quest_participants_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                crew_swithboard::CrewSwithboardPostgres, quest_comments::QuestCommentsPostgres,
+                quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_comments_repository = QuestCommentsPostgres::new(db_pool.clone());
+    let quest_viewing_repository = QuestViewingPostgres::new(db_pool.clone());
+    let crew_swithboard_repository = CrewSwithboardPostgres::new(db_pool);
+    let quest_comments_usecase = QuestCommentsUsecase::new(
+        Arc::new(quest_comments_repository),
+        Arc::new(quest_viewing_repository),
+        Arc::new(crew_swithboard_repository),
+    );
+
+    Router::new()
+        .route("/:quest_id", get(comments))
+        .route("/:quest_id", post(add))
+        .route("/:quest_id/:comment_id", patch(edit))
+        .route("/:quest_id/:comment_id", delete(remove))
+        .route_layer(middleware::from_fn(quest_participants_authorization))
+        .with_state(Arc::new(quest_comments_usecase))
+}
+
+pub async fn comments<T1, T2, T3>(
+    State(quest_comments_usecase): State<Arc<QuestCommentsUsecase<T1, T2, T3>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path(quest_id): Path<i32>,
+    Query(pagination): Query<PaginationQuery>,
+) -> impl IntoResponse
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    match quest_comments_usecase
+        .comments(quest_id, participant, pagination)
+        .await
+    {
+        Ok(comments) => (StatusCode::OK, Json(comments).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn add<T1, T2, T3>(
+    State(quest_comments_usecase): State<Arc<QuestCommentsUsecase<T1, T2, T3>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path(quest_id): Path<i32>,
+    Json(add_quest_comment_model): Json<AddQuestCommentModel>,
+) -> impl IntoResponse
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    match quest_comments_usecase
+        .add(quest_id, participant, add_quest_comment_model)
+        .await
+    {
+        Ok(comment_id) => (
+            StatusCode::CREATED,
+            format!("Comment Add successfully with ID: {}", comment_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn edit<T1, T2, T3>(
+    State(quest_comments_usecase): State<Arc<QuestCommentsUsecase<T1, T2, T3>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path((quest_id, comment_id)): Path<(i32, i32)>,
+    Json(edit_quest_comment_model): Json<EditQuestCommentModel>,
+) -> impl IntoResponse
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    match quest_comments_usecase
+        .edit(quest_id, comment_id, participant, edit_quest_comment_model)
+        .await
+    {
+        Ok(comment_id) => (
+            StatusCode::OK,
+            format!("Comment Edit successfully with ID: {}", comment_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn remove<T1, T2, T3>(
+    State(quest_comments_usecase): State<Arc<QuestCommentsUsecase<T1, T2, T3>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path((quest_id, comment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestCommentsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    match quest_comments_usecase
+        .remove(quest_id, comment_id, participant)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Comment Delete successfully with ID: {}", comment_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
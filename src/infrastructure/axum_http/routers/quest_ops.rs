@@ -3,20 +3,26 @@ use std::sync::Arc;
 use axum::{
     Extension, Json, Router,
     extract::{Path, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     middleware,
     response::IntoResponse,
-    routing::{delete, patch, post},
+    routing::{delete, get, patch, post, put},
 };
 
 use crate::{
     application::usecases::quest_ops::QuestOpsUsecase,
     domain::{
         repositories::{quest_ops::QuestOpsRepository, quest_viewing::QuestViewingRepository},
-        value_objects::quest_model::{AddQuestModel, EditQuestModel},
+        value_objects::{
+            quest_model::{AddQuestModel, EditQuestModel},
+            quest_tag_model::SetQuestTagsModel,
+        },
     },
     infrastructure::{
-        axum_http::middlewares::guild_commanders_authorization,
+        axum_http::{
+            etags::{if_match_version, quest_error_status},
+            middlewares::guild_commanders_authorization,
+        },
         postgres::{
             postgres_connection::PgPoolSquad,
             repositories::{quest_ops::QuestOpsPostgres, quest_viewing::QuestViewingPostgres},
@@ -36,6 +42,14 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .route("/", post(quest_add))
         .route("/:quest_id", patch(quest_edit))
         .route("/:quest_id", delete(quest_remove))
+        .route("/:quest_id/tags", put(quest_set_tags))
+        .route("/:quest_id/clone", post(quest_clone))
+        .route("/deleted", get(deleted_quests))
+        .route("/:quest_id/restore", post(quest_restore))
+        .route(
+            "/:quest_id/revisions/:revision_number/revert",
+            post(quest_revert),
+        )
         .route_layer(middleware::from_fn(guild_commanders_authorization))
         .with_state(Arc::new(quest_ops_usecase))
 }
@@ -59,7 +73,7 @@ where
         ),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Error: {}", e.to_string()).into_response(),
+            format!("Error: {}", e).into_response(),
         ),
     }
 }
@@ -68,14 +82,25 @@ pub async fn quest_edit<T1, T2>(
     State(quest_ops_usecase): State<Arc<QuestOpsUsecase<T1, T2>>>,
     Extension(guild_commander_id): Extension<i32>,
     Path(quest_id): Path<i32>,
+    headers: HeaderMap,
     Json(edit_quest_model): Json<EditQuestModel>,
 ) -> impl IntoResponse
 where
     T1: QuestOpsRepository + Send + Sync,
     T2: QuestViewingRepository + Send + Sync,
 {
+    let expected_version = match if_match_version(&headers) {
+        Ok(expected_version) => expected_version,
+        Err((status, message)) => return (status, message.into_response()),
+    };
+
     match quest_ops_usecase
-        .edit(quest_id, guild_commander_id, edit_quest_model)
+        .edit(
+            quest_id,
+            guild_commander_id,
+            expected_version,
+            edit_quest_model,
+        )
         .await
     {
         Ok(quest_id) => (
@@ -83,8 +108,8 @@ where
             format!("Quest Edit successfully with ID: {}", quest_id).into_response(),
         ),
         Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Error: {}", e.to_string()).into_response(),
+            quest_error_status(&e),
+            format!("Error: {}", e).into_response(),
         ),
     }
 }
@@ -105,7 +130,136 @@ where
         ),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Error: {}", e.to_string()).into_response(),
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn quest_set_tags<T1, T2>(
+    State(quest_ops_usecase): State<Arc<QuestOpsUsecase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+    Json(set_quest_tags_model): Json<SetQuestTagsModel>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_usecase
+        .set_tags(quest_id, guild_commander_id, set_quest_tags_model)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Quest tags updated successfully with ID: {}", quest_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn quest_clone<T1, T2>(
+    State(quest_ops_usecase): State<Arc<QuestOpsUsecase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_usecase
+        .clone_quest(quest_id, guild_commander_id)
+        .await
+    {
+        Ok(new_quest_id) => (
+            StatusCode::CREATED,
+            format!("Quest Clone successfully with ID: {}", new_quest_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn deleted_quests<T1, T2>(
+    State(quest_ops_usecase): State<Arc<QuestOpsUsecase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_usecase.deleted_quests(guild_commander_id).await {
+        Ok(quests_model) => (StatusCode::OK, Json(quests_model).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn quest_restore<T1, T2>(
+    State(quest_ops_usecase): State<Arc<QuestOpsUsecase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    match quest_ops_usecase
+        .restore(quest_id, guild_commander_id)
+        .await
+    {
+        Ok(quest_id) => (
+            StatusCode::OK,
+            format!("Quest Restore successfully with ID: {}", quest_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn quest_revert<T1, T2>(
+    State(quest_ops_usecase): State<Arc<QuestOpsUsecase<T1, T2>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path((quest_id, revision_number)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> impl IntoResponse
+where
+    T1: QuestOpsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+{
+    let expected_version = match if_match_version(&headers) {
+        Ok(expected_version) => expected_version,
+        Err((status, message)) => return (status, message.into_response()),
+    };
+
+    match quest_ops_usecase
+        .revert(
+            quest_id,
+            guild_commander_id,
+            revision_number,
+            expected_version,
+        )
+        .await
+    {
+        Ok(quest_id) => (
+            StatusCode::OK,
+            format!(
+                "Quest reverted to revision {} successfully with ID: {}",
+                revision_number, quest_id
+            )
+            .into_response(),
+        ),
+        Err(e) => (
+            quest_error_status(&e),
+            format!("Error: {}", e).into_response(),
         ),
     }
 }
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use crate::{
+    application::usecases::quest_ratings::QuestRatingsUsecase,
+    domain::{
+        repositories::{
+            crew_swithboard::CrewSwithboardRepository, quest_ratings::QuestRatingsRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_participants::QuestParticipants, quest_rating_model::AddQuestRatingModel,
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::quest_participants_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                crew_swithboard::CrewSwithboardPostgres, quest_ratings::QuestRatingsPostgres,
+                quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_ratings_repository = QuestRatingsPostgres::new(db_pool.clone());
+    let quest_viewing_repository = QuestViewingPostgres::new(db_pool.clone());
+    let crew_swithboard_repository = CrewSwithboardPostgres::new(db_pool);
+    let quest_ratings_usecase = QuestRatingsUsecase::new(
+        Arc::new(quest_ratings_repository),
+        Arc::new(quest_viewing_repository),
+        Arc::new(crew_swithboard_repository),
+    );
+
+    Router::new()
+        .route("/:quest_id", get(ratings))
+        .route("/:quest_id", post(rate))
+        .route_layer(middleware::from_fn(quest_participants_authorization))
+        .with_state(Arc::new(quest_ratings_usecase))
+}
+
+pub async fn ratings<T1, T2, T3>(
+    State(quest_ratings_usecase): State<Arc<QuestRatingsUsecase<T1, T2, T3>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestRatingsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    match quest_ratings_usecase.ratings(quest_id, participant).await {
+        Ok(ratings) => (StatusCode::OK, Json(ratings).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn rate<T1, T2, T3>(
+    State(quest_ratings_usecase): State<Arc<QuestRatingsUsecase<T1, T2, T3>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path(quest_id): Path<i32>,
+    Json(add_quest_rating_model): Json<AddQuestRatingModel>,
+) -> impl IntoResponse
+where
+    T1: QuestRatingsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+{
+    match quest_ratings_usecase
+        .rate(quest_id, participant, add_quest_rating_model)
+        .await
+    {
+        Ok(rating_id) => (
+            StatusCode::CREATED,
+            format!("Rating Add successfully with ID: {}", rating_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
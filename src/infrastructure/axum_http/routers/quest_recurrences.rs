@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+
+use crate::{
+    application::usecases::quest_recurrences::QuestRecurrencesUsecase,
+    domain::{
+        repositories::{
+            quest_ops::QuestOpsRepository, quest_recurrences::QuestRecurrencesRepository,
+            quest_templates::QuestTemplatesRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::quest_recurrence_model::AddQuestRecurrenceModel,
+    },
+    infrastructure::{
+        axum_http::middlewares::guild_commanders_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                quest_ops::QuestOpsPostgres, quest_recurrences::QuestRecurrencesPostgres,
+                quest_templates::QuestTemplatesPostgres, quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_recurrences_repository = QuestRecurrencesPostgres::new(db_pool.clone());
+    let quest_templates_repository = QuestTemplatesPostgres::new(db_pool.clone());
+    let quest_ops_repository = QuestOpsPostgres::new(db_pool.clone());
+    let quest_viewing_repository = QuestViewingPostgres::new(db_pool);
+    let quest_recurrences_usecase = QuestRecurrencesUsecase::new(
+        Arc::new(quest_recurrences_repository),
+        Arc::new(quest_templates_repository),
+        Arc::new(quest_ops_repository),
+        Arc::new(quest_viewing_repository),
+    );
+
+    Router::new()
+        .route("/", post(add))
+        .route("/", get(by_guild_commander_id))
+        .route("/:quest_recurrence_id/pause", post(pause))
+        .route("/:quest_recurrence_id/resume", post(resume))
+        .route("/:quest_recurrence_id", delete(stop))
+        .route_layer(middleware::from_fn(guild_commanders_authorization))
+        .with_state(Arc::new(quest_recurrences_usecase))
+}
+
+pub async fn add<T1, T2, T3, T4>(
+    State(quest_recurrences_usecase): State<Arc<QuestRecurrencesUsecase<T1, T2, T3, T4>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Json(add_quest_recurrence_model): Json<AddQuestRecurrenceModel>,
+) -> impl IntoResponse
+where
+    T1: QuestRecurrencesRepository + Send + Sync,
+    T2: QuestTemplatesRepository + Send + Sync,
+    T3: QuestOpsRepository + Send + Sync,
+    T4: QuestViewingRepository + Send + Sync,
+{
+    match quest_recurrences_usecase
+        .add(guild_commander_id, add_quest_recurrence_model)
+        .await
+    {
+        Ok(quest_recurrence_id) => (
+            StatusCode::CREATED,
+            format!(
+                "Quest recurrence Add successfully with ID: {}",
+                quest_recurrence_id
+            )
+            .into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn by_guild_commander_id<T1, T2, T3, T4>(
+    State(quest_recurrences_usecase): State<Arc<QuestRecurrencesUsecase<T1, T2, T3, T4>>>,
+    Extension(guild_commander_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestRecurrencesRepository + Send + Sync,
+    T2: QuestTemplatesRepository + Send + Sync,
+    T3: QuestOpsRepository + Send + Sync,
+    T4: QuestViewingRepository + Send + Sync,
+{
+    match quest_recurrences_usecase
+        .by_guild_commander_id(guild_commander_id)
+        .await
+    {
+        Ok(quest_recurrences) => (StatusCode::OK, Json(quest_recurrences).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn pause<T1, T2, T3, T4>(
+    State(quest_recurrences_usecase): State<Arc<QuestRecurrencesUsecase<T1, T2, T3, T4>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_recurrence_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestRecurrencesRepository + Send + Sync,
+    T2: QuestTemplatesRepository + Send + Sync,
+    T3: QuestOpsRepository + Send + Sync,
+    T4: QuestViewingRepository + Send + Sync,
+{
+    match quest_recurrences_usecase
+        .pause(quest_recurrence_id, guild_commander_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Quest recurrence Pause successfully with ID: {}",
+                quest_recurrence_id
+            )
+            .into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn resume<T1, T2, T3, T4>(
+    State(quest_recurrences_usecase): State<Arc<QuestRecurrencesUsecase<T1, T2, T3, T4>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_recurrence_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestRecurrencesRepository + Send + Sync,
+    T2: QuestTemplatesRepository + Send + Sync,
+    T3: QuestOpsRepository + Send + Sync,
+    T4: QuestViewingRepository + Send + Sync,
+{
+    match quest_recurrences_usecase
+        .resume(quest_recurrence_id, guild_commander_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Quest recurrence Resume successfully with ID: {}",
+                quest_recurrence_id
+            )
+            .into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn stop<T1, T2, T3, T4>(
+    State(quest_recurrences_usecase): State<Arc<QuestRecurrencesUsecase<T1, T2, T3, T4>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_recurrence_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestRecurrencesRepository + Send + Sync,
+    T2: QuestTemplatesRepository + Send + Sync,
+    T3: QuestOpsRepository + Send + Sync,
+    T4: QuestViewingRepository + Send + Sync,
+{
+    match quest_recurrences_usecase
+        .stop(quest_recurrence_id, guild_commander_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Quest recurrence Stop successfully with ID: {}",
+                quest_recurrence_id
+            )
+            .into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
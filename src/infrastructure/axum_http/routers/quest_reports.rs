@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{DefaultBodyLimit, Multipart, Path, State},
+    http::{StatusCode, header},
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+
+use crate::{
+    application::usecases::quest_reports::QuestReportsUsecase,
+    config::config_loader::get_quest_attachments_env,
+    domain::{
+        repositories::{
+            attachment_storage::AttachmentStorage, crew_swithboard::CrewSwithboardRepository,
+            quest_reports::QuestReportsRepository, quest_viewing::QuestViewingRepository,
+        },
+        value_objects::{
+            quest_participants::QuestParticipants, quest_report_model::UploadQuestAttachmentModel,
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::quest_participants_authorization,
+        local_storage::LocalAttachmentStorage,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                crew_swithboard::CrewSwithboardPostgres, quest_reports::QuestReportsPostgres,
+                quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_attachments =
+        get_quest_attachments_env().expect("Quest attachments config is invalid");
+
+    let quest_reports_repository = QuestReportsPostgres::new(db_pool.clone());
+    let quest_viewing_repository = QuestViewingPostgres::new(db_pool.clone());
+    let crew_swithboard_repository = CrewSwithboardPostgres::new(db_pool);
+    let attachment_storage = LocalAttachmentStorage::new(quest_attachments.storage_dir);
+    let quest_reports_usecase = QuestReportsUsecase::new(
+        Arc::new(quest_reports_repository),
+        Arc::new(quest_viewing_repository),
+        Arc::new(crew_swithboard_repository),
+        Arc::new(attachment_storage),
+    );
+
+    Router::new()
+        .route("/:quest_id", get(evidence))
+        .route("/:quest_id/attachments", post(upload))
+        .route("/:quest_id/attachments/:attachment_id", get(download))
+        .route(
+            "/:quest_id/attachments/:attachment_id",
+            delete(remove_attachment),
+        )
+        .route_layer(middleware::from_fn(quest_participants_authorization))
+        // ขนาดไฟล์คุมด้วย RequestBodyLimitLayer ของ server กับ config ของไฟล์แนบแทน
+        .layer(DefaultBodyLimit::disable())
+        .with_state(Arc::new(quest_reports_usecase))
+}
+
+pub async fn evidence<T1, T2, T3, T4>(
+    State(quest_reports_usecase): State<Arc<QuestReportsUsecase<T1, T2, T3, T4>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestReportsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+    T4: AttachmentStorage + Send + Sync,
+{
+    match quest_reports_usecase.evidence(quest_id, participant).await {
+        Ok(evidence) => (StatusCode::OK, Json(evidence).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn upload<T1, T2, T3, T4>(
+    State(quest_reports_usecase): State<Arc<QuestReportsUsecase<T1, T2, T3, T4>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path(quest_id): Path<i32>,
+    multipart: Multipart,
+) -> impl IntoResponse
+where
+    T1: QuestReportsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+    T4: AttachmentStorage + Send + Sync,
+{
+    let upload_quest_attachment_model = match read_file_field(multipart).await {
+        Ok(upload_quest_attachment_model) => upload_quest_attachment_model,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("Error: {}", e).into_response(),
+            );
+        }
+    };
+
+    match quest_reports_usecase
+        .upload(quest_id, participant, upload_quest_attachment_model)
+        .await
+    {
+        Ok(attachment_id) => (
+            StatusCode::CREATED,
+            format!("Attachment Upload successfully with ID: {}", attachment_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn download<T1, T2, T3, T4>(
+    State(quest_reports_usecase): State<Arc<QuestReportsUsecase<T1, T2, T3, T4>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path((quest_id, attachment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestReportsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+    T4: AttachmentStorage + Send + Sync,
+{
+    match quest_reports_usecase
+        .download(quest_id, attachment_id, participant)
+        .await
+    {
+        Ok(attachment) => (
+            StatusCode::OK,
+            (
+                [
+                    (header::CONTENT_TYPE, attachment.content_type),
+                    (
+                        header::CONTENT_DISPOSITION,
+                        format!(
+                            "attachment; filename=\"{}\"",
+                            attachment.file_name.replace(['"', '\\', '\r', '\n'], "_")
+                        ),
+                    ),
+                ],
+                attachment.content,
+            )
+                .into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn remove_attachment<T1, T2, T3, T4>(
+    State(quest_reports_usecase): State<Arc<QuestReportsUsecase<T1, T2, T3, T4>>>,
+    Extension(participant): Extension<QuestParticipants>,
+    Path((quest_id, attachment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: QuestReportsRepository + Send + Sync,
+    T2: QuestViewingRepository + Send + Sync,
+    T3: CrewSwithboardRepository + Send + Sync,
+    T4: AttachmentStorage + Send + Sync,
+{
+    match quest_reports_usecase
+        .remove_attachment(quest_id, attachment_id, participant)
+        .await
+    {
+        Ok(()) => (
+            StatusCode::OK,
+            format!("Attachment Delete successfully with ID: {}", attachment_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+async fn read_file_field(mut multipart: Multipart) -> anyhow::Result<UploadQuestAttachmentModel> {
+    while let Some(field) = multipart.next_field().await? {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let file_name = field
+            .file_name()
+            .map(|file_name| file_name.to_string())
+            .ok_or_else(|| anyhow::anyhow!("File name is required"))?;
+        let content_type = field
+            .content_type()
+            .map(|content_type| content_type.to_string())
+            .unwrap_or("application/octet-stream".to_string());
+        let content = field.bytes().await?.to_vec();
+
+        return Ok(UploadQuestAttachmentModel {
+            file_name,
+            content_type,
+            content,
+        });
+    }
+
+    Err(anyhow::anyhow!("Multipart field file is required"))
+}
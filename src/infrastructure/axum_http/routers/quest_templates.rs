@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+
+use crate::{
+    application::usecases::quest_templates::QuestTemplatesUsecase,
+    domain::{
+        repositories::{
+            quest_ops::QuestOpsRepository, quest_templates::QuestTemplatesRepository,
+            quest_viewing::QuestViewingRepository,
+        },
+        value_objects::quest_template_model::{
+            AddQuestTemplateModel, CreateQuestFromTemplateModel,
+        },
+    },
+    infrastructure::{
+        axum_http::middlewares::guild_commanders_authorization,
+        postgres::{
+            postgres_connection::PgPoolSquad,
+            repositories::{
+                quest_ops::QuestOpsPostgres, quest_templates::QuestTemplatesPostgres,
+                quest_viewing::QuestViewingPostgres,
+            },
+        },
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let quest_templates_repository = QuestTemplatesPostgres::new(db_pool.clone());
+    let quest_ops_repository = QuestOpsPostgres::new(db_pool.clone());
+    let quest_viewing_repository = QuestViewingPostgres::new(db_pool);
+    let quest_templates_usecase = QuestTemplatesUsecase::new(
+        Arc::new(quest_templates_repository),
+        Arc::new(quest_ops_repository),
+        Arc::new(quest_viewing_repository),
+    );
+
+    Router::new()
+        .route("/", post(add))
+        .route("/", get(by_guild_commander_id))
+        .route("/:quest_template_id", get(view_details))
+        .route("/:quest_template_id", delete(remove))
+        .route("/:quest_template_id/quests", post(create_quest))
+        .route_layer(middleware::from_fn(guild_commanders_authorization))
+        .with_state(Arc::new(quest_templates_usecase))
+}
+
+pub async fn add<T1, T2, T3>(
+    State(quest_templates_usecase): State<Arc<QuestTemplatesUsecase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Json(add_quest_template_model): Json<AddQuestTemplateModel>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    match quest_templates_usecase
+        .add(guild_commander_id, add_quest_template_model)
+        .await
+    {
+        Ok(quest_template_id) => (
+            StatusCode::CREATED,
+            format!(
+                "Quest template Add successfully with ID: {}",
+                quest_template_id
+            )
+            .into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn by_guild_commander_id<T1, T2, T3>(
+    State(quest_templates_usecase): State<Arc<QuestTemplatesUsecase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    match quest_templates_usecase
+        .by_guild_commander_id(guild_commander_id)
+        .await
+    {
+        Ok(quest_templates) => (StatusCode::OK, Json(quest_templates).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn view_details<T1, T2, T3>(
+    State(quest_templates_usecase): State<Arc<QuestTemplatesUsecase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    match quest_templates_usecase
+        .view_details(quest_template_id, guild_commander_id)
+        .await
+    {
+        Ok(quest_template) => (StatusCode::OK, Json(quest_template).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn remove<T1, T2, T3>(
+    State(quest_templates_usecase): State<Arc<QuestTemplatesUsecase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    match quest_templates_usecase
+        .remove(quest_template_id, guild_commander_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Quest template Delete successfully with ID: {}",
+                quest_template_id
+            )
+            .into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn create_quest<T1, T2, T3>(
+    State(quest_templates_usecase): State<Arc<QuestTemplatesUsecase<T1, T2, T3>>>,
+    Extension(guild_commander_id): Extension<i32>,
+    Path(quest_template_id): Path<i32>,
+    Json(create_quest_from_template_model): Json<CreateQuestFromTemplateModel>,
+) -> impl IntoResponse
+where
+    T1: QuestTemplatesRepository + Send + Sync,
+    T2: QuestOpsRepository + Send + Sync,
+    T3: QuestViewingRepository + Send + Sync,
+{
+    match quest_templates_usecase
+        .create_quest(
+            quest_template_id,
+            guild_commander_id,
+            create_quest_from_template_model,
+        )
+        .await
+    {
+        Ok(quest_id) => (
+            StatusCode::CREATED,
+            format!("Quest Add successfully with ID: {}", quest_id).into_response(),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
@@ -1,9 +1,9 @@
 use std::sync::Arc;
 
 use axum::{
-    Json, Router,
+    Extension, Json, Router,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{StatusCode, header},
     middleware,
     response::IntoResponse,
     routing::get,
@@ -13,10 +13,13 @@ use crate::{
     application::usecases::quest_viewing::QuestViewingUsecase,
     domain::{
         repositories::quest_viewing::QuestViewingRepository,
-        value_objects::board_checking_filter::BoardCheckingFilter,
+        value_objects::{
+            board_checking_filter::BoardCheckingFilter, quest_participants::QuestParticipants,
+            quest_revision_model::QuestRevisionDiffQuery,
+        },
     },
     infrastructure::{
-        axum_http::middlewares::adventures_authorization,
+        axum_http::{etags::quest_etag, middlewares::quest_participants_identification},
         postgres::{
             postgres_connection::PgPoolSquad, repositories::quest_viewing::QuestViewingPostgres,
         },
@@ -27,9 +30,17 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let quest_viewing_repository = QuestViewingPostgres::new(db_pool);
     let quest_viewing_usecase = QuestViewingUsecase::new(Arc::new(quest_viewing_repository));
 
-    Router::new()
+    // quest แบบ GuildOnly ต้องรู้ว่าใครเป็นคนดู
+    let viewer_routes = Router::new()
         .route("/:quest_id", get(view_details))
+        .route("/:quest_id/revisions", get(revisions))
+        .route("/:quest_id/revisions/diff", get(revisions_diff))
         .route("/board-checking", get(board_checking))
+        .route_layer(middleware::from_fn(quest_participants_identification));
+
+    Router::new()
+        .merge(viewer_routes)
+        .route("/tags", get(tags))
         .route("/adventurers-count", get(adventurers_counting_by_quest_id))
         // .route_layer(middleware::from_fn(adventures_authorization))
         .with_state(Arc::new(quest_viewing_usecase))
@@ -37,32 +48,45 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
 
 pub async fn view_details<T>(
     State(quest_viewing_usecase): State<Arc<QuestViewingUsecase<T>>>,
+    viewer: Option<Extension<QuestParticipants>>,
     Path(quest_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T: QuestViewingRepository + Send + Sync,
 {
-    match quest_viewing_usecase.view_details(quest_id).await {
-        Ok(quest_model) => (StatusCode::OK, Json(quest_model).into_response()),
+    let viewer = viewer.map(|Extension(viewer)| viewer);
+
+    match quest_viewing_usecase.view_details(quest_id, viewer).await {
+        Ok(quest_model) => (
+            StatusCode::OK,
+            (
+                [(header::ETAG, quest_etag(quest_model.version))],
+                Json(quest_model),
+            )
+                .into_response(),
+        ),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Error: {}", e.to_string()).into_response(),
+            format!("Error: {}", e).into_response(),
         ),
     }
 }
 
 pub async fn board_checking<T>(
     State(quest_viewing_usecase): State<Arc<QuestViewingUsecase<T>>>,
+    viewer: Option<Extension<QuestParticipants>>,
     Query(filter): Query<BoardCheckingFilter>,
 ) -> impl IntoResponse
 where
     T: QuestViewingRepository + Send + Sync,
 {
-    match quest_viewing_usecase.board_checking(&filter).await {
+    let viewer = viewer.map(|Extension(viewer)| viewer);
+
+    match quest_viewing_usecase.board_checking(&filter, viewer).await {
         Ok(quest_models) => (StatusCode::OK, Json(quest_models).into_response()),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Error: {}", e.to_string()).into_response(),
+            format!("Error: {}", e).into_response(),
         ),
     }
 }
@@ -81,7 +105,64 @@ where
         Ok(adventurers_count) => (StatusCode::OK, Json(adventurers_count).into_response()),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Error: {}", e.to_string()).into_response(),
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn tags<T>(
+    State(quest_viewing_usecase): State<Arc<QuestViewingUsecase<T>>>,
+) -> impl IntoResponse
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    match quest_viewing_usecase.tags().await {
+        Ok(tag_models) => (StatusCode::OK, Json(tag_models).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn revisions<T>(
+    State(quest_viewing_usecase): State<Arc<QuestViewingUsecase<T>>>,
+    viewer: Option<Extension<QuestParticipants>>,
+    Path(quest_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    let viewer = viewer.map(|Extension(viewer)| viewer);
+
+    match quest_viewing_usecase.revisions(quest_id, viewer).await {
+        Ok(revisions) => (StatusCode::OK, Json(revisions).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
+        ),
+    }
+}
+
+pub async fn revisions_diff<T>(
+    State(quest_viewing_usecase): State<Arc<QuestViewingUsecase<T>>>,
+    viewer: Option<Extension<QuestParticipants>>,
+    Path(quest_id): Path<i32>,
+    Query(diff_query): Query<QuestRevisionDiffQuery>,
+) -> impl IntoResponse
+where
+    T: QuestViewingRepository + Send + Sync,
+{
+    let viewer = viewer.map(|Extension(viewer)| viewer);
+
+    match quest_viewing_usecase
+        .revisions_diff(quest_id, diff_query.from, diff_query.to, viewer)
+        .await
+    {
+        Ok(revision_diff) => (StatusCode::OK, Json(revision_diff).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error: {}", e).into_response(),
         ),
     }
 }
@@ -0,0 +1,66 @@
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::Result;
+use axum::async_trait;
+
+use crate::domain::repositories::attachment_storage::AttachmentStorage;
+
+pub struct LocalAttachmentStorage {
+    root_dir: PathBuf,
+}
+
+impl LocalAttachmentStorage {
+    pub fn new(root_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            root_dir: root_dir.into(),
+        }
+    }
+
+    // กัน key ที่พยายามออกนอก root_dir เช่น ../
+    fn path_of(&self, storage_key: &str) -> Result<PathBuf> {
+        let key_path = Path::new(storage_key);
+
+        let is_safe = key_path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+
+        if storage_key.is_empty() || !is_safe {
+            return Err(anyhow::anyhow!("Invalid storage key"));
+        }
+
+        Ok(self.root_dir.join(key_path))
+    }
+}
+
+#[async_trait]
+impl AttachmentStorage for LocalAttachmentStorage {
+    async fn put(&self, storage_key: &str, content: Vec<u8>) -> Result<()> {
+        let path = self.path_of(storage_key)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        tokio::fs::write(path, content).await?;
+
+        Ok(())
+    }
+
+    async fn get(&self, storage_key: &str) -> Result<Vec<u8>> {
+        let path = self.path_of(storage_key)?;
+
+        let content = tokio::fs::read(path).await?;
+
+        Ok(content)
+    }
+
+    async fn delete(&self, storage_key: &str) -> Result<()> {
+        let path = self.path_of(storage_key)?;
+
+        match tokio::fs::remove_file(path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -1,4 +1,6 @@
 pub mod axum_http;
 pub mod postgres;
 pub mod argon2_hashing;
-pub mod jwt_authentication;
\ No newline at end of file
+pub mod jwt_authentication;
+pub mod schedulers;
+pub mod local_storage;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE quest_crew_bans (
+    quest_id INTEGER NOT NULL,
+    adventurer_id INTEGER NOT NULL,
+    guild_commander_id INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (quest_id, adventurer_id)
+);
+
+ALTER TABLE
+    quest_crew_bans
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id),
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id);
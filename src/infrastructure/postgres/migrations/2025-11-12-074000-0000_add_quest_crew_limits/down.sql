@@ -0,0 +1,4 @@
+ALTER TABLE
+    quests DROP CONSTRAINT IF EXISTS chk_crew_limits,
+    DROP COLUMN IF EXISTS max_crew,
+    DROP COLUMN IF EXISTS min_crew;
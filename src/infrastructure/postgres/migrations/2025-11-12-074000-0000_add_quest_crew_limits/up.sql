@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN min_crew INTEGER NOT NULL DEFAULT 1,
+ADD
+    COLUMN max_crew INTEGER NOT NULL DEFAULT 4,
+ADD
+    CONSTRAINT chk_crew_limits CHECK (min_crew >= 1 AND min_crew <= max_crew);
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS quest_join_applications;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS join_policy;
@@ -0,0 +1,19 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN join_policy VARCHAR(255) NOT NULL DEFAULT 'Open';
+
+CREATE TABLE quest_join_applications (
+    quest_id INTEGER NOT NULL,
+    adventurer_id INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (quest_id, adventurer_id)
+);
+
+ALTER TABLE
+    quest_join_applications
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id);
@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE quest_invitations (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    adventurer_id INTEGER NOT NULL,
+    guild_commander_id INTEGER NOT NULL,
+    expires_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    UNIQUE (quest_id, adventurer_id)
+);
+
+ALTER TABLE
+    quest_invitations
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id),
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id);
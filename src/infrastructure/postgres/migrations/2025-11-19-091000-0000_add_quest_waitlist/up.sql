@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE quest_waitlist (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    adventurer_id INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    UNIQUE (quest_id, adventurer_id)
+);
+
+ALTER TABLE
+    quest_waitlist
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id);
@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS idx_quests_status_due_at;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS due_at,
+    DROP COLUMN IF EXISTS join_deadline,
+    DROP COLUMN IF EXISTS starts_at;
@@ -0,0 +1,13 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN starts_at TIMESTAMP,
+ADD
+    COLUMN join_deadline TIMESTAMP,
+ADD
+    COLUMN due_at TIMESTAMP;
+
+CREATE INDEX idx_quests_status_due_at ON quests ("status", due_at)
+WHERE
+    due_at IS NOT NULL;
@@ -0,0 +1,6 @@
+DROP TABLE IF EXISTS adventurer_ledger;
+
+ALTER TABLE
+    quests DROP CONSTRAINT IF EXISTS chk_quest_rewards,
+    DROP COLUMN IF EXISTS reward_experience,
+    DROP COLUMN IF EXISTS reward_gold;
@@ -0,0 +1,31 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN reward_gold INTEGER NOT NULL DEFAULT 0,
+ADD
+    COLUMN reward_experience INTEGER NOT NULL DEFAULT 0,
+ADD
+    CONSTRAINT chk_quest_rewards CHECK (
+        reward_gold >= 0
+        AND reward_experience >= 0
+    );
+
+CREATE TABLE adventurer_ledger (
+    id SERIAL PRIMARY KEY,
+    adventurer_id INTEGER NOT NULL,
+    quest_id INTEGER,
+    entry_type VARCHAR(255) NOT NULL,
+    gold INTEGER NOT NULL DEFAULT 0,
+    experience INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_adventurer_ledger_adventurer_id ON adventurer_ledger (adventurer_id, created_at);
+
+ALTER TABLE
+    adventurer_ledger
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id),
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE SET NULL;
@@ -0,0 +1,3 @@
+ALTER TABLE
+    quests DROP CONSTRAINT IF EXISTS chk_quest_min_level,
+    DROP COLUMN IF EXISTS min_level;
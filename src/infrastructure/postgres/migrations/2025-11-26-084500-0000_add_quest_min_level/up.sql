@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN min_level INTEGER NOT NULL DEFAULT 1,
+ADD
+    CONSTRAINT chk_quest_min_level CHECK (min_level >= 1);
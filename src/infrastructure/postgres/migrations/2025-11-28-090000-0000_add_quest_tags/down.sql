@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS quest_tags;
+
+DROP TABLE IF EXISTS tags;
@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE tags (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(64) NOT NULL UNIQUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE TABLE quest_tags (
+    quest_id INTEGER NOT NULL,
+    tag_id INTEGER NOT NULL,
+    PRIMARY KEY (quest_id, tag_id)
+);
+
+CREATE INDEX idx_quest_tags_tag_id ON quest_tags (tag_id);
+
+ALTER TABLE
+    quest_tags
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_tag FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE;
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_quests_search_vector;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS search_vector;
@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE quest_templates (
+    id SERIAL PRIMARY KEY,
+    guild_commander_id INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    min_crew INTEGER NOT NULL DEFAULT 1,
+    max_crew INTEGER NOT NULL DEFAULT 4,
+    join_policy VARCHAR(255) NOT NULL DEFAULT 'Open',
+    reward_gold INTEGER NOT NULL DEFAULT 0,
+    reward_experience INTEGER NOT NULL DEFAULT 0,
+    min_level INTEGER NOT NULL DEFAULT 1,
+    tags TEXT [] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    quest_templates
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS quest_recurrence_instances;
+
+DROP TABLE IF EXISTS quest_recurrences;
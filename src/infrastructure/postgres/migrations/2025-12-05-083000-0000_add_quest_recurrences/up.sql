@@ -0,0 +1,37 @@
+-- Your SQL goes here
+CREATE TABLE quest_recurrences (
+    id SERIAL PRIMARY KEY,
+    guild_commander_id INTEGER NOT NULL,
+    quest_template_id INTEGER NOT NULL,
+    schedule VARCHAR(255) NOT NULL,
+    max_open_instances INTEGER NOT NULL DEFAULT 1,
+    "status" VARCHAR(255) NOT NULL DEFAULT 'Active',
+    next_run_at TIMESTAMP NOT NULL,
+    last_run_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT chk_max_open_instances CHECK (max_open_instances >= 1)
+);
+
+CREATE INDEX idx_quest_recurrences_status_next_run_at ON quest_recurrences ("status", next_run_at);
+
+CREATE TABLE quest_recurrence_instances (
+    quest_recurrence_id INTEGER NOT NULL,
+    quest_id INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (quest_recurrence_id, quest_id)
+);
+
+ALTER TABLE
+    quest_recurrences
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id),
+ADD
+    CONSTRAINT fk_quest_template FOREIGN KEY (quest_template_id) REFERENCES quest_templates(id) ON DELETE CASCADE;
+
+ALTER TABLE
+    quest_recurrence_instances
+ADD
+    CONSTRAINT fk_quest_recurrence FOREIGN KEY (quest_recurrence_id) REFERENCES quest_recurrences(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE;
@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS idx_quests_deleted_at;
+
+ALTER TABLE
+    quest_adventurer_junction DROP CONSTRAINT fk_quest,
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id);
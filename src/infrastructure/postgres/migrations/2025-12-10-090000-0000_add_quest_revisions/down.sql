@@ -0,0 +1,5 @@
+ALTER TABLE
+    quest_adventurer_junction DROP COLUMN IF EXISTS reconfirmation_required,
+    DROP COLUMN IF EXISTS joined_at;
+
+DROP TABLE IF EXISTS quest_revisions;
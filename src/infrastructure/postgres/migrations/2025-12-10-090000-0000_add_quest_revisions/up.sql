@@ -0,0 +1,25 @@
+-- Your SQL goes here
+CREATE TABLE quest_revisions (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    revision_number INTEGER NOT NULL,
+    guild_commander_id INTEGER NOT NULL,
+    changes JSONB NOT NULL DEFAULT '[]',
+    requires_reconfirmation BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    UNIQUE (quest_id, revision_number)
+);
+
+ALTER TABLE
+    quest_revisions
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id);
+
+ALTER TABLE
+    quest_adventurer_junction
+ADD
+    COLUMN joined_at TIMESTAMP NOT NULL DEFAULT now(),
+ADD
+    COLUMN reconfirmation_required BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,2 @@
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS version;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN version INTEGER NOT NULL DEFAULT 1;
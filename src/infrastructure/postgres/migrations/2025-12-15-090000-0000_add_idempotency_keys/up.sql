@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE idempotency_keys (
+    id SERIAL PRIMARY KEY,
+    user_scope VARCHAR(255) NOT NULL,
+    idempotency_key VARCHAR(255) NOT NULL,
+    request_method VARCHAR(16) NOT NULL,
+    request_path TEXT NOT NULL,
+    status_code INTEGER,
+    content_type VARCHAR(255),
+    response_body BYTEA,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    expires_at TIMESTAMP NOT NULL,
+    UNIQUE (user_scope, idempotency_key)
+);
+
+CREATE INDEX idx_idempotency_keys_expires_at ON idempotency_keys (expires_at);
@@ -0,0 +1,29 @@
+-- Your SQL goes here
+CREATE TABLE quest_comments (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    parent_comment_id INTEGER,
+    adventurer_id INTEGER,
+    guild_commander_id INTEGER,
+    body TEXT NOT NULL,
+    moderated BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    deleted_at TIMESTAMP,
+    CONSTRAINT chk_single_author CHECK ((adventurer_id IS NULL) <> (guild_commander_id IS NULL))
+);
+
+ALTER TABLE
+    quest_comments
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_parent_comment FOREIGN KEY (parent_comment_id) REFERENCES quest_comments(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id),
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id);
+
+CREATE INDEX idx_quest_comments_quest_id_created_at ON quest_comments (quest_id, created_at);
+
+CREATE INDEX idx_quest_comments_parent_comment_id ON quest_comments (parent_comment_id);
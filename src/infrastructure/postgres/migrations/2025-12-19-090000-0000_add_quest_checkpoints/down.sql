@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS quest_checkpoints;
+
+ALTER TABLE
+    quests DROP COLUMN IF EXISTS require_checkpoints;
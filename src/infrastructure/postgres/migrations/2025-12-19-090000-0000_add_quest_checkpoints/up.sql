@@ -0,0 +1,28 @@
+-- Your SQL goes here
+ALTER TABLE
+    quests
+ADD
+    COLUMN require_checkpoints BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE quest_checkpoints (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    description TEXT,
+    mandatory BOOLEAN NOT NULL DEFAULT TRUE,
+    position INTEGER NOT NULL DEFAULT 0,
+    completed_by_adventurer_id INTEGER,
+    completion_note TEXT,
+    completed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    quest_checkpoints
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_completed_by_adventurer FOREIGN KEY (completed_by_adventurer_id) REFERENCES adventurers(id);
+
+CREATE INDEX idx_quest_checkpoints_quest_id ON quest_checkpoints (quest_id, position);
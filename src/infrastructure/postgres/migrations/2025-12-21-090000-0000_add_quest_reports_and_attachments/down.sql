@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS quest_attachments;
+
+DROP TABLE IF EXISTS quest_reports;
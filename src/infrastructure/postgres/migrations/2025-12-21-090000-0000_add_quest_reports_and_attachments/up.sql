@@ -0,0 +1,38 @@
+-- Your SQL goes here
+CREATE TABLE quest_reports (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    guild_commander_id INTEGER NOT NULL,
+    outcome VARCHAR(50) NOT NULL,
+    body TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    quest_reports
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id);
+
+CREATE INDEX idx_quest_reports_quest_id ON quest_reports (quest_id, created_at);
+
+CREATE TABLE quest_attachments (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    adventurer_id INTEGER NOT NULL,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(100) NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    storage_key VARCHAR(255) NOT NULL UNIQUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    quest_attachments
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id);
+
+CREATE INDEX idx_quest_attachments_quest_id ON quest_attachments (quest_id, created_at);
@@ -0,0 +1,26 @@
+-- Your SQL goes here
+CREATE TABLE quest_ratings (
+    id SERIAL PRIMARY KEY,
+    quest_id INTEGER NOT NULL,
+    guild_commander_id INTEGER NOT NULL,
+    adventurer_id INTEGER NOT NULL,
+    direction VARCHAR(50) NOT NULL,
+    score INTEGER NOT NULL,
+    comment TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT chk_quest_ratings_score CHECK (score BETWEEN 1 AND 5),
+    CONSTRAINT uq_quest_ratings_pair UNIQUE (quest_id, guild_commander_id, adventurer_id, direction)
+);
+
+ALTER TABLE
+    quest_ratings
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id),
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id);
+
+CREATE INDEX idx_quest_ratings_adventurer ON quest_ratings (adventurer_id, direction);
+
+CREATE INDEX idx_quest_ratings_guild_commander ON quest_ratings (guild_commander_id, direction);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS guild_commander_stats_daily;
+
+DROP TABLE IF EXISTS adventurer_stats_daily;
@@ -0,0 +1,136 @@
+-- Your SQL goes here
+CREATE TABLE adventurer_stats_daily (
+    adventurer_id INTEGER NOT NULL,
+    stat_date DATE NOT NULL,
+    completed_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    gold_earned INTEGER NOT NULL DEFAULT 0,
+    experience_earned INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (adventurer_id, stat_date)
+);
+
+ALTER TABLE
+    adventurer_stats_daily
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_adventurer_stats_daily_stat_date ON adventurer_stats_daily (stat_date);
+
+CREATE TABLE guild_commander_stats_daily (
+    guild_commander_id INTEGER NOT NULL,
+    stat_date DATE NOT NULL,
+    completed_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    rating_score_total INTEGER NOT NULL DEFAULT 0,
+    ratings_count INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (guild_commander_id, stat_date)
+);
+
+ALTER TABLE
+    guild_commander_stats_daily
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_guild_commander_stats_daily_stat_date ON guild_commander_stats_daily (stat_date);
+
+-- backfill จากข้อมูลเดิม ใช้ updated_at ของ quest เป็นวันที่จบ quest
+INSERT INTO
+    adventurer_stats_daily (
+        adventurer_id,
+        stat_date,
+        completed_count,
+        failed_count
+    )
+SELECT
+    quest_adventurer_junction.adventurer_id,
+    quests.updated_at :: DATE,
+    COUNT(*) FILTER (
+        WHERE
+            quests.status = 'Completed'
+    ),
+    COUNT(*) FILTER (
+        WHERE
+            quests.status = 'Failed'
+    )
+FROM
+    quest_adventurer_junction
+    INNER JOIN quests ON quests.id = quest_adventurer_junction.quest_id
+WHERE
+    quests.status IN ('Completed', 'Failed')
+GROUP BY
+    quest_adventurer_junction.adventurer_id,
+    quests.updated_at :: DATE;
+
+INSERT INTO
+    adventurer_stats_daily (
+        adventurer_id,
+        stat_date,
+        gold_earned,
+        experience_earned
+    )
+SELECT
+    adventurer_id,
+    created_at :: DATE,
+    SUM(gold),
+    SUM(experience)
+FROM
+    adventurer_ledger
+WHERE
+    entry_type = 'QuestReward'
+GROUP BY
+    adventurer_id,
+    created_at :: DATE ON CONFLICT (adventurer_id, stat_date) DO
+UPDATE
+SET
+    gold_earned = adventurer_stats_daily.gold_earned + EXCLUDED.gold_earned,
+    experience_earned = adventurer_stats_daily.experience_earned + EXCLUDED.experience_earned;
+
+INSERT INTO
+    guild_commander_stats_daily (
+        guild_commander_id,
+        stat_date,
+        completed_count,
+        failed_count
+    )
+SELECT
+    guild_commander_id,
+    updated_at :: DATE,
+    COUNT(*) FILTER (
+        WHERE
+            status = 'Completed'
+    ),
+    COUNT(*) FILTER (
+        WHERE
+            status = 'Failed'
+    )
+FROM
+    quests
+WHERE
+    status IN ('Completed', 'Failed')
+GROUP BY
+    guild_commander_id,
+    updated_at :: DATE;
+
+INSERT INTO
+    guild_commander_stats_daily (
+        guild_commander_id,
+        stat_date,
+        rating_score_total,
+        ratings_count
+    )
+SELECT
+    guild_commander_id,
+    created_at :: DATE,
+    SUM(score),
+    COUNT(*)
+FROM
+    quest_ratings
+WHERE
+    direction = 'AdventurerToCommander'
+GROUP BY
+    guild_commander_id,
+    created_at :: DATE ON CONFLICT (guild_commander_id, stat_date) DO
+UPDATE
+SET
+    rating_score_total = guild_commander_stats_daily.rating_score_total + EXCLUDED.rating_score_total,
+    ratings_count = guild_commander_stats_daily.ratings_count + EXCLUDED.ratings_count;
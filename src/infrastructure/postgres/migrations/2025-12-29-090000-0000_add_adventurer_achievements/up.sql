@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE adventurer_achievements (
+    id SERIAL PRIMARY KEY,
+    adventurer_id INTEGER NOT NULL,
+    badge_code VARCHAR(100) NOT NULL,
+    quest_id INTEGER,
+    awarded_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT uq_adventurer_achievements_badge UNIQUE (adventurer_id, badge_code)
+);
+
+ALTER TABLE
+    adventurer_achievements
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE SET NULL;
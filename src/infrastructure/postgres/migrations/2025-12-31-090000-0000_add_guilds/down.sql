@@ -0,0 +1,10 @@
+DROP INDEX IF EXISTS idx_quests_guild_id;
+
+ALTER TABLE
+    quests DROP CONSTRAINT IF EXISTS fk_guild,
+    DROP COLUMN IF EXISTS visibility,
+    DROP COLUMN IF EXISTS guild_id;
+
+DROP TABLE IF EXISTS guild_members;
+
+DROP TABLE IF EXISTS guilds;
@@ -0,0 +1,44 @@
+-- Your SQL goes here
+CREATE TABLE guilds (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL UNIQUE,
+    description TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE TABLE guild_members (
+    id SERIAL PRIMARY KEY,
+    guild_id INTEGER NOT NULL,
+    guild_commander_id INTEGER,
+    adventurer_id INTEGER,
+    role VARCHAR(50) NOT NULL DEFAULT 'Member',
+    joined_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT chk_single_member CHECK ((adventurer_id IS NULL) <> (guild_commander_id IS NULL)),
+    CONSTRAINT uq_guild_members_guild_commander UNIQUE (guild_id, guild_commander_id),
+    CONSTRAINT uq_guild_members_adventurer UNIQUE (guild_id, adventurer_id)
+);
+
+ALTER TABLE
+    guild_members
+ADD
+    CONSTRAINT fk_guild FOREIGN KEY (guild_id) REFERENCES guilds(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_guild_commander FOREIGN KEY (guild_commander_id) REFERENCES guild_commanders(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_guild_members_guild_commander_id ON guild_members (guild_commander_id);
+
+CREATE INDEX idx_guild_members_adventurer_id ON guild_members (adventurer_id);
+
+ALTER TABLE
+    quests
+ADD
+    COLUMN guild_id INTEGER,
+ADD
+    COLUMN visibility VARCHAR(255) NOT NULL DEFAULT 'Public',
+ADD
+    CONSTRAINT fk_guild FOREIGN KEY (guild_id) REFERENCES guilds(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_quests_guild_id ON quests (guild_id);
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TABLE
+    idempotency_keys
+ADD
+    COLUMN request_fingerprint VARCHAR(64) NOT NULL DEFAULT '',
+ADD
+    COLUMN etag VARCHAR(255),
+ADD
+    COLUMN location TEXT;
@@ -0,0 +1,26 @@
+-- Your SQL goes here
+CREATE TABLE quest_final_crews (
+    quest_id INTEGER NOT NULL,
+    adventurer_id INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (quest_id, adventurer_id)
+);
+
+ALTER TABLE
+    quest_final_crews
+ADD
+    CONSTRAINT fk_quest FOREIGN KEY (quest_id) REFERENCES quests(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_adventurer FOREIGN KEY (adventurer_id) REFERENCES adventurers(id);
+
+-- quest ที่จบไปแล้วใช้ crew ปัจจุบันเป็น snapshot แทน
+INSERT INTO
+    quest_final_crews (quest_id, adventurer_id)
+SELECT
+    quest_adventurer_junction.quest_id,
+    quest_adventurer_junction.adventurer_id
+FROM
+    quest_adventurer_junction
+    INNER JOIN quests ON quests.id = quest_adventurer_junction.quest_id
+WHERE
+    quests.status IN ('Completed', 'Failed');
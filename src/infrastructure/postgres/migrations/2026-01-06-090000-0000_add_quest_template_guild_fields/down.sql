@@ -0,0 +1,5 @@
+ALTER TABLE
+    quest_templates DROP CONSTRAINT IF EXISTS fk_guild,
+    DROP COLUMN IF EXISTS visibility,
+    DROP COLUMN IF EXISTS guild_id,
+    DROP COLUMN IF EXISTS require_checkpoints;
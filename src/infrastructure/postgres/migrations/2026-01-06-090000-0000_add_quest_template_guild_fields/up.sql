@@ -0,0 +1,11 @@
+-- Your SQL goes here
+ALTER TABLE
+    quest_templates
+ADD
+    COLUMN require_checkpoints BOOLEAN NOT NULL DEFAULT false,
+ADD
+    COLUMN guild_id INTEGER,
+ADD
+    COLUMN visibility VARCHAR(255) NOT NULL DEFAULT 'Public',
+ADD
+    CONSTRAINT fk_guild FOREIGN KEY (guild_id) REFERENCES guilds(id) ON DELETE SET NULL;
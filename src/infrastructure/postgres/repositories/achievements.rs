@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::async_trait;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::adventurer_achievements::{
+            AddAdventurerAchievementEntity, AdventurerAchievementEntity,
+        },
+        repositories::achievements::AchievementsRepository,
+        value_objects::achievement_model::AdventurerAchievementStatsModel,
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        schema::{adventurer_achievements, adventurer_stats_daily, quest_adventurer_junction},
+    },
+};
+
+pub struct AchievementsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl AchievementsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AchievementsRepository for AchievementsPostgres {
+    async fn achievement_stats(
+        &self,
+        adventurer_id: i32,
+    ) -> Result<AdventurerAchievementStatsModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let joined_count = quest_adventurer_junction::table
+            .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        // ผลลัพธ์ quest อ่านจาก aggregate ของ leaderboard ไม่ต้องนับใหม่
+        let (completed_count, failed_count) = adventurer_stats_daily::table
+            .filter(adventurer_stats_daily::adventurer_id.eq(adventurer_id))
+            .select((
+                diesel::dsl::sum(adventurer_stats_daily::completed_count),
+                diesel::dsl::sum(adventurer_stats_daily::failed_count),
+            ))
+            .first::<(Option<i64>, Option<i64>)>(&mut conn)?;
+
+        let joined_quest_ids = quest_adventurer_junction::table
+            .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+            .select(quest_adventurer_junction::quest_id)
+            .load::<i32>(&mut conn)?;
+
+        let crew_sizes = quest_adventurer_junction::table
+            .filter(quest_adventurer_junction::quest_id.eq_any(joined_quest_ids))
+            .group_by(quest_adventurer_junction::quest_id)
+            .select(diesel::dsl::count(quest_adventurer_junction::adventurer_id))
+            .load::<i64>(&mut conn)?;
+
+        Ok(AdventurerAchievementStatsModel {
+            joined_count,
+            completed_count: completed_count.unwrap_or(0),
+            failed_count: failed_count.unwrap_or(0),
+            largest_crew_size: crew_sizes.into_iter().max().unwrap_or(0),
+        })
+    }
+
+    async fn crew_adventurer_ids(&self, quest_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_adventurer_junction::table
+            .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+            .select(quest_adventurer_junction::adventurer_id)
+            .order_by(quest_adventurer_junction::adventurer_id.asc())
+            .load::<i32>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn by_adventurer_id(
+        &self,
+        adventurer_id: i32,
+    ) -> Result<Vec<AdventurerAchievementEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = adventurer_achievements::table
+            .filter(adventurer_achievements::adventurer_id.eq(adventurer_id))
+            .select(AdventurerAchievementEntity::as_select())
+            .order_by(adventurer_achievements::awarded_at.asc())
+            .load::<AdventurerAchievementEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn award(
+        &self,
+        add_adventurer_achievement_entities: Vec<AddAdventurerAchievementEntity>,
+    ) -> Result<Vec<String>> {
+        if add_adventurer_achievement_entities.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // badge ซ้ำจะถูกข้าม ได้เฉพาะ badge ที่เพิ่งได้รับจริงกลับมา
+        let results = diesel::insert_into(adventurer_achievements::table)
+            .values(&add_adventurer_achievement_entities)
+            .on_conflict((
+                adventurer_achievements::adventurer_id,
+                adventurer_achievements::badge_code,
+            ))
+            .do_nothing()
+            .returning(adventurer_achievements::badge_code)
+            .get_results::<String>(&mut conn)?;
+
+        Ok(results)
+    }
+}
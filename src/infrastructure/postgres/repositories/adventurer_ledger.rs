@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::async_trait;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::adventurer_ledger::AdventurerLedgerEntity,
+        repositories::adventurer_ledger::AdventurerLedgerRepository,
+    },
+    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::adventurer_ledger},
+};
+
+pub struct AdventurerLedgerPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl AdventurerLedgerPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AdventurerLedgerRepository for AdventurerLedgerPostgres {
+    async fn gold_balance(&self, adventurer_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = adventurer_ledger::table
+            .filter(adventurer_ledger::adventurer_id.eq(adventurer_id))
+            .select(diesel::dsl::sum(adventurer_ledger::gold))
+            .first::<Option<i64>>(&mut conn)?;
+
+        Ok(result.unwrap_or(0))
+    }
+
+    async fn experience_total(&self, adventurer_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = adventurer_ledger::table
+            .filter(adventurer_ledger::adventurer_id.eq(adventurer_id))
+            .select(diesel::dsl::sum(adventurer_ledger::experience))
+            .first::<Option<i64>>(&mut conn)?;
+
+        Ok(result.unwrap_or(0))
+    }
+
+    async fn entries_by_adventurer_id(
+        &self,
+        adventurer_id: i32,
+    ) -> Result<Vec<AdventurerLedgerEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = adventurer_ledger::table
+            .filter(adventurer_ledger::adventurer_id.eq(adventurer_id))
+            .select(AdventurerLedgerEntity::as_select())
+            .order_by((
+                adventurer_ledger::created_at.desc(),
+                adventurer_ledger::id.desc(),
+            ))
+            .load::<AdventurerLedgerEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+}
@@ -0,0 +1,107 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{delete, dsl::insert_into, prelude::*, upsert::excluded};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::quest_invitations::{AddQuestInvitationEntity, QuestInvitationEntity},
+        repositories::crew_invitations::CrewInvitationsRepository,
+    },
+    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quest_invitations},
+};
+
+pub struct CrewInvitationsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl CrewInvitationsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl CrewInvitationsRepository for CrewInvitationsPostgres {
+    async fn invite(&self, invitation_entities: Vec<AddQuestInvitationEntity>) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // re-inviting refreshes the expiry instead of failing on the unique key
+        let results = insert_into(quest_invitations::table)
+            .values(invitation_entities)
+            .on_conflict((
+                quest_invitations::quest_id,
+                quest_invitations::adventurer_id,
+            ))
+            .do_update()
+            .set((
+                quest_invitations::guild_commander_id
+                    .eq(excluded(quest_invitations::guild_commander_id)),
+                quest_invitations::expires_at.eq(excluded(quest_invitations::expires_at)),
+                quest_invitations::created_at.eq(excluded(quest_invitations::created_at)),
+            ))
+            .returning(quest_invitations::id)
+            .get_results::<i32>(&mut conn)?;
+
+        Ok(results)
+    }
+    async fn pending_by_adventurer_id(
+        &self,
+        adventurer_id: i32,
+        now: NaiveDateTime,
+    ) -> Result<Vec<QuestInvitationEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_invitations::table
+            .filter(quest_invitations::adventurer_id.eq(adventurer_id))
+            .filter(quest_invitations::expires_at.gt(now))
+            .select(QuestInvitationEntity::as_select())
+            .order_by(quest_invitations::created_at.desc())
+            .load::<QuestInvitationEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+    async fn pending_by_quest_id(
+        &self,
+        quest_id: i32,
+        now: NaiveDateTime,
+    ) -> Result<Vec<QuestInvitationEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_invitations::table
+            .filter(quest_invitations::quest_id.eq(quest_id))
+            .filter(quest_invitations::expires_at.gt(now))
+            .select(QuestInvitationEntity::as_select())
+            .order_by(quest_invitations::created_at.desc())
+            .load::<QuestInvitationEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+    async fn find_pending(
+        &self,
+        invitation_id: i32,
+        adventurer_id: i32,
+        now: NaiveDateTime,
+    ) -> Result<QuestInvitationEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_invitations::table
+            .filter(quest_invitations::id.eq(invitation_id))
+            .filter(quest_invitations::adventurer_id.eq(adventurer_id))
+            .filter(quest_invitations::expires_at.gt(now))
+            .select(QuestInvitationEntity::as_select())
+            .first::<QuestInvitationEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn remove(&self, invitation_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        delete(quest_invitations::table)
+            .filter(quest_invitations::id.eq(invitation_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
@@ -1,15 +1,30 @@
 use anyhow::Result;
 use axum::async_trait;
+use chrono::NaiveDateTime;
 use diesel::{delete, dsl::insert_into, prelude::*};
 use std::sync::Arc;
 
 use crate::{
+    config::config_loader::get_adventurer_levels_env,
     domain::{
+        entities::{
+            quest_crew_bans::QuestCrewBanEntity,
+            quest_crew_members::QuestCrewMemberEntity,
+            quest_join_applications::QuestJoinApplicationEntity,
+            quest_waitlist::{AddQuestWaitlistEntity, QuestWaitlistEntity},
+        },
         repositories::crew_swithboard::CrewSwithboardRepository,
-        value_objects::quest_adventurer_juntion::QuestAdventurerJunction,
+        value_objects::{
+            adventurer_level_model::level_progress,
+            quest_adventurer_juntion::QuestAdventurerJunction, quest_statuses::QuestStatuses,
+        },
     },
     infrastructure::postgres::{
-        postgres_connection::PgPoolSquad, schema::quest_adventurer_junction,
+        postgres_connection::PgPoolSquad,
+        schema::{
+            adventurer_ledger, quest_adventurer_junction, quest_crew_bans, quest_join_applications,
+            quest_waitlist, quests,
+        },
     },
 };
 
@@ -25,23 +40,405 @@ impl CrewSwithboardPostgres {
 
 #[async_trait]
 impl CrewSwithboardRepository for CrewSwithboardPostgres {
-    async fn join(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
+    async fn join(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        waitlist_entity: Option<AddQuestWaitlistEntity>,
+    ) -> Result<bool> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        insert_into(quest_adventurer_junction::table)
-            .values(junction_body)
+        // crew เต็มแล้วเข้าคิว waitlist แทนถ้ามีให้ ไม่งั้นถือว่า join ไม่ได้
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            if !lock_crew_capacity(conn, junction_body.quest_id)? {
+                let Some(waitlist_entity) = waitlist_entity else {
+                    return Err(anyhow::anyhow!("Quest crew is full"));
+                };
+
+                insert_into(quest_waitlist::table)
+                    .values(waitlist_entity)
+                    .execute(conn)?;
+
+                return Ok(false);
+            }
+
+            insert_into(quest_adventurer_junction::table)
+                .values(junction_body)
+                .execute(conn)?;
+
+            Ok(true)
+        })?;
+
+        Ok(result)
+    }
+    async fn leave(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            delete(quest_adventurer_junction::table)
+                .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
+                .filter(quest_adventurer_junction::adventurer_id.eq(junction_body.adventurer_id))
+                .execute(conn)?;
+
+            promote_waitlisted(conn, junction_body.quest_id)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+    async fn kick(
+        &self,
+        junction_body: QuestAdventurerJunction,
+        ban_entity: Option<QuestCrewBanEntity>,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let removed = delete(quest_adventurer_junction::table)
+                .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
+                .filter(quest_adventurer_junction::adventurer_id.eq(junction_body.adventurer_id))
+                .execute(conn)?;
+
+            match ban_entity {
+                Some(ban_entity) => {
+                    insert_into(quest_crew_bans::table)
+                        .values(ban_entity)
+                        .on_conflict_do_nothing()
+                        .execute(conn)?;
+
+                    delete(quest_waitlist::table)
+                        .filter(quest_waitlist::quest_id.eq(junction_body.quest_id))
+                        .filter(quest_waitlist::adventurer_id.eq(junction_body.adventurer_id))
+                        .execute(conn)?;
+                }
+                None if removed == 0 => {
+                    return Err(anyhow::anyhow!("Adventurer is not in the quest crew"));
+                }
+                None => {}
+            }
+
+            promote_waitlisted(conn, junction_body.quest_id)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+    async fn unban(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        delete(quest_crew_bans::table)
+            .filter(quest_crew_bans::quest_id.eq(quest_id))
+            .filter(quest_crew_bans::adventurer_id.eq(adventurer_id))
             .execute(&mut conn)?;
 
         Ok(())
     }
-    async fn leave(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
+    async fn is_banned(&self, quest_id: i32, adventurer_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::select(diesel::dsl::exists(
+            quest_crew_bans::table
+                .filter(quest_crew_bans::quest_id.eq(quest_id))
+                .filter(quest_crew_bans::adventurer_id.eq(adventurer_id)),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn bans_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestCrewBanEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_crew_bans::table
+            .filter(quest_crew_bans::quest_id.eq(quest_id))
+            .select(QuestCrewBanEntity::as_select())
+            .order_by(quest_crew_bans::created_at.desc())
+            .load::<QuestCrewBanEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+    async fn apply(&self, application_entity: QuestJoinApplicationEntity) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(quest_join_applications::table)
+            .values(application_entity)
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+    async fn remove_application(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let removed = delete(quest_join_applications::table)
+            .filter(quest_join_applications::quest_id.eq(quest_id))
+            .filter(quest_join_applications::adventurer_id.eq(adventurer_id))
+            .execute(&mut conn)?;
+
+        if removed == 0 {
+            return Err(anyhow::anyhow!("Join application not found"));
+        }
+
+        Ok(())
+    }
+    async fn applications_by_quest_id(
+        &self,
+        quest_id: i32,
+    ) -> Result<Vec<QuestJoinApplicationEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_join_applications::table
+            .filter(quest_join_applications::quest_id.eq(quest_id))
+            .select(QuestJoinApplicationEntity::as_select())
+            .order_by(quest_join_applications::created_at.asc())
+            .load::<QuestJoinApplicationEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+    async fn approve(&self, junction_body: QuestAdventurerJunction) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let removed = delete(quest_join_applications::table)
+                .filter(quest_join_applications::quest_id.eq(junction_body.quest_id))
+                .filter(quest_join_applications::adventurer_id.eq(junction_body.adventurer_id))
+                .execute(conn)?;
+
+            if removed == 0 {
+                return Err(anyhow::anyhow!("Join application not found"));
+            }
+
+            if !lock_crew_capacity(conn, junction_body.quest_id)? {
+                return Err(anyhow::anyhow!("Quest crew is full"));
+            }
+
+            insert_into(quest_adventurer_junction::table)
+                .values(junction_body)
+                .execute(conn)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+    async fn is_crew_member(&self, quest_id: i32, adventurer_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::select(diesel::dsl::exists(
+            quest_adventurer_junction::table
+                .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+                .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id)),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn crew_member(
+        &self,
+        quest_id: i32,
+        adventurer_id: i32,
+    ) -> Result<QuestCrewMemberEntity> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        delete(quest_adventurer_junction::table)
-            .filter(quest_adventurer_junction::quest_id.eq(junction_body.quest_id))
-            .filter(quest_adventurer_junction::adventurer_id.eq(junction_body.adventurer_id))
+        let result = quest_adventurer_junction::table
+            .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+            .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+            .select(QuestCrewMemberEntity::as_select())
+            .first::<QuestCrewMemberEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn reconfirm(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(quest_adventurer_junction::table)
+            .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+            .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+            .set(quest_adventurer_junction::reconfirmation_required.eq(false))
             .execute(&mut conn)?;
 
         Ok(())
     }
+    async fn reconfirmations_by_adventurer_id(&self, adventurer_id: i32) -> Result<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_adventurer_junction::table
+            .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id))
+            .filter(quest_adventurer_junction::reconfirmation_required.eq(true))
+            .select(quest_adventurer_junction::quest_id)
+            .order_by(quest_adventurer_junction::quest_id.asc())
+            .load::<i32>(&mut conn)?;
+
+        Ok(results)
+    }
+    async fn leave_waitlist(&self, quest_id: i32, adventurer_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let removed = delete(quest_waitlist::table)
+            .filter(quest_waitlist::quest_id.eq(quest_id))
+            .filter(quest_waitlist::adventurer_id.eq(adventurer_id))
+            .execute(&mut conn)?;
+
+        if removed == 0 {
+            return Err(anyhow::anyhow!("Adventurer is not on the quest waitlist"));
+        }
+
+        Ok(())
+    }
+    async fn waitlist_position(&self, quest_id: i32, adventurer_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let waitlist_id = quest_waitlist::table
+            .filter(quest_waitlist::quest_id.eq(quest_id))
+            .filter(quest_waitlist::adventurer_id.eq(adventurer_id))
+            .select(quest_waitlist::id)
+            .first::<i32>(&mut conn)?;
+
+        let result = quest_waitlist::table
+            .filter(quest_waitlist::quest_id.eq(quest_id))
+            .filter(quest_waitlist::id.le(waitlist_id))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+    async fn waitlist_by_adventurer_id(
+        &self,
+        adventurer_id: i32,
+    ) -> Result<Vec<QuestWaitlistEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_waitlist::table
+            .filter(quest_waitlist::adventurer_id.eq(adventurer_id))
+            .select(QuestWaitlistEntity::as_select())
+            .order_by(quest_waitlist::created_at.asc())
+            .load::<QuestWaitlistEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+}
+
+// ล็อกแถว quest ไว้จนจบ transaction แล้วดูว่า crew ยังมีที่ว่างไหม
+fn lock_crew_capacity(conn: &mut PgConnection, quest_id: i32) -> Result<bool> {
+    let max_crew = quests::table
+        .filter(quests::id.eq(quest_id))
+        .select(quests::max_crew)
+        .for_update()
+        .first::<i32>(conn)?;
+
+    let adventurers_count = quest_adventurer_junction::table
+        .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    Ok(adventurers_count < max_crew as i64)
+}
+
+// เลื่อนคิวจาก waitlist เข้าทีมจนกว่าทีมจะเต็ม (FIFO)
+// ตรวจสิทธิ์ซ้ำใต้ lock เพราะสถานะ quest หรือตัว adventurer อาจเปลี่ยนไปตั้งแต่ตอนเข้าคิว
+fn promote_waitlisted(conn: &mut PgConnection, quest_id: i32) -> Result<()> {
+    let (max_crew, status, join_deadline, min_level, deleted_at) = quests::table
+        .filter(quests::id.eq(quest_id))
+        .select((
+            quests::max_crew,
+            quests::status,
+            quests::join_deadline,
+            quests::min_level,
+            quests::deleted_at,
+        ))
+        .for_update()
+        .first::<(
+            i32,
+            String,
+            Option<NaiveDateTime>,
+            i32,
+            Option<NaiveDateTime>,
+        )>(conn)?;
+
+    let is_joining_closed = deleted_at.is_some()
+        || status != QuestStatuses::Open.to_string()
+        || join_deadline
+            .is_some_and(|join_deadline| chrono::Utc::now().naive_utc() > join_deadline);
+
+    if is_joining_closed {
+        return Ok(());
+    }
+
+    let mut adventurers_count = quest_adventurer_junction::table
+        .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    while adventurers_count < max_crew as i64 {
+        let next_waitlisted = quest_waitlist::table
+            .filter(quest_waitlist::quest_id.eq(quest_id))
+            .order_by(quest_waitlist::id.asc())
+            .select(QuestWaitlistEntity::as_select())
+            .first::<QuestWaitlistEntity>(conn)
+            .optional()?;
+
+        let Some(next_waitlisted) = next_waitlisted else {
+            break;
+        };
+
+        // คนที่ไม่ผ่านเงื่อนไขแล้วถูกเอาออกจากคิวไปเลย
+        delete(quest_waitlist::table)
+            .filter(quest_waitlist::id.eq(next_waitlisted.id))
+            .execute(conn)?;
+
+        if !is_still_eligible(conn, quest_id, next_waitlisted.adventurer_id, min_level)? {
+            continue;
+        }
+
+        let promoted = insert_into(quest_adventurer_junction::table)
+            .values(QuestAdventurerJunction {
+                adventurer_id: next_waitlisted.adventurer_id,
+                quest_id,
+            })
+            .on_conflict_do_nothing()
+            .execute(conn)?;
+
+        adventurers_count += promoted as i64;
+    }
+
+    Ok(())
+}
+
+fn is_still_eligible(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    adventurer_id: i32,
+    min_level: i32,
+) -> Result<bool> {
+    let is_banned = diesel::select(diesel::dsl::exists(
+        quest_crew_bans::table
+            .filter(quest_crew_bans::quest_id.eq(quest_id))
+            .filter(quest_crew_bans::adventurer_id.eq(adventurer_id)),
+    ))
+    .get_result::<bool>(conn)?;
+
+    if is_banned {
+        return Ok(false);
+    }
+
+    if min_level > 1 {
+        let adventurer_levels = get_adventurer_levels_env()?;
+
+        let experience = adventurer_ledger::table
+            .filter(adventurer_ledger::adventurer_id.eq(adventurer_id))
+            .select(diesel::dsl::sum(adventurer_ledger::experience))
+            .first::<Option<i64>>(conn)?
+            .unwrap_or(0);
+
+        let adventurer_level = level_progress(
+            experience,
+            adventurer_levels.base_experience,
+            adventurer_levels.growth_factor,
+        );
+
+        if adventurer_level.level < min_level {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
 }
@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::async_trait;
+use diesel::{insert_into, prelude::*};
+
+use crate::{
+    domain::{
+        entities::guilds::{AddGuildEntity, AddGuildMemberEntity, GuildEntity, GuildMemberEntity},
+        repositories::guilds::GuildsRepository,
+        value_objects::{guild_roles::GuildRoles, quest_participants::QuestParticipants},
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        schema::{guild_members, guilds},
+    },
+};
+
+pub struct GuildsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl GuildsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl GuildsRepository for GuildsPostgres {
+    async fn create(
+        &self,
+        add_guild_entity: AddGuildEntity,
+        founder_guild_commander_id: i32,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let guild_id = insert_into(guilds::table)
+                .values(&add_guild_entity)
+                .returning(guilds::id)
+                .get_result::<i32>(conn)?;
+
+            // ผู้สร้าง guild เป็น Owner คนแรก
+            insert_into(guild_members::table)
+                .values(AddGuildMemberEntity {
+                    guild_id,
+                    guild_commander_id: Some(founder_guild_commander_id),
+                    adventurer_id: None,
+                    role: GuildRoles::Owner.to_string(),
+                    joined_at: add_guild_entity.created_at,
+                })
+                .execute(conn)?;
+
+            Ok(guild_id)
+        })?;
+
+        Ok(result)
+    }
+
+    async fn view_details(&self, guild_id: i32) -> Result<GuildEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = guilds::table
+            .filter(guilds::id.eq(guild_id))
+            .select(GuildEntity::as_select())
+            .first::<GuildEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn guilds(&self, limit: i64, offset: i64) -> Result<Vec<GuildEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = guilds::table
+            .select(GuildEntity::as_select())
+            .order_by(guilds::name.asc())
+            .limit(limit)
+            .offset(offset)
+            .load::<GuildEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn guilds_counting(&self) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = guilds::table.count().get_result::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn members_counting_by_guild_id(&self, guild_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = guild_members::table
+            .filter(guild_members::guild_id.eq(guild_id))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn members_by_guild_id(&self, guild_id: i32) -> Result<Vec<GuildMemberEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = guild_members::table
+            .filter(guild_members::guild_id.eq(guild_id))
+            .select(GuildMemberEntity::as_select())
+            .order_by(guild_members::joined_at.asc())
+            .load::<GuildMemberEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn member(
+        &self,
+        guild_id: i32,
+        participant: QuestParticipants,
+    ) -> Result<Option<GuildMemberEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let query = guild_members::table
+            .filter(guild_members::guild_id.eq(guild_id))
+            .into_boxed();
+
+        let query = match participant {
+            QuestParticipants::Adventurer(adventurer_id) => {
+                query.filter(guild_members::adventurer_id.eq(adventurer_id))
+            }
+            QuestParticipants::GuildCommander(guild_commander_id) => {
+                query.filter(guild_members::guild_commander_id.eq(guild_commander_id))
+            }
+        };
+
+        let result = query
+            .select(GuildMemberEntity::as_select())
+            .first::<GuildMemberEntity>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn member_details(&self, member_id: i32) -> Result<GuildMemberEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = guild_members::table
+            .filter(guild_members::id.eq(member_id))
+            .select(GuildMemberEntity::as_select())
+            .first::<GuildMemberEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn memberships(
+        &self,
+        participant: QuestParticipants,
+    ) -> Result<Vec<(GuildMemberEntity, GuildEntity)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let query = guild_members::table.inner_join(guilds::table).into_boxed();
+
+        let query = match participant {
+            QuestParticipants::Adventurer(adventurer_id) => {
+                query.filter(guild_members::adventurer_id.eq(adventurer_id))
+            }
+            QuestParticipants::GuildCommander(guild_commander_id) => {
+                query.filter(guild_members::guild_commander_id.eq(guild_commander_id))
+            }
+        };
+
+        let results = query
+            .select((GuildMemberEntity::as_select(), GuildEntity::as_select()))
+            .order_by(guilds::name.asc())
+            .load::<(GuildMemberEntity, GuildEntity)>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn add_member(&self, add_guild_member_entity: AddGuildMemberEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(guild_members::table)
+            .values(add_guild_member_entity)
+            .returning(guild_members::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn change_role(&self, member_id: i32, role: String) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(guild_members::table)
+            .filter(guild_members::id.eq(member_id))
+            .set(guild_members::role.eq(role))
+            .returning(guild_members::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn remove_member(&self, member_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(guild_members::table)
+            .filter(guild_members::id.eq(member_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{insert_into, prelude::*};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::idempotency_keys::{
+            AddIdempotencyKeyEntity, CompleteIdempotencyKeyEntity, IdempotencyKeyEntity,
+        },
+        repositories::idempotency_keys::IdempotencyKeysRepository,
+    },
+    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::idempotency_keys},
+};
+
+pub struct IdempotencyKeysPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl IdempotencyKeysPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl IdempotencyKeysRepository for IdempotencyKeysPostgres {
+    async fn reserve(
+        &self,
+        add_idempotency_key_entity: AddIdempotencyKeyEntity,
+        abandoned_before: NaiveDateTime,
+    ) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            // key ที่หมดอายุแล้ว หรือค้าง in progress เพราะ request ถูกยกเลิก ใช้ซ้ำได้
+            diesel::delete(idempotency_keys::table)
+                .filter(idempotency_keys::user_scope.eq(&add_idempotency_key_entity.user_scope))
+                .filter(
+                    idempotency_keys::idempotency_key
+                        .eq(&add_idempotency_key_entity.idempotency_key),
+                )
+                .filter(
+                    idempotency_keys::expires_at
+                        .lt(add_idempotency_key_entity.created_at)
+                        .or(idempotency_keys::status_code
+                            .is_null()
+                            .and(idempotency_keys::created_at.lt(abandoned_before))),
+                )
+                .execute(conn)?;
+
+            let inserted = insert_into(idempotency_keys::table)
+                .values(&add_idempotency_key_entity)
+                .on_conflict((
+                    idempotency_keys::user_scope,
+                    idempotency_keys::idempotency_key,
+                ))
+                .do_nothing()
+                .execute(conn)?;
+
+            Ok(inserted > 0)
+        })?;
+
+        Ok(result)
+    }
+
+    async fn find(&self, user_scope: &str, idempotency_key: &str) -> Result<IdempotencyKeyEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = idempotency_keys::table
+            .filter(idempotency_keys::user_scope.eq(user_scope))
+            .filter(idempotency_keys::idempotency_key.eq(idempotency_key))
+            .select(IdempotencyKeyEntity::as_select())
+            .first::<IdempotencyKeyEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn complete(
+        &self,
+        user_scope: &str,
+        idempotency_key: &str,
+        complete_idempotency_key_entity: CompleteIdempotencyKeyEntity,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(idempotency_keys::table)
+            .filter(idempotency_keys::user_scope.eq(user_scope))
+            .filter(idempotency_keys::idempotency_key.eq(idempotency_key))
+            .filter(idempotency_keys::status_code.is_null())
+            .set(&complete_idempotency_key_entity)
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn release(&self, user_scope: &str, idempotency_key: &str) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(idempotency_keys::table)
+            .filter(idempotency_keys::user_scope.eq(user_scope))
+            .filter(idempotency_keys::idempotency_key.eq(idempotency_key))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn purge_expired(&self, now: NaiveDateTime) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::delete(idempotency_keys::table)
+            .filter(idempotency_keys::expires_at.lt(now))
+            .execute(&mut conn)?;
+
+        Ok(result)
+    }
+}
@@ -5,10 +5,28 @@ use std::sync::Arc;
 
 use crate::{
     domain::{
+        entities::{
+            adventurer_ledger::AddAdventurerLedgerEntity,
+            leaderboards::{AddAdventurerStatsDailyEntity, AddGuildCommanderStatsDailyEntity},
+            quest_final_crews::AddQuestFinalCrewEntity,
+            quest_reports::AddQuestReportEntity,
+        },
         repositories::journey_ledger::JourneyLedgerRepository,
-        value_objects::quest_statuses::QuestStatuses,
+        value_objects::{
+            adventurer_ledger_model::split_reward, ledger_entry_types::LedgerEntryTypes,
+            quest_statuses::QuestStatuses,
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::{
+            leaderboards::{record_adventurer_stats, record_guild_commander_stats},
+            quest_ops::lock_quest_version,
+        },
+        schema::{
+            adventurer_ledger, quest_adventurer_junction, quest_final_crews, quest_reports, quests,
+        },
     },
-    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quests},
 };
 
 pub struct JourneyLedgerPostgres {
@@ -23,40 +41,277 @@ impl JourneyLedgerPostgres {
 
 #[async_trait]
 impl JourneyLedgerRepository for JourneyLedgerPostgres {
-    async fn in_journey(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+    async fn in_journey(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        expected_version: Option<i32>,
+    ) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::guild_commander_id.eq(guild_commander_id))
-            .set(quests::status.eq(QuestStatuses::InJourney.to_string()))
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            lock_quest_version(conn, quest_id, expected_version)?;
+
+            let result = diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::guild_commander_id.eq(guild_commander_id))
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::status.eq_any([
+                    QuestStatuses::Open.to_string(),
+                    QuestStatuses::Failed.to_string(),
+                ]))
+                .set((
+                    quests::status.eq(QuestStatuses::InJourney.to_string()),
+                    quests::version.eq(quests::version + 1),
+                ))
+                .returning(quests::id)
+                .get_result::<i32>(conn)?;
+
+            Ok(result)
+        })?;
 
         Ok(result)
     }
-    async fn to_completed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+    async fn to_completed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        expected_version: Option<i32>,
+        report_body: Option<String>,
+    ) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::guild_commander_id.eq(guild_commander_id))
-            .set(quests::status.eq(QuestStatuses::Completed.to_string()))
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            lock_quest_version(conn, quest_id, expected_version)?;
+
+            let (quest_id, reward_gold, reward_experience) = diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::guild_commander_id.eq(guild_commander_id))
+                .filter(quests::status.eq(QuestStatuses::InJourney.to_string()))
+                .set((
+                    quests::status.eq(QuestStatuses::Completed.to_string()),
+                    quests::version.eq(quests::version + 1),
+                ))
+                .returning((quests::id, quests::reward_gold, quests::reward_experience))
+                .get_result::<(i32, i32, i32)>(conn)?;
+
+            let adventurer_ids = quest_adventurer_junction::table
+                .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+                .select(quest_adventurer_junction::adventurer_id)
+                .order_by(quest_adventurer_junction::adventurer_id.asc())
+                .load::<i32>(conn)?;
+
+            let gold_shares = split_reward(reward_gold, adventurer_ids.len());
+            let experience_shares = split_reward(reward_experience, adventurer_ids.len());
+            let created_at = chrono::Utc::now().naive_utc();
+
+            record_final_crew(conn, quest_id, &adventurer_ids, created_at)?;
+
+            let ledger_entities = adventurer_ids
+                .iter()
+                .zip(gold_shares.iter().zip(experience_shares.iter()))
+                .map(
+                    |(adventurer_id, (gold, experience))| AddAdventurerLedgerEntity {
+                        adventurer_id: *adventurer_id,
+                        quest_id: Some(quest_id),
+                        entry_type: LedgerEntryTypes::QuestReward.to_string(),
+                        gold: *gold,
+                        experience: *experience,
+                        created_at,
+                    },
+                )
+                .collect::<Vec<AddAdventurerLedgerEntity>>();
+
+            diesel::insert_into(adventurer_ledger::table)
+                .values(&ledger_entities)
+                .execute(conn)?;
+
+            let stat_date = created_at.date();
+
+            let stats_entities = ledger_entities
+                .iter()
+                .map(|ledger_entity| AddAdventurerStatsDailyEntity {
+                    adventurer_id: ledger_entity.adventurer_id,
+                    stat_date,
+                    completed_count: 1,
+                    failed_count: 0,
+                    gold_earned: ledger_entity.gold,
+                    experience_earned: ledger_entity.experience,
+                })
+                .collect::<Vec<AddAdventurerStatsDailyEntity>>();
+
+            record_adventurer_stats(conn, &stats_entities)?;
+            record_guild_commander_stats(
+                conn,
+                AddGuildCommanderStatsDailyEntity {
+                    guild_commander_id,
+                    stat_date,
+                    completed_count: 1,
+                    failed_count: 0,
+                    rating_score_total: 0,
+                    ratings_count: 0,
+                },
+            )?;
+
+            insert_report(
+                conn,
+                quest_id,
+                guild_commander_id,
+                QuestStatuses::Completed,
+                report_body,
+            )?;
+
+            Ok(quest_id)
+        })?;
 
         Ok(result)
     }
-    async fn to_failed(&self, quest_id: i32, guild_commander_id: i32) -> Result<i32> {
+    async fn to_failed(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        expected_version: Option<i32>,
+        experience_penalty: i32,
+        report_body: Option<String>,
+    ) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::guild_commander_id.eq(guild_commander_id))
-            .set(quests::status.eq(QuestStatuses::Failed.to_string()))
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            lock_quest_version(conn, quest_id, expected_version)?;
+
+            let quest_id = diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::guild_commander_id.eq(guild_commander_id))
+                .filter(quests::status.eq(QuestStatuses::InJourney.to_string()))
+                .set((
+                    quests::status.eq(QuestStatuses::Failed.to_string()),
+                    quests::version.eq(quests::version + 1),
+                ))
+                .returning(quests::id)
+                .get_result::<i32>(conn)?;
+
+            insert_report(
+                conn,
+                quest_id,
+                guild_commander_id,
+                QuestStatuses::Failed,
+                report_body,
+            )?;
+
+            let adventurer_ids = quest_adventurer_junction::table
+                .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+                .select(quest_adventurer_junction::adventurer_id)
+                .load::<i32>(conn)?;
+
+            let created_at = chrono::Utc::now().naive_utc();
+            let stat_date = created_at.date();
+
+            record_final_crew(conn, quest_id, &adventurer_ids, created_at)?;
+
+            let stats_entities = adventurer_ids
+                .iter()
+                .map(|adventurer_id| AddAdventurerStatsDailyEntity {
+                    adventurer_id: *adventurer_id,
+                    stat_date,
+                    completed_count: 0,
+                    failed_count: 1,
+                    gold_earned: 0,
+                    experience_earned: 0,
+                })
+                .collect::<Vec<AddAdventurerStatsDailyEntity>>();
+
+            record_adventurer_stats(conn, &stats_entities)?;
+            record_guild_commander_stats(
+                conn,
+                AddGuildCommanderStatsDailyEntity {
+                    guild_commander_id,
+                    stat_date,
+                    completed_count: 0,
+                    failed_count: 1,
+                    rating_score_total: 0,
+                    ratings_count: 0,
+                },
+            )?;
+
+            if experience_penalty <= 0 {
+                return Ok(quest_id);
+            }
+
+            let mut ledger_entities = Vec::<AddAdventurerLedgerEntity>::new();
+
+            for adventurer_id in adventurer_ids {
+                // XP ไม่ติดลบ หักได้ไม่เกินที่มีอยู่
+                let experience = adventurer_ledger::table
+                    .filter(adventurer_ledger::adventurer_id.eq(adventurer_id))
+                    .select(diesel::dsl::sum(adventurer_ledger::experience))
+                    .first::<Option<i64>>(conn)?
+                    .unwrap_or(0);
+
+                let deduction = (experience_penalty as i64).min(experience.max(0)) as i32;
+
+                if deduction > 0 {
+                    ledger_entities.push(AddAdventurerLedgerEntity {
+                        adventurer_id,
+                        quest_id: Some(quest_id),
+                        entry_type: LedgerEntryTypes::QuestFailurePenalty.to_string(),
+                        gold: 0,
+                        experience: -deduction,
+                        created_at,
+                    });
+                }
+            }
+
+            diesel::insert_into(adventurer_ledger::table)
+                .values(&ledger_entities)
+                .execute(conn)?;
+
+            Ok(quest_id)
+        })?;
 
         Ok(result)
     }
 }
+
+fn insert_report(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    guild_commander_id: i32,
+    outcome: QuestStatuses,
+    body: Option<String>,
+) -> Result<()> {
+    diesel::insert_into(quest_reports::table)
+        .values(AddQuestReportEntity {
+            quest_id,
+            guild_commander_id,
+            outcome: outcome.to_string(),
+            body,
+            created_at: chrono::Utc::now().naive_utc(),
+        })
+        .execute(conn)?;
+
+    Ok(())
+}
+
+// crew ตอนจบ quest ใช้ตัดสินสิทธิ์ให้คะแนน แม้ภายหลังจะมีคนออกหรือเข้ามาใหม่
+fn record_final_crew(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    adventurer_ids: &[i32],
+    created_at: chrono::NaiveDateTime,
+) -> Result<()> {
+    let final_crew_entities = adventurer_ids
+        .iter()
+        .map(|adventurer_id| AddQuestFinalCrewEntity {
+            quest_id,
+            adventurer_id: *adventurer_id,
+            created_at,
+        })
+        .collect::<Vec<AddQuestFinalCrewEntity>>();
+
+    diesel::insert_into(quest_final_crews::table)
+        .values(&final_crew_entities)
+        .on_conflict_do_nothing()
+        .execute(conn)?;
+
+    Ok(())
+}
@@ -0,0 +1,240 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDate;
+use diesel::{
+    dsl::sql,
+    prelude::*,
+    sql_types::{BigInt, Double, Nullable},
+    upsert::excluded,
+};
+
+use crate::{
+    domain::{
+        entities::leaderboards::{
+            AddAdventurerStatsDailyEntity, AddGuildCommanderStatsDailyEntity,
+            AdventurerLeaderboardEntity, GuildCommanderLeaderboardEntity,
+        },
+        repositories::leaderboards::LeaderboardsRepository,
+        value_objects::leaderboard_model::{
+            AdventurerLeaderboardSorts, GuildCommanderLeaderboardSorts,
+        },
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        schema::{adventurer_stats_daily, guild_commander_stats_daily},
+    },
+};
+
+pub struct LeaderboardsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl LeaderboardsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl LeaderboardsRepository for LeaderboardsPostgres {
+    async fn adventurers(
+        &self,
+        since: Option<NaiveDate>,
+        sort: AdventurerLeaderboardSorts,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AdventurerLeaderboardEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = adventurer_stats_daily::table
+            .group_by(adventurer_stats_daily::adventurer_id)
+            .select((
+                adventurer_stats_daily::adventurer_id,
+                sql::<BigInt>("COALESCE(SUM(adventurer_stats_daily.completed_count), 0)"),
+                sql::<BigInt>("COALESCE(SUM(adventurer_stats_daily.failed_count), 0)"),
+                sql::<BigInt>("COALESCE(SUM(adventurer_stats_daily.gold_earned), 0)"),
+                sql::<BigInt>("COALESCE(SUM(adventurer_stats_daily.experience_earned), 0)"),
+            ))
+            .into_boxed();
+
+        if let Some(since) = since {
+            query = query.filter(adventurer_stats_daily::stat_date.ge(since));
+        }
+
+        let completed = sql::<BigInt>("SUM(adventurer_stats_daily.completed_count)");
+        let gold_earned = sql::<BigInt>("SUM(adventurer_stats_daily.gold_earned)");
+
+        let query = match sort {
+            AdventurerLeaderboardSorts::Completed => query.order_by((
+                completed.desc(),
+                gold_earned.desc(),
+                adventurer_stats_daily::adventurer_id.asc(),
+            )),
+            AdventurerLeaderboardSorts::SuccessRate => query.order_by((
+                sql::<Nullable<Double>>(
+                    "SUM(adventurer_stats_daily.completed_count)::float8 / NULLIF(SUM(adventurer_stats_daily.completed_count) + SUM(adventurer_stats_daily.failed_count), 0) DESC NULLS LAST",
+                ),
+                completed.desc(),
+                adventurer_stats_daily::adventurer_id.asc(),
+            )),
+            AdventurerLeaderboardSorts::Rewards => query.order_by((
+                gold_earned.desc(),
+                sql::<BigInt>("SUM(adventurer_stats_daily.experience_earned)").desc(),
+                adventurer_stats_daily::adventurer_id.asc(),
+            )),
+        };
+
+        let results = query
+            .limit(limit)
+            .offset(offset)
+            .load::<AdventurerLeaderboardEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn adventurers_counting(&self, since: Option<NaiveDate>) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = adventurer_stats_daily::table
+            .select(diesel::dsl::count(adventurer_stats_daily::adventurer_id).aggregate_distinct())
+            .into_boxed();
+
+        if let Some(since) = since {
+            query = query.filter(adventurer_stats_daily::stat_date.ge(since));
+        }
+
+        let result = query.first::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn guild_commanders(
+        &self,
+        since: Option<NaiveDate>,
+        sort: GuildCommanderLeaderboardSorts,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<GuildCommanderLeaderboardEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = guild_commander_stats_daily::table
+            .group_by(guild_commander_stats_daily::guild_commander_id)
+            .select((
+                guild_commander_stats_daily::guild_commander_id,
+                sql::<BigInt>("COALESCE(SUM(guild_commander_stats_daily.completed_count), 0)"),
+                sql::<BigInt>("COALESCE(SUM(guild_commander_stats_daily.failed_count), 0)"),
+                sql::<BigInt>("COALESCE(SUM(guild_commander_stats_daily.rating_score_total), 0)"),
+                sql::<BigInt>("COALESCE(SUM(guild_commander_stats_daily.ratings_count), 0)"),
+            ))
+            .into_boxed();
+
+        if let Some(since) = since {
+            query = query.filter(guild_commander_stats_daily::stat_date.ge(since));
+        }
+
+        let completed = sql::<BigInt>("SUM(guild_commander_stats_daily.completed_count)");
+
+        let query = match sort {
+            GuildCommanderLeaderboardSorts::Completed => query.order_by((
+                completed.desc(),
+                guild_commander_stats_daily::guild_commander_id.asc(),
+            )),
+            GuildCommanderLeaderboardSorts::Satisfaction => query.order_by((
+                sql::<Nullable<Double>>(
+                    "SUM(guild_commander_stats_daily.rating_score_total)::float8 / NULLIF(SUM(guild_commander_stats_daily.ratings_count), 0) DESC NULLS LAST",
+                ),
+                sql::<BigInt>("SUM(guild_commander_stats_daily.ratings_count)").desc(),
+                guild_commander_stats_daily::guild_commander_id.asc(),
+            )),
+        };
+
+        let results = query
+            .limit(limit)
+            .offset(offset)
+            .load::<GuildCommanderLeaderboardEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn guild_commanders_counting(&self, since: Option<NaiveDate>) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut query = guild_commander_stats_daily::table
+            .select(
+                diesel::dsl::count(guild_commander_stats_daily::guild_commander_id)
+                    .aggregate_distinct(),
+            )
+            .into_boxed();
+
+        if let Some(since) = since {
+            query = query.filter(guild_commander_stats_daily::stat_date.ge(since));
+        }
+
+        let result = query.first::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+}
+
+// เรียกใน transaction เดียวกับการเปลี่ยนสถานะ quest ให้ leaderboard ตรงกับผลลัพธ์เสมอ
+pub fn record_adventurer_stats(
+    conn: &mut PgConnection,
+    stats_entities: &[AddAdventurerStatsDailyEntity],
+) -> Result<()> {
+    if stats_entities.is_empty() {
+        return Ok(());
+    }
+
+    diesel::insert_into(adventurer_stats_daily::table)
+        .values(stats_entities)
+        .on_conflict((
+            adventurer_stats_daily::adventurer_id,
+            adventurer_stats_daily::stat_date,
+        ))
+        .do_update()
+        .set((
+            adventurer_stats_daily::completed_count.eq(adventurer_stats_daily::completed_count
+                + excluded(adventurer_stats_daily::completed_count)),
+            adventurer_stats_daily::failed_count.eq(adventurer_stats_daily::failed_count
+                + excluded(adventurer_stats_daily::failed_count)),
+            adventurer_stats_daily::gold_earned
+                .eq(adventurer_stats_daily::gold_earned
+                    + excluded(adventurer_stats_daily::gold_earned)),
+            adventurer_stats_daily::experience_earned.eq(adventurer_stats_daily::experience_earned
+                + excluded(adventurer_stats_daily::experience_earned)),
+        ))
+        .execute(conn)?;
+
+    Ok(())
+}
+
+pub fn record_guild_commander_stats(
+    conn: &mut PgConnection,
+    stats_entity: AddGuildCommanderStatsDailyEntity,
+) -> Result<()> {
+    diesel::insert_into(guild_commander_stats_daily::table)
+        .values(stats_entity)
+        .on_conflict((
+            guild_commander_stats_daily::guild_commander_id,
+            guild_commander_stats_daily::stat_date,
+        ))
+        .do_update()
+        .set((
+            guild_commander_stats_daily::completed_count
+                .eq(guild_commander_stats_daily::completed_count
+                    + excluded(guild_commander_stats_daily::completed_count)),
+            guild_commander_stats_daily::failed_count.eq(guild_commander_stats_daily::failed_count
+                + excluded(guild_commander_stats_daily::failed_count)),
+            guild_commander_stats_daily::rating_score_total
+                .eq(guild_commander_stats_daily::rating_score_total
+                    + excluded(guild_commander_stats_daily::rating_score_total)),
+            guild_commander_stats_daily::ratings_count
+                .eq(guild_commander_stats_daily::ratings_count
+                    + excluded(guild_commander_stats_daily::ratings_count)),
+        ))
+        .execute(conn)?;
+
+    Ok(())
+}
@@ -1,6 +1,18 @@
+pub mod achievements;
+pub mod adventurer_ledger;
 pub mod adventures;
+pub mod crew_invitations;
 pub mod crew_swithboard;
 pub mod guild_commanders;
+pub mod guilds;
+pub mod idempotency_keys;
 pub mod journey_ledger;
+pub mod leaderboards;
+pub mod quest_checkpoints;
+pub mod quest_comments;
 pub mod quest_ops;
-pub mod quest_viewing;
\ No newline at end of file
+pub mod quest_ratings;
+pub mod quest_recurrences;
+pub mod quest_reports;
+pub mod quest_templates;
+pub mod quest_viewing;
@@ -0,0 +1,133 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{insert_into, prelude::*};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::quest_checkpoints::{
+            AddQuestCheckpointEntity, EditQuestCheckpointEntity, QuestCheckpointEntity,
+        },
+        repositories::quest_checkpoints::QuestCheckpointsRepository,
+    },
+    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quest_checkpoints},
+};
+
+pub struct QuestCheckpointsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl QuestCheckpointsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QuestCheckpointsRepository for QuestCheckpointsPostgres {
+    async fn add(&self, add_quest_checkpoint_entity: AddQuestCheckpointEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(quest_checkpoints::table)
+            .values(add_quest_checkpoint_entity)
+            .returning(quest_checkpoints::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn view_details(&self, checkpoint_id: i32) -> Result<QuestCheckpointEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_checkpoints::table
+            .filter(quest_checkpoints::id.eq(checkpoint_id))
+            .select(QuestCheckpointEntity::as_select())
+            .first::<QuestCheckpointEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn edit(
+        &self,
+        checkpoint_id: i32,
+        edit_quest_checkpoint_entity: EditQuestCheckpointEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(quest_checkpoints::table)
+            .filter(quest_checkpoints::id.eq(checkpoint_id))
+            .set(edit_quest_checkpoint_entity)
+            .returning(quest_checkpoints::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn remove(&self, checkpoint_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(quest_checkpoints::table)
+            .filter(quest_checkpoints::id.eq(checkpoint_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestCheckpointEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_checkpoints::table
+            .filter(quest_checkpoints::quest_id.eq(quest_id))
+            .select(QuestCheckpointEntity::as_select())
+            .order_by((
+                quest_checkpoints::position.asc(),
+                quest_checkpoints::id.asc(),
+            ))
+            .load::<QuestCheckpointEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn complete(
+        &self,
+        checkpoint_id: i32,
+        adventurer_id: i32,
+        note: Option<String>,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let now = chrono::Utc::now().naive_utc();
+
+        let result = diesel::update(quest_checkpoints::table)
+            .filter(quest_checkpoints::id.eq(checkpoint_id))
+            .filter(quest_checkpoints::completed_at.is_null())
+            .set((
+                quest_checkpoints::completed_by_adventurer_id.eq(adventurer_id),
+                quest_checkpoints::completion_note.eq(note),
+                quest_checkpoints::completed_at.eq(now),
+                quest_checkpoints::updated_at.eq(now),
+            ))
+            .returning(quest_checkpoints::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn reopen(&self, checkpoint_id: i32) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(quest_checkpoints::table)
+            .filter(quest_checkpoints::id.eq(checkpoint_id))
+            .set((
+                quest_checkpoints::completed_by_adventurer_id.eq(None::<i32>),
+                quest_checkpoints::completion_note.eq(None::<String>),
+                quest_checkpoints::completed_at.eq(None::<NaiveDateTime>),
+                quest_checkpoints::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .returning(quest_checkpoints::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+}
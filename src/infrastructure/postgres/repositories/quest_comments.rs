@@ -0,0 +1,122 @@
+use anyhow::Result;
+use axum::async_trait;
+use diesel::{insert_into, prelude::*};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::quest_comments::{AddQuestCommentEntity, QuestCommentEntity},
+        repositories::quest_comments::QuestCommentsRepository,
+    },
+    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quest_comments},
+};
+
+pub struct QuestCommentsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl QuestCommentsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QuestCommentsRepository for QuestCommentsPostgres {
+    async fn add(&self, add_quest_comment_entity: AddQuestCommentEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(quest_comments::table)
+            .values(add_quest_comment_entity)
+            .returning(quest_comments::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn view_details(&self, comment_id: i32) -> Result<QuestCommentEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_comments::table
+            .filter(quest_comments::id.eq(comment_id))
+            .select(QuestCommentEntity::as_select())
+            .first::<QuestCommentEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn edit(&self, comment_id: i32, body: String) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(quest_comments::table)
+            .filter(quest_comments::id.eq(comment_id))
+            .filter(quest_comments::deleted_at.is_null())
+            .set((
+                quest_comments::body.eq(body),
+                quest_comments::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .returning(quest_comments::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn remove(&self, comment_id: i32, moderated: bool) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(quest_comments::table)
+            .filter(quest_comments::id.eq(comment_id))
+            .filter(quest_comments::deleted_at.is_null())
+            .set((
+                quest_comments::deleted_at.eq(chrono::Utc::now().naive_utc()),
+                quest_comments::moderated.eq(moderated),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn threads_by_quest_id(
+        &self,
+        quest_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<QuestCommentEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_comments::table
+            .filter(quest_comments::quest_id.eq(quest_id))
+            .filter(quest_comments::parent_comment_id.is_null())
+            .select(QuestCommentEntity::as_select())
+            .order_by((quest_comments::created_at.asc(), quest_comments::id.asc()))
+            .limit(limit)
+            .offset(offset)
+            .load::<QuestCommentEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn threads_counting_by_quest_id(&self, quest_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_comments::table
+            .filter(quest_comments::quest_id.eq(quest_id))
+            .filter(quest_comments::parent_comment_id.is_null())
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn replies_by_parent_ids(&self, parent_ids: Vec<i32>) -> Result<Vec<QuestCommentEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_comments::table
+            .filter(quest_comments::parent_comment_id.eq_any(parent_ids))
+            .select(QuestCommentEntity::as_select())
+            .order_by((quest_comments::created_at.asc(), quest_comments::id.asc()))
+            .load::<QuestCommentEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+}
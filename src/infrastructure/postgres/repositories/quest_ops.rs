@@ -1,15 +1,26 @@
 use anyhow::Result;
 use axum::async_trait;
+use chrono::NaiveDateTime;
 use diesel::{insert_into, prelude::*};
 use std::sync::Arc;
 
 use crate::{
     domain::{
-        entities::quests::{AddQuestEntity, EditQuestEntity},
+        entities::{
+            quest_revisions::AddQuestRevisionEntity,
+            quests::{AddQuestEntity, EditQuestEntity, QuestEntity},
+            tags::{AddTagEntity, QuestTagEntity},
+        },
         repositories::quest_ops::QuestOpsRepository,
-        value_objects::quest_statuses::QuestStatuses,
+        value_objects::{quest_statuses::QuestStatuses, quest_version::QuestVersionMismatch},
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        schema::{
+            guild_members, quest_adventurer_junction, quest_attachments, quest_revisions,
+            quest_tags, quests, tags,
+        },
     },
-    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quests},
 };
 
 pub struct QuestOpsPostgres {
@@ -24,26 +35,74 @@ impl QuestOpsPostgres {
 
 #[async_trait]
 impl QuestOpsRepository for QuestOpsPostgres {
-    async fn add(&self, add_quest_entity: AddQuestEntity) -> Result<i32> {
+    async fn add(&self, add_quest_entity: AddQuestEntity, tag_names: Vec<String>) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = insert_into(quests::table)
-            .values(add_quest_entity)
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            insert_quest(conn, add_quest_entity, &tag_names)
+        })?;
 
         Ok(result)
     }
-    async fn edit(&self, quest_id: i32, edit_quest_entity: EditQuestEntity) -> Result<i32> {
+    async fn edit(
+        &self,
+        quest_id: i32,
+        expected_version: Option<i32>,
+        edit_quest_entity: EditQuestEntity,
+        add_quest_revision_entity: AddQuestRevisionEntity,
+    ) -> Result<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(quests::table)
-            .filter(quests::id.eq(quest_id))
-            .filter(quests::deleted_at.is_null())
-            .filter(quests::status.eq(QuestStatuses::Open.to_string()))
-            .set(edit_quest_entity)
-            .returning(quests::id)
-            .get_result::<i32>(&mut conn)?;
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            lock_quest_version(conn, quest_id, expected_version)?;
+
+            // ย้าย quest เข้า guild ได้เฉพาะ guild ที่ guild commander เป็นสมาชิก
+            if let Some(Some(guild_id)) = edit_quest_entity.guild_id {
+                let current_guild_id = quests::table
+                    .filter(quests::id.eq(quest_id))
+                    .select(quests::guild_id)
+                    .first::<Option<i32>>(conn)?;
+
+                if current_guild_id != Some(guild_id) {
+                    ensure_guild_member(conn, guild_id, edit_quest_entity.guild_commander_id)?;
+                }
+            }
+
+            let result = diesel::update(quests::table)
+                .filter(quests::id.eq(quest_id))
+                .filter(quests::deleted_at.is_null())
+                .filter(quests::status.eq(QuestStatuses::Open.to_string()))
+                .set((edit_quest_entity, quests::version.eq(quests::version + 1)))
+                .returning(quests::id)
+                .get_result::<i32>(conn)?;
+
+            let last_revision_number = quest_revisions::table
+                .filter(quest_revisions::quest_id.eq(quest_id))
+                .select(diesel::dsl::max(quest_revisions::revision_number))
+                .first::<Option<i32>>(conn)?;
+
+            insert_into(quest_revisions::table)
+                .values((
+                    quest_revisions::quest_id.eq(quest_id),
+                    quest_revisions::revision_number.eq(last_revision_number.unwrap_or(0) + 1),
+                    quest_revisions::guild_commander_id
+                        .eq(add_quest_revision_entity.guild_commander_id),
+                    quest_revisions::changes.eq(&add_quest_revision_entity.changes),
+                    quest_revisions::requires_reconfirmation
+                        .eq(add_quest_revision_entity.requires_reconfirmation),
+                    quest_revisions::created_at.eq(add_quest_revision_entity.created_at),
+                ))
+                .execute(conn)?;
+
+            if add_quest_revision_entity.requires_reconfirmation {
+                diesel::update(quest_adventurer_junction::table)
+                    .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+                    .set(quest_adventurer_junction::reconfirmation_required.eq(true))
+                    .execute(conn)?;
+            }
+
+            Ok(result)
+        })?;
 
         Ok(result)
     }
@@ -54,13 +113,202 @@ impl QuestOpsRepository for QuestOpsPostgres {
             .filter(quests::id.eq(quest_id))
             .filter(quests::deleted_at.is_null())
             .filter(quests::status.eq(QuestStatuses::Open.to_string()))
+            .filter(quests::guild_commander_id.eq(guild_commander_id))
             .set((
                 quests::deleted_at.eq(chrono::Utc::now().naive_utc()),
                 quests::updated_at.eq(chrono::Utc::now().naive_utc()),
-                quests::guild_commander_id.eq(guild_commander_id),
+                quests::version.eq(quests::version + 1),
             ))
-            .execute(&mut conn)?;
+            .returning(quests::id)
+            .get_result::<i32>(&mut conn)?;
 
         Ok(())
     }
+    async fn deleted_by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+        deleted_after: NaiveDateTime,
+    ) -> Result<Vec<QuestEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quests::table
+            .filter(quests::guild_commander_id.eq(guild_commander_id))
+            .filter(quests::deleted_at.gt(deleted_after))
+            .select(QuestEntity::as_select())
+            .order_by(quests::deleted_at.desc())
+            .load::<QuestEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn restore(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        deleted_after: NaiveDateTime,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(quests::table)
+            .filter(quests::id.eq(quest_id))
+            .filter(quests::guild_commander_id.eq(guild_commander_id))
+            .filter(quests::deleted_at.gt(deleted_after))
+            .set((
+                quests::deleted_at.eq(None::<NaiveDateTime>),
+                quests::updated_at.eq(chrono::Utc::now().naive_utc()),
+                quests::version.eq(quests::version + 1),
+            ))
+            .returning(quests::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn purge_deleted_before(
+        &self,
+        deleted_before: NaiveDateTime,
+    ) -> Result<(Vec<i32>, Vec<String>)> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let quest_ids = quests::table
+                .filter(quests::deleted_at.lt(deleted_before))
+                .select(quests::id)
+                .for_update()
+                .load::<i32>(conn)?;
+
+            // แถวไฟล์แนบหายไปกับ CASCADE จึงต้องเก็บ key ไว้ก่อนลบ
+            let storage_keys = quest_attachments::table
+                .filter(quest_attachments::quest_id.eq_any(&quest_ids))
+                .select(quest_attachments::storage_key)
+                .load::<String>(conn)?;
+
+            // ตารางที่ผูกกับ quest ถูกลบตามด้วย ON DELETE CASCADE
+            let purged_quest_ids = diesel::delete(quests::table)
+                .filter(quests::id.eq_any(&quest_ids))
+                .returning(quests::id)
+                .get_results::<i32>(conn)?;
+
+            Ok((purged_quest_ids, storage_keys))
+        })?;
+
+        Ok(results)
+    }
+
+    async fn set_tags(&self, quest_id: i32, tag_names: Vec<String>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            replace_quest_tags(conn, quest_id, &tag_names)
+        })?;
+
+        Ok(())
+    }
+}
+
+// ใช้ภายใน transaction ของผู้เรียก เพื่อให้ quest กับ tag ถูกสร้างพร้อมกันหรือไม่สร้างเลย
+pub fn insert_quest(
+    conn: &mut PgConnection,
+    add_quest_entity: AddQuestEntity,
+    tag_names: &[String],
+) -> Result<i32> {
+    // quest ของ guild สร้างได้เฉพาะ guild commander ที่เป็นสมาชิก guild นั้น
+    if let Some(guild_id) = add_quest_entity.guild_id {
+        ensure_guild_member(conn, guild_id, add_quest_entity.guild_commander_id)?;
+    }
+
+    let quest_id = insert_into(quests::table)
+        .values(add_quest_entity)
+        .returning(quests::id)
+        .get_result::<i32>(conn)?;
+
+    if !tag_names.is_empty() {
+        replace_quest_tags(conn, quest_id, tag_names)?;
+    }
+
+    Ok(quest_id)
+}
+
+fn ensure_guild_member(
+    conn: &mut PgConnection,
+    guild_id: i32,
+    guild_commander_id: i32,
+) -> Result<()> {
+    let is_member = diesel::select(diesel::dsl::exists(
+        guild_members::table
+            .filter(guild_members::guild_id.eq(guild_id))
+            .filter(guild_members::guild_commander_id.eq(guild_commander_id)),
+    ))
+    .get_result::<bool>(conn)?;
+
+    if !is_member {
+        return Err(anyhow::anyhow!(
+            "Guild commander is not a member of this guild"
+        ));
+    }
+
+    Ok(())
+}
+
+fn replace_quest_tags(conn: &mut PgConnection, quest_id: i32, tag_names: &[String]) -> Result<()> {
+    let add_tag_entities = tag_names
+        .iter()
+        .map(|name| AddTagEntity {
+            name: name.clone(),
+            created_at: chrono::Utc::now().naive_utc(),
+        })
+        .collect::<Vec<AddTagEntity>>();
+
+    insert_into(tags::table)
+        .values(&add_tag_entities)
+        .on_conflict(tags::name)
+        .do_nothing()
+        .execute(conn)?;
+
+    let tag_ids = tags::table
+        .filter(tags::name.eq_any(tag_names))
+        .select(tags::id)
+        .load::<i32>(conn)?;
+
+    diesel::delete(quest_tags::table)
+        .filter(quest_tags::quest_id.eq(quest_id))
+        .execute(conn)?;
+
+    let quest_tag_entities = tag_ids
+        .into_iter()
+        .map(|tag_id| QuestTagEntity { quest_id, tag_id })
+        .collect::<Vec<QuestTagEntity>>();
+
+    insert_into(quest_tags::table)
+        .values(&quest_tag_entities)
+        .execute(conn)?;
+
+    Ok(())
+}
+
+// ล็อกแถว quest ไว้จนจบ transaction แล้วเทียบ version กับ If-Match
+pub fn lock_quest_version(
+    conn: &mut PgConnection,
+    quest_id: i32,
+    expected_version: Option<i32>,
+) -> Result<()> {
+    let Some(expected_version) = expected_version else {
+        return Ok(());
+    };
+
+    let current_version = quests::table
+        .filter(quests::id.eq(quest_id))
+        .select(quests::version)
+        .for_update()
+        .first::<i32>(conn)?;
+
+    if current_version != expected_version {
+        return Err(QuestVersionMismatch {
+            expected: expected_version,
+            current: current_version,
+        }
+        .into());
+    }
+
+    Ok(())
 }
@@ -0,0 +1,145 @@
+use anyhow::Result;
+use axum::async_trait;
+use diesel::{insert_into, prelude::*};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::{
+            leaderboards::AddGuildCommanderStatsDailyEntity,
+            quest_ratings::{AddQuestRatingEntity, QuestRatingEntity},
+        },
+        repositories::quest_ratings::QuestRatingsRepository,
+        value_objects::rating_directions::RatingDirections,
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::leaderboards::record_guild_commander_stats,
+        schema::{quest_final_crews, quest_ratings},
+    },
+};
+
+pub struct QuestRatingsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl QuestRatingsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QuestRatingsRepository for QuestRatingsPostgres {
+    async fn add(&self, add_quest_rating_entity: AddQuestRatingEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let rating_id = insert_into(quest_ratings::table)
+                .values(&add_quest_rating_entity)
+                .returning(quest_ratings::id)
+                .get_result::<i32>(conn)?;
+
+            // คะแนนจาก crew นับเป็นความพึงพอใจของ commander บน leaderboard
+            if add_quest_rating_entity.direction
+                == RatingDirections::AdventurerToCommander.to_string()
+            {
+                record_guild_commander_stats(
+                    conn,
+                    AddGuildCommanderStatsDailyEntity {
+                        guild_commander_id: add_quest_rating_entity.guild_commander_id,
+                        stat_date: add_quest_rating_entity.created_at.date(),
+                        completed_count: 0,
+                        failed_count: 0,
+                        rating_score_total: add_quest_rating_entity.score,
+                        ratings_count: 1,
+                    },
+                )?;
+            }
+
+            Ok(rating_id)
+        })?;
+
+        Ok(result)
+    }
+
+    async fn is_rated(
+        &self,
+        quest_id: i32,
+        guild_commander_id: i32,
+        adventurer_id: i32,
+        direction: RatingDirections,
+    ) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::select(diesel::dsl::exists(
+            quest_ratings::table
+                .filter(quest_ratings::quest_id.eq(quest_id))
+                .filter(quest_ratings::guild_commander_id.eq(guild_commander_id))
+                .filter(quest_ratings::adventurer_id.eq(adventurer_id))
+                .filter(quest_ratings::direction.eq(direction.to_string())),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn is_final_crew_member(&self, quest_id: i32, adventurer_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::select(diesel::dsl::exists(
+            quest_final_crews::table
+                .filter(quest_final_crews::quest_id.eq(quest_id))
+                .filter(quest_final_crews::adventurer_id.eq(adventurer_id)),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestRatingEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_ratings::table
+            .filter(quest_ratings::quest_id.eq(quest_id))
+            .select(QuestRatingEntity::as_select())
+            .order_by(quest_ratings::created_at.asc())
+            .load::<QuestRatingEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn adventurer_score_totals(&self, adventurer_id: i32) -> Result<(i64, i64)> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let (score_total, ratings_count) = quest_ratings::table
+            .filter(quest_ratings::adventurer_id.eq(adventurer_id))
+            .filter(
+                quest_ratings::direction.eq(RatingDirections::CommanderToAdventurer.to_string()),
+            )
+            .select((
+                diesel::dsl::sum(quest_ratings::score),
+                diesel::dsl::count(quest_ratings::id),
+            ))
+            .first::<(Option<i64>, i64)>(&mut conn)?;
+
+        Ok((score_total.unwrap_or(0), ratings_count))
+    }
+
+    async fn guild_commander_score_totals(&self, guild_commander_id: i32) -> Result<(i64, i64)> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let (score_total, ratings_count) = quest_ratings::table
+            .filter(quest_ratings::guild_commander_id.eq(guild_commander_id))
+            .filter(
+                quest_ratings::direction.eq(RatingDirections::AdventurerToCommander.to_string()),
+            )
+            .select((
+                diesel::dsl::sum(quest_ratings::score),
+                diesel::dsl::count(quest_ratings::id),
+            ))
+            .first::<(Option<i64>, i64)>(&mut conn)?;
+
+        Ok((score_total.unwrap_or(0), ratings_count))
+    }
+}
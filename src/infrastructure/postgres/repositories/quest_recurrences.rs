@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{insert_into, prelude::*};
+
+use crate::{
+    domain::{
+        entities::{
+            quest_recurrences::{
+                AddQuestRecurrenceEntity, QuestRecurrenceEntity, QuestRecurrenceInstanceEntity,
+            },
+            quests::AddQuestEntity,
+        },
+        repositories::quest_recurrences::QuestRecurrencesRepository,
+        value_objects::{quest_statuses::QuestStatuses, recurrence_statuses::RecurrenceStatuses},
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::quest_ops::insert_quest,
+        schema::{quest_recurrence_instances, quest_recurrences, quests},
+    },
+};
+
+pub struct QuestRecurrencesPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl QuestRecurrencesPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QuestRecurrencesRepository for QuestRecurrencesPostgres {
+    async fn add(&self, add_quest_recurrence_entity: AddQuestRecurrenceEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(quest_recurrences::table)
+            .values(add_quest_recurrence_entity)
+            .returning(quest_recurrences::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn view_details(&self, quest_recurrence_id: i32) -> Result<QuestRecurrenceEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_recurrences::table
+            .filter(quest_recurrences::id.eq(quest_recurrence_id))
+            .select(QuestRecurrenceEntity::as_select())
+            .first::<QuestRecurrenceEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestRecurrenceEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_recurrences::table
+            .filter(quest_recurrences::guild_commander_id.eq(guild_commander_id))
+            .select(QuestRecurrenceEntity::as_select())
+            .order_by(quest_recurrences::created_at.desc())
+            .load::<QuestRecurrenceEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn update_status(
+        &self,
+        quest_recurrence_id: i32,
+        status: RecurrenceStatuses,
+        next_run_at: NaiveDateTime,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(quest_recurrences::table)
+            .filter(quest_recurrences::id.eq(quest_recurrence_id))
+            .set((
+                quest_recurrences::status.eq(status.to_string()),
+                quest_recurrences::next_run_at.eq(next_run_at),
+                quest_recurrences::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn due(&self, now: NaiveDateTime) -> Result<Vec<QuestRecurrenceEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_recurrences::table
+            .filter(quest_recurrences::status.eq(RecurrenceStatuses::Active.to_string()))
+            .filter(quest_recurrences::next_run_at.le(now))
+            .select(QuestRecurrenceEntity::as_select())
+            .order_by(quest_recurrences::next_run_at.asc())
+            .load::<QuestRecurrenceEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn open_instances_count(&self, quest_recurrence_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_recurrence_instances::table
+            .inner_join(quests::table)
+            .filter(quest_recurrence_instances::quest_recurrence_id.eq(quest_recurrence_id))
+            .filter(quests::status.eq(QuestStatuses::Open.to_string()))
+            .filter(quests::deleted_at.is_null())
+            .count()
+            .first::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn record_run(
+        &self,
+        quest_recurrence_id: i32,
+        spawned_quest: Option<(AddQuestEntity, Vec<String>)>,
+        ran_at: NaiveDateTime,
+        next_run_at: NaiveDateTime,
+    ) -> Result<Option<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // quest ใหม่กับการเลื่อนรอบถัดไปต้องสำเร็จพร้อมกัน ไม่งั้นรอบหน้าจะ spawn ซ้ำ
+        let result = conn.transaction::<_, anyhow::Error, _>(|conn| {
+            let quest_id = match spawned_quest {
+                Some((add_quest_entity, tag_names)) => {
+                    let quest_id = insert_quest(conn, add_quest_entity, &tag_names)?;
+
+                    insert_into(quest_recurrence_instances::table)
+                        .values(QuestRecurrenceInstanceEntity {
+                            quest_recurrence_id,
+                            quest_id,
+                            created_at: ran_at,
+                        })
+                        .execute(conn)?;
+
+                    Some(quest_id)
+                }
+                None => None,
+            };
+
+            diesel::update(quest_recurrences::table)
+                .filter(quest_recurrences::id.eq(quest_recurrence_id))
+                .set((
+                    quest_recurrences::last_run_at.eq(ran_at),
+                    quest_recurrences::next_run_at.eq(next_run_at),
+                    quest_recurrences::updated_at.eq(ran_at),
+                ))
+                .execute(conn)?;
+
+            Ok(quest_id)
+        })?;
+
+        Ok(result)
+    }
+}
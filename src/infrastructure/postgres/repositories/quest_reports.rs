@@ -0,0 +1,89 @@
+use anyhow::Result;
+use axum::async_trait;
+use diesel::{insert_into, prelude::*};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::quest_reports::{
+            AddQuestAttachmentEntity, QuestAttachmentEntity, QuestReportEntity,
+        },
+        repositories::quest_reports::QuestReportsRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        schema::{quest_attachments, quest_reports},
+    },
+};
+
+pub struct QuestReportsPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl QuestReportsPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QuestReportsRepository for QuestReportsPostgres {
+    async fn reports_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestReportEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_reports::table
+            .filter(quest_reports::quest_id.eq(quest_id))
+            .select(QuestReportEntity::as_select())
+            .order_by(quest_reports::created_at.asc())
+            .load::<QuestReportEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn add_attachment(
+        &self,
+        add_quest_attachment_entity: AddQuestAttachmentEntity,
+    ) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(quest_attachments::table)
+            .values(add_quest_attachment_entity)
+            .returning(quest_attachments::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn attachment(&self, attachment_id: i32) -> Result<QuestAttachmentEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_attachments::table
+            .filter(quest_attachments::id.eq(attachment_id))
+            .select(QuestAttachmentEntity::as_select())
+            .first::<QuestAttachmentEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn attachments_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestAttachmentEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_attachments::table
+            .filter(quest_attachments::quest_id.eq(quest_id))
+            .select(QuestAttachmentEntity::as_select())
+            .order_by(quest_attachments::created_at.asc())
+            .load::<QuestAttachmentEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn remove_attachment(&self, attachment_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(quest_attachments::table)
+            .filter(quest_attachments::id.eq(attachment_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::async_trait;
+use diesel::{insert_into, prelude::*};
+
+use crate::{
+    domain::{
+        entities::quest_templates::{AddQuestTemplateEntity, QuestTemplateEntity},
+        repositories::quest_templates::QuestTemplatesRepository,
+    },
+    infrastructure::postgres::{postgres_connection::PgPoolSquad, schema::quest_templates},
+};
+
+pub struct QuestTemplatesPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl QuestTemplatesPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl QuestTemplatesRepository for QuestTemplatesPostgres {
+    async fn add(&self, add_quest_template_entity: AddQuestTemplateEntity) -> Result<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = insert_into(quest_templates::table)
+            .values(add_quest_template_entity)
+            .returning(quest_templates::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn view_details(&self, quest_template_id: i32) -> Result<QuestTemplateEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_templates::table
+            .filter(quest_templates::id.eq(quest_template_id))
+            .select(QuestTemplateEntity::as_select())
+            .first::<QuestTemplateEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn by_guild_commander_id(
+        &self,
+        guild_commander_id: i32,
+    ) -> Result<Vec<QuestTemplateEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_templates::table
+            .filter(quest_templates::guild_commander_id.eq(guild_commander_id))
+            .select(QuestTemplateEntity::as_select())
+            .order_by(quest_templates::created_at.desc())
+            .load::<QuestTemplateEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn remove(&self, quest_template_id: i32, guild_commander_id: i32) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let deleted = diesel::delete(quest_templates::table)
+            .filter(quest_templates::id.eq(quest_template_id))
+            .filter(quest_templates::guild_commander_id.eq(guild_commander_id))
+            .execute(&mut conn)?;
+
+        if deleted == 0 {
+            return Err(anyhow::anyhow!("Quest template not found"));
+        }
+
+        Ok(())
+    }
+}
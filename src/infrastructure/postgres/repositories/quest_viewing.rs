@@ -2,16 +2,34 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use axum::async_trait;
-use diesel::prelude::*;
+use chrono::NaiveDateTime;
+use diesel::{
+    dsl::sql,
+    expression::SqlLiteral,
+    pg::Pg,
+    prelude::*,
+    sql_types::{BigInt, Bool, Float4, Text},
+};
 
 use crate::{
     domain::{
-        entities::quests::QuestEntity, repositories::quest_viewing::QuestViewingRepository,
-        value_objects::board_checking_filter::BoardCheckingFilter,
+        entities::{quest_revisions::QuestRevisionEntity, quests::QuestEntity},
+        repositories::quest_viewing::QuestViewingRepository,
+        value_objects::{
+            board_checking_filter::{
+                BoardCheckingFilter, SEARCH_HIGHLIGHT_START, SEARCH_HIGHLIGHT_STOP, TagMatchModes,
+            },
+            quest_participants::QuestParticipants,
+            quest_statuses::QuestStatuses,
+            quest_visibilities::QuestVisibilities,
+        },
     },
     infrastructure::postgres::{
         postgres_connection::PgPoolSquad,
-        schema::{quest_adventurer_junction, quests},
+        schema::{
+            guild_members, quest_adventurer_junction, quest_checkpoints, quest_revisions,
+            quest_tags, quests, tags,
+        },
     },
 };
 
@@ -27,32 +45,38 @@ impl QuestViewingPostgres {
 
 #[async_trait]
 impl QuestViewingRepository for QuestViewingPostgres {
-    async fn view_details(&self, quest_id: i32) -> Result<QuestEntity> {
-        println!("view_details: quest_idx = {}", quest_id);
+    async fn view_details(
+        &self,
+        quest_id: i32,
+        viewer: Option<QuestParticipants>,
+    ) -> Result<QuestEntity> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = match quests::table
+        let query = quests::table
             .filter(quests::id.eq(quest_id))
             .filter(quests::deleted_at.is_null())
+            .into_boxed();
+
+        let result = visible_to(query, viewer)
             .select(QuestEntity::as_select())
-            .first::<QuestEntity>(&mut conn)
-        {
-            Ok(res) => res,
-            Err(e) => {
-                println!("view_details: error = {}", e.to_string());
-                return Err(e.into());
-            }
-        };
+            .first::<QuestEntity>(&mut conn)?;
 
         Ok(result)
     }
 
-    async fn board_checking(&self, filter: &BoardCheckingFilter) -> Result<Vec<QuestEntity>> {
+    async fn board_checking(
+        &self,
+        filter: &BoardCheckingFilter,
+        viewer: Option<QuestParticipants>,
+    ) -> Result<Vec<QuestEntity>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let mut query = quests::table
-            .filter(quests::deleted_at.is_null())
-            .into_boxed();
+        let mut query = visible_to(
+            quests::table
+                .filter(quests::deleted_at.is_null())
+                .into_boxed(),
+            viewer,
+        );
 
         if let Some(status) = &filter.status {
             query = query.filter(quests::status.eq(status.to_string()));
@@ -62,14 +86,144 @@ impl QuestViewingRepository for QuestViewingPostgres {
             query = query.filter(quests::name.ilike(format!("%{}%", name)));
         }
 
+        let status_names = filter.status_names()?;
+
+        if !status_names.is_empty() {
+            query = query.filter(quests::status.eq_any(status_names));
+        }
+
+        if let Some(guild_commander_id) = filter.guild_commander_id {
+            query = query.filter(quests::guild_commander_id.eq(guild_commander_id));
+        }
+
+        if let Some(created_from) = filter.created_from {
+            query = query.filter(quests::created_at.ge(created_from));
+        }
+
+        if let Some(created_to) = filter.created_to {
+            query = query.filter(quests::created_at.le(created_to));
+        }
+
+        if let Some(updated_from) = filter.updated_from {
+            query = query.filter(quests::updated_at.ge(updated_from));
+        }
+
+        if let Some(updated_to) = filter.updated_to {
+            query = query.filter(quests::updated_at.le(updated_to));
+        }
+
+        if let Some(has_free_slots) = filter.has_free_slots {
+            let operator = if has_free_slots { "<" } else { ">=" };
+            query = query.filter(sql::<Bool>(&format!(
+                "{} {} quests.max_crew",
+                CREW_COUNT_SQL, operator
+            )));
+        }
+
+        if let Some(min_crew_count) = filter.min_crew_count {
+            query = query.filter(crew_count_sql().ge(min_crew_count));
+        }
+
+        if let Some(max_crew_count) = filter.max_crew_count {
+            query = query.filter(crew_count_sql().le(max_crew_count));
+        }
+
+        if filter.exclude_joined.unwrap_or(false)
+            && let Some(adventurer_id) = viewer.and_then(|viewer| viewer.adventurer_id())
+        {
+            query = query.filter(diesel::dsl::not(diesel::dsl::exists(
+                quest_adventurer_junction::table
+                    .filter(quest_adventurer_junction::quest_id.eq(quests::id))
+                    .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id)),
+            )));
+        }
+
+        let tag_names = filter.tag_names();
+
+        if !tag_names.is_empty() {
+            let tagged_quest_ids = match filter.tag_match.clone().unwrap_or_default() {
+                TagMatchModes::Any => quest_tags::table
+                    .inner_join(tags::table)
+                    .filter(tags::name.eq_any(&tag_names))
+                    .select(quest_tags::quest_id)
+                    .distinct()
+                    .load::<i32>(&mut conn)?,
+                TagMatchModes::All => quest_tags::table
+                    .inner_join(tags::table)
+                    .filter(tags::name.eq_any(&tag_names))
+                    .group_by(quest_tags::quest_id)
+                    .having(diesel::dsl::count(quest_tags::tag_id).eq(tag_names.len() as i64))
+                    .select(quest_tags::quest_id)
+                    .load::<i32>(&mut conn)?,
+            };
+
+            query = query.filter(quests::id.eq_any(tagged_quest_ids));
+        }
+
+        let search_text = filter.search_text();
+
+        if let Some(q) = &search_text {
+            query = query.filter(
+                sql::<Bool>("quests.search_vector @@ websearch_to_tsquery('english', ")
+                    .bind::<Text, _>(q.clone())
+                    .sql(")"),
+            );
+        }
+
+        let query = match search_text {
+            Some(q) => query.order_by((
+                sql::<Float4>("ts_rank(quests.search_vector, websearch_to_tsquery('english', ")
+                    .bind::<Text, _>(q)
+                    .sql("))")
+                    .desc(),
+                quests::created_at.desc(),
+            )),
+            None => query.order_by(quests::created_at.desc()),
+        };
+
         let results = query
             .select(QuestEntity::as_select())
-            .order_by(quests::created_at.desc())
             .load::<QuestEntity>(&mut conn)?;
 
         Ok(results)
     }
 
+    async fn search_highlights(
+        &self,
+        q: &str,
+        quest_ids: &[i32],
+    ) -> Result<Vec<(i32, f32, String)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // ลบตัวคั่นที่อาจอยู่ในข้อความเดิมออกก่อน เพื่อให้ตัวคั่นใน headline มาจาก ts_headline เท่านั้น
+        let highlight_delimiters = format!("{}{}", SEARCH_HIGHLIGHT_START, SEARCH_HIGHLIGHT_STOP);
+        let headline_options = format!(
+            "StartSel={}, StopSel={}, MaxFragments=2",
+            SEARCH_HIGHLIGHT_START, SEARCH_HIGHLIGHT_STOP
+        );
+
+        let results = quests::table
+            .filter(quests::id.eq_any(quest_ids))
+            .select((
+                quests::id,
+                sql::<Float4>("ts_rank(quests.search_vector, websearch_to_tsquery('english', ")
+                    .bind::<Text, _>(q.to_string())
+                    .sql("))"),
+                sql::<Text>(
+                    "ts_headline('english', translate(quests.name || ' ' || coalesce(quests.description, ''), ",
+                )
+                .bind::<Text, _>(highlight_delimiters)
+                .sql(", ''), websearch_to_tsquery('english', ")
+                .bind::<Text, _>(q.to_string())
+                .sql("), ")
+                .bind::<Text, _>(headline_options)
+                .sql(")"),
+            ))
+            .load::<(i32, f32, String)>(&mut conn)?;
+
+        Ok(results)
+    }
+
     async fn adventurers_counting_by_quest_id(&self, quest_id: i32) -> Result<i64> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
@@ -80,4 +234,144 @@ impl QuestViewingRepository for QuestViewingPostgres {
 
         Ok(result)
     }
+
+    async fn tags_by_quest_id(&self, quest_id: i32) -> Result<Vec<String>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_tags::table
+            .inner_join(tags::table)
+            .filter(quest_tags::quest_id.eq(quest_id))
+            .select(tags::name)
+            .order_by(tags::name.asc())
+            .load::<String>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn tags_with_counts(&self) -> Result<Vec<(String, i64)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let quests_count = diesel::dsl::count(quest_tags::quest_id);
+
+        let results = quest_tags::table
+            .inner_join(tags::table)
+            .inner_join(quests::table)
+            .filter(quests::deleted_at.is_null())
+            // นับเฉพาะ quest สาธารณะ ไม่ให้จำนวนเผย quest ของ guild
+            .filter(quests::visibility.eq(QuestVisibilities::Public.to_string()))
+            .group_by(tags::name)
+            .select((tags::name, quests_count))
+            .order_by((quests_count.desc(), tags::name.asc()))
+            .load::<(String, i64)>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn revisions_by_quest_id(&self, quest_id: i32) -> Result<Vec<QuestRevisionEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quest_revisions::table
+            .filter(quest_revisions::quest_id.eq(quest_id))
+            .select(QuestRevisionEntity::as_select())
+            .order_by(quest_revisions::revision_number.asc())
+            .load::<QuestRevisionEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn pending_reconfirmations_count(&self, quest_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_adventurer_junction::table
+            .filter(quest_adventurer_junction::quest_id.eq(quest_id))
+            .filter(quest_adventurer_junction::reconfirmation_required.eq(true))
+            .count()
+            .first::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn checkpoints_counting_by_quest_id(&self, quest_id: i32) -> Result<(i64, i64)> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let total_count = quest_checkpoints::table
+            .filter(quest_checkpoints::quest_id.eq(quest_id))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        let done_count = quest_checkpoints::table
+            .filter(quest_checkpoints::quest_id.eq(quest_id))
+            .filter(quest_checkpoints::completed_at.is_not_null())
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok((done_count, total_count))
+    }
+
+    async fn pending_mandatory_checkpoints_count(&self, quest_id: i32) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = quest_checkpoints::table
+            .filter(quest_checkpoints::quest_id.eq(quest_id))
+            .filter(quest_checkpoints::mandatory.eq(true))
+            .filter(quest_checkpoints::completed_at.is_null())
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn overdue_in_journey(&self, now: NaiveDateTime) -> Result<Vec<QuestEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = quests::table
+            .filter(quests::deleted_at.is_null())
+            .filter(quests::status.eq(QuestStatuses::InJourney.to_string()))
+            .filter(quests::due_at.lt(now))
+            .select(QuestEntity::as_select())
+            .order_by(quests::due_at.asc())
+            .load::<QuestEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+}
+
+const CREW_COUNT_SQL: &str = "(SELECT COUNT(*) FROM quest_adventurer_junction WHERE quest_adventurer_junction.quest_id = quests.id)";
+
+fn crew_count_sql() -> SqlLiteral<BigInt> {
+    sql::<BigInt>(CREW_COUNT_SQL)
+}
+
+// quest แบบ GuildOnly เห็นได้เฉพาะสมาชิก guild, guild commander เจ้าของ quest และ crew ของ quest
+fn visible_to(
+    query: quests::BoxedQuery<'_, Pg>,
+    viewer: Option<QuestParticipants>,
+) -> quests::BoxedQuery<'_, Pg> {
+    let is_public = quests::visibility.eq(QuestVisibilities::Public.to_string());
+
+    match viewer {
+        None => query.filter(is_public),
+        Some(QuestParticipants::Adventurer(adventurer_id)) => query.filter(
+            is_public
+                .or(diesel::dsl::exists(
+                    guild_members::table
+                        .filter(guild_members::guild_id.nullable().eq(quests::guild_id))
+                        .filter(guild_members::adventurer_id.eq(adventurer_id)),
+                ))
+                .or(diesel::dsl::exists(
+                    quest_adventurer_junction::table
+                        .filter(quest_adventurer_junction::quest_id.eq(quests::id))
+                        .filter(quest_adventurer_junction::adventurer_id.eq(adventurer_id)),
+                )),
+        ),
+        Some(QuestParticipants::GuildCommander(guild_commander_id)) => query.filter(
+            is_public
+                .or(quests::guild_commander_id.eq(guild_commander_id))
+                .or(diesel::dsl::exists(
+                    guild_members::table
+                        .filter(guild_members::guild_id.nullable().eq(quests::guild_id))
+                        .filter(guild_members::guild_commander_id.eq(guild_commander_id)),
+                )),
+        ),
+    }
 }
@@ -1,5 +1,46 @@
 // @generated automatically by Diesel CLI.
 
+pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "tsvector", schema = "pg_catalog"))]
+    pub struct Tsvector;
+}
+
+diesel::table! {
+    adventurer_achievements (id) {
+        id -> Int4,
+        adventurer_id -> Int4,
+        #[max_length = 100]
+        badge_code -> Varchar,
+        quest_id -> Nullable<Int4>,
+        awarded_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    adventurer_ledger (id) {
+        id -> Int4,
+        adventurer_id -> Int4,
+        quest_id -> Nullable<Int4>,
+        #[max_length = 255]
+        entry_type -> Varchar,
+        gold -> Int4,
+        experience -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    adventurer_stats_daily (adventurer_id, stat_date) {
+        adventurer_id -> Int4,
+        stat_date -> Date,
+        completed_count -> Int4,
+        failed_count -> Int4,
+        gold_earned -> Int4,
+        experience_earned -> Int4,
+    }
+}
+
 diesel::table! {
     adventurers (id) {
         id -> Int4,
@@ -12,6 +53,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    guild_commander_stats_daily (guild_commander_id, stat_date) {
+        guild_commander_id -> Int4,
+        stat_date -> Date,
+        completed_count -> Int4,
+        failed_count -> Int4,
+        rating_score_total -> Int4,
+        ratings_count -> Int4,
+    }
+}
+
 diesel::table! {
     guild_commanders (id) {
         id -> Int4,
@@ -24,14 +76,253 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    guild_members (id) {
+        id -> Int4,
+        guild_id -> Int4,
+        guild_commander_id -> Nullable<Int4>,
+        adventurer_id -> Nullable<Int4>,
+        #[max_length = 50]
+        role -> Varchar,
+        joined_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    guilds (id) {
+        id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    idempotency_keys (id) {
+        id -> Int4,
+        #[max_length = 255]
+        user_scope -> Varchar,
+        #[max_length = 255]
+        idempotency_key -> Varchar,
+        #[max_length = 16]
+        request_method -> Varchar,
+        request_path -> Text,
+        status_code -> Nullable<Int4>,
+        #[max_length = 255]
+        content_type -> Nullable<Varchar>,
+        response_body -> Nullable<Bytea>,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+        #[max_length = 64]
+        request_fingerprint -> Varchar,
+        #[max_length = 255]
+        etag -> Nullable<Varchar>,
+        location -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     quest_adventurer_junction (quest_id, adventurer_id) {
         quest_id -> Int4,
         adventurer_id -> Int4,
+        joined_at -> Timestamp,
+        reconfirmation_required -> Bool,
+    }
+}
+
+diesel::table! {
+    quest_attachments (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        adventurer_id -> Int4,
+        #[max_length = 255]
+        file_name -> Varchar,
+        #[max_length = 100]
+        content_type -> Varchar,
+        size_bytes -> Int8,
+        #[max_length = 255]
+        storage_key -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_checkpoints (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        #[max_length = 255]
+        title -> Varchar,
+        description -> Nullable<Text>,
+        mandatory -> Bool,
+        position -> Int4,
+        completed_by_adventurer_id -> Nullable<Int4>,
+        completion_note -> Nullable<Text>,
+        completed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_comments (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        parent_comment_id -> Nullable<Int4>,
+        adventurer_id -> Nullable<Int4>,
+        guild_commander_id -> Nullable<Int4>,
+        body -> Text,
+        moderated -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    quest_crew_bans (quest_id, adventurer_id) {
+        quest_id -> Int4,
+        adventurer_id -> Int4,
+        guild_commander_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_final_crews (quest_id, adventurer_id) {
+        quest_id -> Int4,
+        adventurer_id -> Int4,
+        created_at -> Timestamp,
     }
 }
 
 diesel::table! {
+    quest_invitations (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        adventurer_id -> Int4,
+        guild_commander_id -> Int4,
+        expires_at -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_join_applications (quest_id, adventurer_id) {
+        quest_id -> Int4,
+        adventurer_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_ratings (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        guild_commander_id -> Int4,
+        adventurer_id -> Int4,
+        #[max_length = 50]
+        direction -> Varchar,
+        score -> Int4,
+        comment -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_recurrence_instances (quest_recurrence_id, quest_id) {
+        quest_recurrence_id -> Int4,
+        quest_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_recurrences (id) {
+        id -> Int4,
+        guild_commander_id -> Int4,
+        quest_template_id -> Int4,
+        #[max_length = 255]
+        schedule -> Varchar,
+        max_open_instances -> Int4,
+        #[max_length = 255]
+        status -> Varchar,
+        next_run_at -> Timestamp,
+        last_run_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_reports (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        guild_commander_id -> Int4,
+        #[max_length = 50]
+        outcome -> Varchar,
+        body -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_revisions (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        revision_number -> Int4,
+        guild_commander_id -> Int4,
+        changes -> Jsonb,
+        requires_reconfirmation -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    quest_tags (quest_id, tag_id) {
+        quest_id -> Int4,
+        tag_id -> Int4,
+    }
+}
+
+diesel::table! {
+    quest_templates (id) {
+        id -> Int4,
+        guild_commander_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        min_crew -> Int4,
+        max_crew -> Int4,
+        #[max_length = 255]
+        join_policy -> Varchar,
+        reward_gold -> Int4,
+        reward_experience -> Int4,
+        min_level -> Int4,
+        tags -> Array<Nullable<Text>>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        require_checkpoints -> Bool,
+        guild_id -> Nullable<Int4>,
+        #[max_length = 255]
+        visibility -> Varchar,
+    }
+}
+
+diesel::table! {
+    quest_waitlist (id) {
+        id -> Int4,
+        quest_id -> Int4,
+        adventurer_id -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Tsvector;
+
     quests (id) {
         id -> Int4,
         #[max_length = 255]
@@ -43,16 +334,108 @@ diesel::table! {
         created_at -> Timestamp,
         updated_at -> Timestamp,
         deleted_at -> Nullable<Timestamp>,
+        min_crew -> Int4,
+        max_crew -> Int4,
+        #[max_length = 255]
+        join_policy -> Varchar,
+        starts_at -> Nullable<Timestamp>,
+        join_deadline -> Nullable<Timestamp>,
+        due_at -> Nullable<Timestamp>,
+        reward_gold -> Int4,
+        reward_experience -> Int4,
+        min_level -> Int4,
+        search_vector -> Nullable<Tsvector>,
+        version -> Int4,
+        require_checkpoints -> Bool,
+        guild_id -> Nullable<Int4>,
+        #[max_length = 255]
+        visibility -> Varchar,
+    }
+}
+
+diesel::table! {
+    tags (id) {
+        id -> Int4,
+        #[max_length = 64]
+        name -> Varchar,
+        created_at -> Timestamp,
     }
 }
 
+diesel::joinable!(adventurer_achievements -> adventurers (adventurer_id));
+diesel::joinable!(adventurer_achievements -> quests (quest_id));
+diesel::joinable!(adventurer_ledger -> adventurers (adventurer_id));
+diesel::joinable!(adventurer_ledger -> quests (quest_id));
+diesel::joinable!(adventurer_stats_daily -> adventurers (adventurer_id));
+diesel::joinable!(guild_commander_stats_daily -> guild_commanders (guild_commander_id));
+diesel::joinable!(guild_members -> adventurers (adventurer_id));
+diesel::joinable!(guild_members -> guild_commanders (guild_commander_id));
+diesel::joinable!(guild_members -> guilds (guild_id));
 diesel::joinable!(quest_adventurer_junction -> adventurers (adventurer_id));
 diesel::joinable!(quest_adventurer_junction -> quests (quest_id));
+diesel::joinable!(quest_attachments -> adventurers (adventurer_id));
+diesel::joinable!(quest_attachments -> quests (quest_id));
+diesel::joinable!(quest_checkpoints -> adventurers (completed_by_adventurer_id));
+diesel::joinable!(quest_checkpoints -> quests (quest_id));
+diesel::joinable!(quest_comments -> adventurers (adventurer_id));
+diesel::joinable!(quest_comments -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_comments -> quests (quest_id));
+diesel::joinable!(quest_crew_bans -> adventurers (adventurer_id));
+diesel::joinable!(quest_crew_bans -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_crew_bans -> quests (quest_id));
+diesel::joinable!(quest_final_crews -> adventurers (adventurer_id));
+diesel::joinable!(quest_final_crews -> quests (quest_id));
+diesel::joinable!(quest_invitations -> adventurers (adventurer_id));
+diesel::joinable!(quest_invitations -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_invitations -> quests (quest_id));
+diesel::joinable!(quest_join_applications -> adventurers (adventurer_id));
+diesel::joinable!(quest_join_applications -> quests (quest_id));
+diesel::joinable!(quest_ratings -> adventurers (adventurer_id));
+diesel::joinable!(quest_ratings -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_ratings -> quests (quest_id));
+diesel::joinable!(quest_recurrence_instances -> quest_recurrences (quest_recurrence_id));
+diesel::joinable!(quest_recurrence_instances -> quests (quest_id));
+diesel::joinable!(quest_recurrences -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_recurrences -> quest_templates (quest_template_id));
+diesel::joinable!(quest_reports -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_reports -> quests (quest_id));
+diesel::joinable!(quest_revisions -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_revisions -> quests (quest_id));
+diesel::joinable!(quest_tags -> quests (quest_id));
+diesel::joinable!(quest_tags -> tags (tag_id));
+diesel::joinable!(quest_templates -> guild_commanders (guild_commander_id));
+diesel::joinable!(quest_templates -> guilds (guild_id));
+diesel::joinable!(quest_waitlist -> adventurers (adventurer_id));
+diesel::joinable!(quest_waitlist -> quests (quest_id));
 diesel::joinable!(quests -> guild_commanders (guild_commander_id));
+diesel::joinable!(quests -> guilds (guild_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    adventurer_achievements,
+    adventurer_ledger,
+    adventurer_stats_daily,
     adventurers,
+    guild_commander_stats_daily,
     guild_commanders,
+    guild_members,
+    guilds,
+    idempotency_keys,
     quest_adventurer_junction,
+    quest_attachments,
+    quest_checkpoints,
+    quest_comments,
+    quest_crew_bans,
+    quest_final_crews,
+    quest_invitations,
+    quest_join_applications,
+    quest_ratings,
+    quest_recurrence_instances,
+    quest_recurrences,
+    quest_reports,
+    quest_revisions,
+    quest_tags,
+    quest_templates,
+    quest_waitlist,
     quests,
+    tags,
 );
@@ -0,0 +1,38 @@
+use std::{sync::Arc, time::Duration};
+
+use tracing::{error, info};
+
+use crate::{
+    application::usecases::idempotency_keys::IdempotencyKeysUsecase,
+    config::config_loader::get_quest_scheduler_env,
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad, repositories::idempotency_keys::IdempotencyKeysPostgres,
+    },
+};
+
+pub async fn run(db_pool: Arc<PgPoolSquad>) {
+    let interval = match get_quest_scheduler_env() {
+        Ok(quest_scheduler) => quest_scheduler.interval,
+        Err(e) => {
+            error!("Failed to load quest scheduler ENV :{}", e);
+            return;
+        }
+    };
+
+    let idempotency_keys_usecase =
+        IdempotencyKeysUsecase::new(Arc::new(IdempotencyKeysPostgres::new(db_pool)));
+
+    let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+
+    loop {
+        ticker.tick().await;
+
+        match idempotency_keys_usecase.purge_expired().await {
+            Ok(purged_count) if purged_count > 0 => {
+                info!("Expired idempotency keys purged: {}", purged_count);
+            }
+            Ok(_) => {}
+            Err(e) => error!("Failed to purge expired idempotency keys :{}", e),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod idempotency_keys;
+pub mod quest_deadlines;
+pub mod quest_purge;
+pub mod quest_recurrences;
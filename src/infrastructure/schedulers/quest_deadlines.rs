@@ -0,0 +1,45 @@
+use std::{sync::Arc, time::Duration};
+
+use tracing::{error, info};
+
+use crate::{
+    application::usecases::journey_ledger::JourneyLedgerUsecase,
+    config::config_loader::get_quest_scheduler_env,
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::{
+            achievements::AchievementsPostgres, journey_ledger::JourneyLedgerPostgres,
+            quest_viewing::QuestViewingPostgres,
+        },
+    },
+};
+
+pub async fn run(db_pool: Arc<PgPoolSquad>) {
+    let interval = match get_quest_scheduler_env() {
+        Ok(quest_scheduler) => quest_scheduler.interval,
+        Err(e) => {
+            error!("Failed to load quest scheduler ENV :{}", e);
+            return;
+        }
+    };
+
+    let journey_ledger_usecase = JourneyLedgerUsecase::new(
+        Arc::new(JourneyLedgerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(QuestViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(AchievementsPostgres::new(Arc::clone(&db_pool))),
+    );
+
+    let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+
+    loop {
+        ticker.tick().await;
+
+        match journey_ledger_usecase.expire_overdue().await {
+            Ok(expired_quest_ids) if !expired_quest_ids.is_empty() => {
+                info!("Overdue quests moved to Failed: {:?}", expired_quest_ids);
+            }
+            Ok(_) => {}
+            Err(e) => error!("Failed to expire overdue quests :{}", e),
+        }
+    }
+}
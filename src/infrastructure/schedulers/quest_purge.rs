@@ -0,0 +1,49 @@
+use std::{sync::Arc, time::Duration};
+
+use tracing::{error, info};
+
+use crate::{
+    application::usecases::quest_purge::QuestPurgeUsecase,
+    config::config_loader::{get_quest_attachments_env, get_quest_scheduler_env},
+    infrastructure::{
+        local_storage::LocalAttachmentStorage,
+        postgres::{postgres_connection::PgPoolSquad, repositories::quest_ops::QuestOpsPostgres},
+    },
+};
+
+pub async fn run(db_pool: Arc<PgPoolSquad>) {
+    let interval = match get_quest_scheduler_env() {
+        Ok(quest_scheduler) => quest_scheduler.interval,
+        Err(e) => {
+            error!("Failed to load quest scheduler ENV :{}", e);
+            return;
+        }
+    };
+
+    let quest_attachments = match get_quest_attachments_env() {
+        Ok(quest_attachments) => quest_attachments,
+        Err(e) => {
+            error!("Failed to load quest attachments ENV :{}", e);
+            return;
+        }
+    };
+
+    let quest_purge_usecase = QuestPurgeUsecase::new(
+        Arc::new(QuestOpsPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(LocalAttachmentStorage::new(quest_attachments.storage_dir)),
+    );
+
+    let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+
+    loop {
+        ticker.tick().await;
+
+        match quest_purge_usecase.purge_expired().await {
+            Ok(purged_quest_ids) if !purged_quest_ids.is_empty() => {
+                info!("Deleted quests purged: {:?}", purged_quest_ids);
+            }
+            Ok(_) => {}
+            Err(e) => error!("Failed to purge deleted quests :{}", e),
+        }
+    }
+}
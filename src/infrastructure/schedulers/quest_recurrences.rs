@@ -0,0 +1,46 @@
+use std::{sync::Arc, time::Duration};
+
+use tracing::{error, info};
+
+use crate::{
+    application::usecases::quest_recurrences::QuestRecurrencesUsecase,
+    config::config_loader::get_quest_scheduler_env,
+    infrastructure::postgres::{
+        postgres_connection::PgPoolSquad,
+        repositories::{
+            quest_ops::QuestOpsPostgres, quest_recurrences::QuestRecurrencesPostgres,
+            quest_templates::QuestTemplatesPostgres, quest_viewing::QuestViewingPostgres,
+        },
+    },
+};
+
+pub async fn run(db_pool: Arc<PgPoolSquad>) {
+    let interval = match get_quest_scheduler_env() {
+        Ok(quest_scheduler) => quest_scheduler.interval,
+        Err(e) => {
+            error!("Failed to load quest scheduler ENV :{}", e);
+            return;
+        }
+    };
+
+    let quest_recurrences_usecase = QuestRecurrencesUsecase::new(
+        Arc::new(QuestRecurrencesPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(QuestTemplatesPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(QuestOpsPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(QuestViewingPostgres::new(Arc::clone(&db_pool))),
+    );
+
+    let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+
+    loop {
+        ticker.tick().await;
+
+        match quest_recurrences_usecase.spawn_due().await {
+            Ok(spawned_quest_ids) if !spawned_quest_ids.is_empty() => {
+                info!("Recurring quests spawned: {:?}", spawned_quest_ids);
+            }
+            Ok(_) => {}
+            Err(e) => error!("Failed to spawn recurring quests :{}", e),
+        }
+    }
+}